@@ -25,7 +25,9 @@ use sudoku::solver::strategic::strategies::locked_sets::v2::test_utils::locked_s
 use sudoku::solver::strategic::strategies::{
     GroupIntersectionBoth, HiddenSingles, LockedSets, Strategy,
 };
-use sudoku::solver::{FallibleSolver, InfallibleSolver, backtracking, introspective, strategic};
+use sudoku::solver::{
+    FallibleSolver, InfallibleSolver, backtracking, bitboard, dlx, introspective, strategic,
+};
 use sudoku::{
     base::{SudokuBase, consts::*},
     solver::strategic::strategies::selection::StrategySet,
@@ -122,6 +124,12 @@ fn bench_solver_sample_group<Base: SudokuBase>(solver_group: &mut BenchmarkGroup
         },
     );
 
+    solver_group.bench_with_input(
+        BenchmarkId::new("dlx", &parameter_string),
+        &grid,
+        |b, grid| b.iter(|| dlx::Solver::new(grid).solve().unwrap()),
+    );
+
     solver_group.bench_with_input(
         BenchmarkId::new("strategic", &parameter_string),
         &grid,
@@ -151,6 +159,19 @@ fn bench_solver_sample_group<Base: SudokuBase>(solver_group: &mut BenchmarkGroup
         &grid,
         |b, grid| b.iter(|| sat::Solver::new(grid).try_solve().unwrap().unwrap()),
     );
+
+    solver_group.bench_with_input(
+        BenchmarkId::new("sat_triads", &parameter_string),
+        &grid,
+        |b, grid| {
+            b.iter(|| {
+                sat::Solver::with_clause_set(grid, sat::ClauseSet::Triads)
+                    .try_solve()
+                    .unwrap()
+                    .unwrap()
+            })
+        },
+    );
 }
 
 fn bench_solver_tdoku_group(solver_tdoku_group: &mut BenchmarkGroup<WallTime>) {
@@ -190,6 +211,28 @@ fn bench_solver_tdoku_group(solver_tdoku_group: &mut BenchmarkGroup<WallTime>) {
                 })
             },
         );
+        solver_tdoku_group.bench_with_input(
+            BenchmarkId::new("bitboard", tdoku_dataset),
+            grids,
+            |b, grids| {
+                b.iter(|| {
+                    for grid in grids {
+                        bitboard::Solver::new(grid).solve().unwrap();
+                    }
+                })
+            },
+        );
+        solver_tdoku_group.bench_with_input(
+            BenchmarkId::new("dlx", tdoku_dataset),
+            grids,
+            |b, grids| {
+                b.iter(|| {
+                    for grid in grids {
+                        dlx::Solver::new(grid).solve().unwrap();
+                    }
+                })
+            },
+        );
         solver_tdoku_group.bench_with_input(
             BenchmarkId::new("strategic", tdoku_dataset),
             grids,
@@ -216,6 +259,20 @@ fn bench_solver_tdoku_group(solver_tdoku_group: &mut BenchmarkGroup<WallTime>) {
                 })
             },
         );
+        solver_tdoku_group.bench_with_input(
+            BenchmarkId::new("sat_triads", tdoku_dataset),
+            grids,
+            |b, grids| {
+                b.iter(|| {
+                    for grid in grids {
+                        sat::Solver::with_clause_set(grid, sat::ClauseSet::Triads)
+                            .try_solve()
+                            .unwrap()
+                            .unwrap();
+                    }
+                })
+            },
+        );
     }
 }
 
@@ -360,7 +417,7 @@ fn bench_strategy_group(strategy_group: &mut BenchmarkGroup<WallTime>) {
     strategy_group.bench_with_input(
         BenchmarkId::new("LockedSets/execute", "sample_grid_hidden_pairs"),
         &grid,
-        |b, grid| b.iter(|| LockedSets.execute(grid).unwrap()),
+        |b, grid| b.iter(|| LockedSets::hidden(2).execute(grid).unwrap()),
     );
 
     let grid: Grid<Base3> = "s00905cgdg2103pgc00h03r0ccd85cmcpcece0c0b0g1do036s9sec11c48222g1482c8c0ho421og8o9o1ogc410209sgoi22054gi0o011i6gkiq116q814s0s4ca48kao4s6o4s1003g10610410s0qg081210c".parse().unwrap();
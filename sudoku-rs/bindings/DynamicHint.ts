@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DynamicSolveStep } from "./DynamicSolveStep";
+import type { StrategyEnum } from "./StrategyEnum";
+import type { TransportGroup } from "./TransportGroup";
+
+export type DynamicHint = { "kind": "technique", strategy: StrategyEnum, } | { "kind": "region", strategy: StrategyEnum, groups: Array<TransportGroup>, } | { "kind": "deduction", step: DynamicSolveStep, } | { "kind": "apply", step: DynamicSolveStep, };
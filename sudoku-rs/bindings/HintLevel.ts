@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How much of a solve step is revealed to the player.
+ *
+ * Each level reveals the information of the previous levels and more.
+ */
+export type HintLevel = "technique" | "region" | "deduction" | "apply";
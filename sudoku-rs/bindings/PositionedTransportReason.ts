@@ -2,4 +2,4 @@
 import type { DynamicCandidates } from "./DynamicCandidates";
 import type { DynamicPosition } from "./DynamicPosition";
 
-export type PositionedTransportReason = { position: DynamicPosition, } & ({ "candidates": DynamicCandidates });
+export type PositionedTransportReason = { position: DynamicPosition, } & ({ "cell": Record<string, never> } | { "candidates": DynamicCandidates } | { "fin": { candidates: DynamicCandidates, fins: DynamicCandidates, } } | { "colors": { color_1: DynamicCandidates, color_2: DynamicCandidates, } });
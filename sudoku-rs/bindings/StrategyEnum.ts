@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type StrategyEnum = "NakedSingles" | "HiddenSingles" | "NakedPairs" | "LockedSets" | "GroupIntersectionBlockToAxis" | "GroupIntersectionAxisToBlock" | "GroupIntersectionBoth" | "XWing" | "BruteForce";
+export type StrategyEnum = "NakedSingles" | "HiddenSingles" | "NakedPairs" | "LockedSets" | "GroupIntersectionBlockToAxis" | "GroupIntersectionAxisToBlock" | "GroupIntersectionBoth" | "Skyscraper" | "TwoStringKite" | "EmptyRectangle" | "WWing" | "XWing" | "XyWing" | "XyzWing" | "Fish" | "SimpleColoring" | "FinnedFish" | "MultiColoring" | "XChain" | "XyChain" | "AlternatingInferenceChain" | "SueDeCoq" | "AlsXz" | "AlsXyWing" | "DeathBlossom" | "UniqueRectangle" | "BugPlusOne" | "ForcingChains" | "BruteForce" | "LockedNakedPairs" | "LockedHiddenPairs" | "LockedNakedTriples" | "LockedHiddenTriples" | "LockedNakedQuads" | "LockedHiddenQuads";
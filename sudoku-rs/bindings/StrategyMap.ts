@@ -3,4 +3,4 @@
 /**
  * A map of `StrategyEnum` to `T`.
  */
-export type StrategyMap<T> = { naked_singles: T, hidden_singles: T, naked_pairs: T, locked_sets: T, group_intersection_block_to_axis: T, group_intersection_axis_to_block: T, group_intersection_both: T, x_wing: T, brute_force: T, };
+export type StrategyMap<T> = { naked_singles: T, hidden_singles: T, naked_pairs: T, locked_sets: T, group_intersection_block_to_axis: T, group_intersection_axis_to_block: T, group_intersection_both: T, skyscraper: T, two_string_kite: T, empty_rectangle: T, w_wing: T, x_wing: T, xy_wing: T, xyz_wing: T, fish: T, simple_coloring: T, finned_fish: T, multi_coloring: T, x_chain: T, xy_chain: T, alternating_inference_chain: T, sue_de_coq: T, als_xz: T, als_xy_wing: T, death_blossom: T, unique_rectangle: T, bug_plus_one: T, forcing_chains: T, brute_force: T, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DynamicPosition } from "./DynamicPosition";
+import type { DynamicValue } from "./DynamicValue";
+
+export type TransportChainNode = { position: DynamicPosition, candidate: DynamicValue, };
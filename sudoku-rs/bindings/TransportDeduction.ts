@@ -1,5 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PositionedTransportAction } from "./PositionedTransportAction";
 import type { PositionedTransportReason } from "./PositionedTransportReason";
+import type { TransportChainNode } from "./TransportChainNode";
+import type { TransportGroup } from "./TransportGroup";
+import type { TransportLink } from "./TransportLink";
 
-export type TransportDeduction = { reasons: Array<PositionedTransportReason>, actions: Array<PositionedTransportAction>, };
+export type TransportDeduction = { reasons: Array<PositionedTransportReason>, actions: Array<PositionedTransportAction>, chain?: Array<TransportChainNode>, groups?: Array<TransportGroup>, links?: Array<TransportLink>, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TransportChainNode } from "./TransportChainNode";
+import type { TransportLinkType } from "./TransportLinkType";
+
+export type TransportLink = { from: TransportChainNode, to: TransportChainNode, kind: TransportLinkType, };
@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { DynamicCandidates } from "./DynamicCandidates";
 
-export type TransportReason = { "candidates": DynamicCandidates };
+export type TransportReason = { "cell": Record<string, never> } | { "candidates": DynamicCandidates } | { "fin": { candidates: DynamicCandidates, fins: DynamicCandidates, } } | { "colors": { color_1: DynamicCandidates, color_2: DynamicCandidates, } };
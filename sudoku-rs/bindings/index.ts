@@ -7,6 +7,7 @@ export type * from "./DynamicCandidates";
 export type * from "./DynamicCell";
 export type * from "./DynamicGeneratorSettings";
 export type * from "./DynamicGrid";
+export type * from "./DynamicHint";
 export type * from "./DynamicMultiShotGeneratorSettings";
 export type * from "./DynamicPosition";
 export type * from "./DynamicPruningOrder";
@@ -19,6 +20,7 @@ export type * from "./GeneratorProgress";
 export type * from "./GoalOptimization";
 export type * from "./GridFormatEnum";
 export type * from "./GridMetric";
+export type * from "./HintLevel";
 export type * from "./MultiShotGeneratorProgress";
 export type * from "./PositionedTransportAction";
 export type * from "./PositionedTransportReason";
@@ -30,9 +32,13 @@ export type * from "./StrategyEnum";
 export type * from "./StrategyMap";
 export type * from "./TransportAction";
 export type * from "./TransportCell";
+export type * from "./TransportChainNode";
 export type * from "./TransportDeduction";
 export type * from "./TransportDeductions";
+export type * from "./TransportGroup";
 export type * from "./TransportHistory";
+export type * from "./TransportLink";
+export type * from "./TransportLinkType";
 export type * from "./TransportReason";
 export type * from "./TransportSolutionState";
 export type * from "./TransportSudoku";
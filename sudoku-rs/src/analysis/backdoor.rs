@@ -0,0 +1,257 @@
+//! Backdoors of a puzzle relative to a set of strategies.
+//!
+//! A backdoor is a set of cells, whose solution values make the puzzle solvable by the strategies alone,
+//! once they are revealed. The size of a minimal backdoor measures how many guesses are needed at least,
+//! which is independent of the solver used for guessing.
+//!
+//! The strategies are assumed to be monotone: revealing a value never prevents a deduction.
+//! This allows applying the strategies once before revealing the next cell,
+//! and skips cells the strategies can already solve.
+
+use anyhow::Context;
+
+use crate::base::SudokuBase;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::position::Position;
+use crate::solver::FallibleSolver;
+use crate::solver::strategic;
+use crate::solver::strategic::strategies::StrategyEnum;
+use crate::solver::strategic::strategies::selection::{StrategyList, StrategySelection};
+
+/// A minimal backdoor of a puzzle.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Backdoor<Base: SudokuBase> {
+    /// Sorted by ascending position.
+    positions: Vec<Position<Base>>,
+}
+
+impl<Base: SudokuBase> Backdoor<Base> {
+    /// Finds a minimal backdoor of `grid` with at most `max_size` cells.
+    ///
+    /// `BruteForce` is ignored, since it would solve every grid without revealing any cell.
+    ///
+    /// Returns `None`, if each backdoor is larger than `max_size`.
+    /// Returns an error, if `grid` has no unique solution.
+    ///
+    /// The runtime grows exponentially with the size of the backdoor.
+    pub fn find(
+        grid: &Grid<Base>,
+        strategies: impl StrategySelection,
+        max_size: usize,
+    ) -> Result<Option<Self>> {
+        let solution = grid
+            .unique_solution()
+            .context("A backdoor requires a grid with a unique solution")?;
+
+        let search = Search {
+            solution,
+            strategies: strategies
+                .iter_strategies()
+                .filter(|strategy| !matches!(strategy, StrategyEnum::BruteForce(_)))
+                .collect(),
+        };
+
+        let mut stuck_grid = grid.clone();
+        stuck_grid.fix_all_values();
+        stuck_grid.set_all_direct_candidates();
+        if search.apply_strategies(&mut stuck_grid)? {
+            return Ok(Some(Self { positions: vec![] }));
+        }
+
+        // Iterative deepening, so that the first backdoor found is minimal.
+        let mut positions = vec![];
+        for size in 1..=max_size {
+            if search.reveal(&stuck_grid, 0, size, &mut positions)? {
+                return Ok(Some(Self { positions }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like [`Self::find`], but without a limit on the size of the backdoor.
+    pub fn find_unbounded(grid: &Grid<Base>, strategies: impl StrategySelection) -> Result<Self> {
+        Ok(Self::find(grid, strategies, usize::from(Base::CELL_COUNT))?
+            .expect("Revealing all cells solves the grid"))
+    }
+}
+
+impl<Base: SudokuBase> Backdoor<Base> {
+    pub fn positions(&self) -> &[Position<Base>] {
+        &self.positions
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the strategies solve the grid without revealing any cell.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
+
+struct Search<Base: SudokuBase> {
+    solution: Solution<Base>,
+    strategies: StrategyList,
+}
+
+impl<Base: SudokuBase> Search<Base> {
+    /// Applies the strategies until they make no more progress.
+    ///
+    /// Returns whether the grid is solved.
+    fn apply_strategies(&self, grid: &mut Grid<Base>) -> Result<bool> {
+        let mut solver = strategic::Solver::with_strategies(grid, self.strategies.clone());
+        Ok(solver.try_solve()?.is_some())
+    }
+
+    /// Reveals `remaining` unsolved cells at or after `start_cell_index` in all combinations,
+    /// until the grid is solved.
+    ///
+    /// Returns whether a combination solved the grid, which is then contained in `positions`.
+    fn reveal(
+        &self,
+        grid: &Grid<Base>,
+        start_cell_index: u16,
+        remaining: usize,
+        positions: &mut Vec<Position<Base>>,
+    ) -> Result<bool> {
+        for pos in grid
+            .all_candidates_positions()
+            .into_iter()
+            .filter(|pos| pos.cell_index() >= start_cell_index)
+        {
+            let value = self.solution[pos];
+            let mut revealed_grid = grid.clone();
+            revealed_grid[pos].set_value(value);
+            revealed_grid.update_direct_candidates_for_new_value(pos, value);
+            positions.push(pos);
+
+            if self.apply_strategies(&mut revealed_grid)?
+                || (remaining > 1
+                    && self.reveal(
+                        &revealed_grid,
+                        pos.cell_index() + 1,
+                        remaining - 1,
+                        positions,
+                    )?)
+            {
+                return Ok(true);
+            }
+
+            positions.pop();
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::base::consts::*;
+    use crate::generator::{Generator, GeneratorSettings, PruningSettings, PruningTarget};
+    use crate::solver::strategic::strategies::selection::StrategySet;
+    use crate::solver::strategic::strategies::{BruteForce, NakedSingles};
+
+    use super::*;
+
+    fn reveal<Base: SudokuBase>(grid: &Grid<Base>, positions: &[Position<Base>]) -> Grid<Base> {
+        let solution = grid.unique_solution().unwrap();
+        let mut revealed_grid = grid.clone();
+        for &pos in positions {
+            revealed_grid[pos].set_value(solution[pos]);
+        }
+        revealed_grid
+    }
+
+    fn is_solvable<Base: SudokuBase>(grid: &Grid<Base>, strategies: StrategySet) -> bool {
+        grid.is_solvable_with_strategies(strategies)
+            .unwrap()
+            .is_some()
+    }
+
+    #[test]
+    fn test_solvable_by_strategies() {
+        let grid = crate::samples::base_3().remove(1);
+        let strategies = StrategySet::default_solver_strategies_no_brute_force();
+
+        let backdoor = Backdoor::find(&grid, strategies, 0).unwrap().unwrap();
+
+        assert!(backdoor.is_empty());
+    }
+
+    #[test]
+    fn test_without_strategies() {
+        let grid = crate::samples::base_2().remove(0);
+
+        let backdoor = Backdoor::find_unbounded(&grid, StrategySet::with_all(false)).unwrap();
+
+        assert_eq!(backdoor.positions(), grid.all_candidates_positions());
+    }
+
+    #[test]
+    fn test_ignores_brute_force() {
+        let grid = crate::samples::base_2().remove(0);
+
+        assert_eq!(
+            Backdoor::find(&grid, StrategySet::with_single(BruteForce.into()), 1).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_max_size() {
+        let grid = crate::samples::base_2().remove(0);
+        let empty_cell_count = grid.all_candidates_positions().len();
+
+        assert_eq!(
+            Backdoor::find(&grid, StrategySet::with_all(false), empty_cell_count - 1).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_requires_unique_solution() {
+        Backdoor::find(&Grid::<Base2>::new(), StrategySet::default(), 1).unwrap_err();
+    }
+
+    #[test]
+    fn test_minimal_against_brute_force() {
+        let strategies = StrategySet::with_single(NakedSingles.into());
+
+        for seed in 0..10 {
+            let grid = Generator::<Base2>::with_settings(GeneratorSettings {
+                prune: Some(PruningSettings {
+                    target: PruningTarget::Minimal,
+                    ..Default::default()
+                }),
+                seed: Some(seed),
+                ..Default::default()
+            })
+            .generate()
+            .unwrap();
+
+            let backdoor = Backdoor::find_unbounded(&grid, strategies).unwrap();
+
+            assert!(is_solvable(
+                &reveal(&grid, backdoor.positions()),
+                strategies
+            ));
+            let Some(smaller_size) = backdoor.len().checked_sub(1) else {
+                continue;
+            };
+            for positions in grid
+                .all_candidates_positions()
+                .into_iter()
+                .combinations(smaller_size)
+            {
+                assert!(
+                    !is_solvable(&reveal(&grid, &positions), strategies),
+                    "Smaller backdoor {positions:?} than {backdoor:?} in:\n{grid}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! Structural analysis of sudokus, independent of a specific solver.
+
+pub mod backdoor;
+pub mod unavoidable_sets;
@@ -0,0 +1,428 @@
+//! Unavoidable sets of a solution grid.
+//!
+//! An unavoidable set (also called deadly pattern) is a set of cells of a solution,
+//! whose values can be rearranged into another valid solution, while all other cells keep their values.
+//! Each puzzle with a unique solution must therefore contain at least one clue of each unavoidable set.
+//!
+//! The minimal unavoidable sets are exactly the minimal sets of cells,
+//! in which another solution differs from the given solution.
+//! They are enumerated by changing the value of a cell and following the conflicts this causes:
+//! if a cell takes the value `v`, the cell holding `v` in each of its groups must change too.
+//! Once no conflicts remain, the changed cells form another solution.
+
+use std::collections::BTreeSet;
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::position::Position;
+
+/// A minimal unavoidable set of a solution.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UnavoidableSet<Base: SudokuBase> {
+    /// Sorted and unique.
+    positions: Vec<Position<Base>>,
+}
+
+impl<Base: SudokuBase> UnavoidableSet<Base> {
+    pub fn positions(&self) -> &[Position<Base>] {
+        &self.positions
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn contains(&self, pos: Position<Base>) -> bool {
+        self.positions.binary_search(&pos).is_ok()
+    }
+
+    /// Whether `grid` contains a value in at least one cell of this set.
+    pub fn is_hit_by(&self, grid: &Grid<Base>) -> bool {
+        self.positions.iter().any(|&pos| grid[pos].has_value())
+    }
+
+    fn is_subset_of(&self, other: &Self) -> bool {
+        self.positions.iter().all(|&pos| other.contains(pos))
+    }
+}
+
+/// All minimal unavoidable sets of a solution up to a maximum size.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnavoidableSets<Base: SudokuBase> {
+    max_size: usize,
+    /// Sorted by ascending size.
+    sets: Vec<UnavoidableSet<Base>>,
+}
+
+/// Constructors
+impl<Base: SudokuBase> UnavoidableSets<Base> {
+    /// Enumerates the minimal unavoidable sets of `solved_grid` with at most `max_size` cells.
+    ///
+    /// Returns an error, if `solved_grid` is not solved.
+    pub fn new(solved_grid: &Grid<Base>, max_size: usize) -> Result<Self> {
+        Ok(Self::with_solution(
+            &Solution::try_from(solved_grid)?,
+            max_size,
+        ))
+    }
+
+    /// Enumerates the minimal unavoidable sets of `solution` with at most `max_size` cells.
+    ///
+    /// The runtime grows exponentially with `max_size`.
+    pub fn with_solution(solution: &Solution<Base>, max_size: usize) -> Self {
+        let mut search = Search::new(solution, max_size);
+        for root in Position::<Base>::all() {
+            search.root = root;
+            for value in Value::all().filter(|&value| value != solution[root]) {
+                search.assign(root, value);
+                search.search();
+                search.unassign(root);
+            }
+        }
+
+        let mut difference_sets = search
+            .difference_sets
+            .into_iter()
+            .map(|positions| UnavoidableSet { positions })
+            .collect::<Vec<_>>();
+        difference_sets.sort_by_key(UnavoidableSet::len);
+
+        let mut sets: Vec<UnavoidableSet<Base>> = vec![];
+        for difference_set in difference_sets {
+            if !sets.iter().any(|set| set.is_subset_of(&difference_set)) {
+                sets.push(difference_set);
+            }
+        }
+
+        Self { max_size, sets }
+    }
+}
+
+impl<Base: SudokuBase> UnavoidableSets<Base> {
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    pub fn sets(&self) -> &[UnavoidableSet<Base>] {
+        &self.sets
+    }
+
+    /// A lower bound on the number of clues of any puzzle with this solution as its unique solution.
+    ///
+    /// Each clue hits at most one of a collection of pairwise disjoint unavoidable sets,
+    /// so the size of such a collection is a lower bound. The collection is chosen greedily,
+    /// preferring small sets, which means the bound is not necessarily the best one provable from these sets.
+    pub fn clue_lower_bound(&self) -> u16 {
+        let mut covered = vec![false; usize::from(Base::CELL_COUNT)];
+        let mut disjoint_set_count = 0;
+        for set in &self.sets {
+            if set
+                .positions
+                .iter()
+                .all(|&pos| !covered[usize::from(pos.cell_index())])
+            {
+                for &pos in &set.positions {
+                    covered[usize::from(pos.cell_index())] = true;
+                }
+                disjoint_set_count += 1;
+            }
+        }
+        disjoint_set_count
+    }
+
+    /// The sets, which contain no value of `grid`.
+    ///
+    /// If any set is returned, `grid` has multiple solutions.
+    pub fn unhit_sets<'a>(
+        &'a self,
+        grid: &'a Grid<Base>,
+    ) -> impl Iterator<Item = &'a UnavoidableSet<Base>> + 'a {
+        self.sets.iter().filter(|set| !set.is_hit_by(grid))
+    }
+
+    /// The values of `grid`, which are the only value in at least one set.
+    ///
+    /// Deleting any of these values results in multiple solutions,
+    /// which allows pruning to keep them without checking for uniqueness.
+    pub fn required_clues(&self, grid: &Grid<Base>) -> BTreeSet<Position<Base>> {
+        self.sets
+            .iter()
+            .filter_map(|set| {
+                let mut value_positions = set
+                    .positions
+                    .iter()
+                    .copied()
+                    .filter(|&pos| grid[pos].has_value());
+                let pos = value_positions.next()?;
+                value_positions.next().is_none().then_some(pos)
+            })
+            .collect()
+    }
+}
+
+/// Depth first search for other solutions, which differ from `solution` in at most `max_size` cells.
+struct Search<'a, Base: SudokuBase> {
+    solution: &'a Solution<Base>,
+    max_size: usize,
+    /// The position of the value in each group, indexed by `group_index * SIDE_LENGTH + value - 1`.
+    value_positions: Vec<Position<Base>>,
+    /// The changed value of each cell, indexed by cell index.
+    changed_values: Vec<Option<Value<Base>>>,
+    /// The changed values in each group.
+    changed_group_values: Vec<Candidates<Base>>,
+    changed_positions: Vec<Position<Base>>,
+    /// The lowest changed position. Only positions after it may change,
+    /// which enumerates each difference set from its lowest position.
+    root: Position<Base>,
+    /// Each sorted and unique.
+    difference_sets: BTreeSet<Vec<Position<Base>>>,
+}
+
+impl<'a, Base: SudokuBase> Search<'a, Base> {
+    fn new(solution: &'a Solution<Base>, max_size: usize) -> Self {
+        let side_length = usize::from(Base::SIDE_LENGTH);
+
+        let mut value_positions = vec![Position::default(); 3 * side_length * side_length];
+        for pos in Position::<Base>::all() {
+            for group_index in Self::group_indexes(pos) {
+                value_positions[Self::value_position_index(group_index, solution[pos])] = pos;
+            }
+        }
+
+        Self {
+            solution,
+            max_size,
+            value_positions,
+            changed_values: vec![None; usize::from(Base::CELL_COUNT)],
+            changed_group_values: vec![Candidates::new(); 3 * side_length],
+            changed_positions: vec![],
+            root: Position::default(),
+            difference_sets: BTreeSet::new(),
+        }
+    }
+
+    /// The indexes of the row, column and block containing `pos`.
+    fn group_indexes(pos: Position<Base>) -> [usize; 3] {
+        let side_length = usize::from(Base::SIDE_LENGTH);
+        [
+            pos.to_row().get_usize(),
+            side_length + pos.to_column().get_usize(),
+            2 * side_length + pos.to_block().get_usize(),
+        ]
+    }
+
+    fn value_position_index(group_index: usize, value: Value<Base>) -> usize {
+        group_index * usize::from(Base::SIDE_LENGTH) + usize::from(value.get() - 1)
+    }
+
+    fn assign(&mut self, pos: Position<Base>, value: Value<Base>) {
+        self.changed_values[usize::from(pos.cell_index())] = Some(value);
+        for group_index in Self::group_indexes(pos) {
+            self.changed_group_values[group_index].insert(value);
+        }
+        self.changed_positions.push(pos);
+    }
+
+    fn unassign(&mut self, pos: Position<Base>) {
+        let value = self.changed_values[usize::from(pos.cell_index())]
+            .take()
+            .expect("Expected a changed value");
+        for group_index in Self::group_indexes(pos) {
+            self.changed_group_values[group_index].delete(value);
+        }
+        debug_assert_eq!(self.changed_positions.last(), Some(&pos));
+        self.changed_positions.pop();
+    }
+
+    /// The unchanged positions, which hold a value taken by a changed cell in the same group.
+    fn conflicting_positions(&self) -> Vec<Position<Base>> {
+        let mut conflicting_positions = vec![];
+        for &pos in &self.changed_positions {
+            let value = self.changed_values[usize::from(pos.cell_index())].unwrap();
+            for group_index in Self::group_indexes(pos) {
+                let value_pos =
+                    self.value_positions[Self::value_position_index(group_index, value)];
+                if self.changed_values[usize::from(value_pos.cell_index())].is_none()
+                    && !conflicting_positions.contains(&value_pos)
+                {
+                    conflicting_positions.push(value_pos);
+                }
+            }
+        }
+        conflicting_positions
+    }
+
+    fn search(&mut self) {
+        let conflicting_positions = self.conflicting_positions();
+
+        let Some(&pos) = conflicting_positions.first() else {
+            // Each group contains a permutation of its original values in its changed cells.
+            let mut difference_set = self.changed_positions.clone();
+            difference_set.sort_unstable();
+            self.difference_sets.insert(difference_set);
+            return;
+        };
+
+        if self.changed_positions.len() + conflicting_positions.len() > self.max_size
+            || conflicting_positions.iter().any(|&pos| pos < self.root)
+        {
+            return;
+        }
+
+        let group_indexes = Self::group_indexes(pos);
+        for value in Value::all() {
+            if value == self.solution[pos]
+                || group_indexes
+                    .iter()
+                    .any(|&group_index| self.changed_group_values[group_index].has(value))
+            {
+                continue;
+            }
+            self.assign(pos, value);
+            self.search();
+            self.unassign(pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::base::consts::*;
+    use crate::generator::{Generator, PruningTarget};
+
+    use super::*;
+
+    fn is_unavoidable<Base: SudokuBase>(
+        solution: &Solution<Base>,
+        positions: &[Position<Base>],
+    ) -> bool {
+        let mut grid = solution.to_grid();
+        for &pos in positions {
+            grid[pos].delete();
+        }
+        grid.count_solutions_up_to(2).unwrap() == 2
+    }
+
+    fn assert_minimal_unavoidable<Base: SudokuBase>(
+        solution: &Solution<Base>,
+        set: &UnavoidableSet<Base>,
+    ) {
+        assert!(is_unavoidable(solution, set.positions()), "{set:?}");
+        for i in 0..set.len() {
+            let mut positions = set.positions().to_vec();
+            positions.remove(i);
+            assert!(!is_unavoidable(solution, &positions), "{set:?}");
+        }
+    }
+
+    #[test]
+    fn test_base_2_matches_brute_force() {
+        let solution = Solution::try_from(&crate::samples::base_2_solved()).unwrap();
+        let max_size = 6;
+
+        let unavoidable_sets = UnavoidableSets::with_solution(&solution, max_size);
+
+        let mut expected_sets: Vec<Vec<Position<Base2>>> = vec![];
+        for size in 1..=max_size {
+            for positions in Position::<Base2>::all().combinations(size) {
+                if !expected_sets
+                    .iter()
+                    .any(|set| set.iter().all(|pos| positions.contains(pos)))
+                    && is_unavoidable(&solution, &positions)
+                {
+                    expected_sets.push(positions);
+                }
+            }
+        }
+
+        assert!(!expected_sets.is_empty());
+        assert_eq!(
+            unavoidable_sets
+                .sets()
+                .iter()
+                .map(|set| set.positions().to_vec())
+                .sorted()
+                .collect_vec(),
+            expected_sets.into_iter().sorted().collect_vec()
+        );
+    }
+
+    #[test]
+    fn test_base_3_minimal() {
+        let grid = crate::samples::base_3().remove(0);
+        let solution = grid.unique_solution().unwrap();
+
+        let unavoidable_sets = UnavoidableSets::with_solution(&solution, 6);
+
+        assert!(!unavoidable_sets.sets().is_empty());
+        assert!(unavoidable_sets.sets().iter().all(|set| set.len() <= 6));
+        for set in unavoidable_sets.sets() {
+            assert_minimal_unavoidable(&solution, set);
+        }
+        // The puzzle has a unique solution.
+        assert_eq!(unavoidable_sets.unhit_sets(&grid).count(), 0);
+    }
+
+    #[test]
+    fn test_new_requires_solved_grid() {
+        UnavoidableSets::new(&crate::samples::base_2_solved(), 4).unwrap();
+        UnavoidableSets::new(&crate::samples::base_2().remove(0), 4).unwrap_err();
+    }
+
+    #[test]
+    fn test_clue_lower_bound() {
+        for seed in 0..10 {
+            let puzzle = Generator::<Base2>::with_settings(crate::generator::GeneratorSettings {
+                prune: Some(crate::generator::PruningSettings {
+                    target: PruningTarget::Minimal,
+                    ..Default::default()
+                }),
+                seed: Some(seed),
+                ..Default::default()
+            })
+            .generate()
+            .unwrap();
+            let solution = puzzle.unique_solution().unwrap();
+
+            let unavoidable_sets =
+                UnavoidableSets::with_solution(&solution, usize::from(Base2::CELL_COUNT));
+
+            assert_eq!(unavoidable_sets.unhit_sets(&puzzle).count(), 0);
+            let clue_count = u16::try_from(puzzle.all_value_positions().len()).unwrap();
+            let clue_lower_bound = unavoidable_sets.clue_lower_bound();
+            assert!(clue_lower_bound > 0);
+            assert!(clue_lower_bound <= clue_count);
+        }
+    }
+
+    #[test]
+    fn test_required_clues() {
+        let grid = crate::samples::base_2_solved();
+        let solution = Solution::try_from(&grid).unwrap();
+        let unavoidable_sets = UnavoidableSets::with_solution(&solution, 4);
+        let set = unavoidable_sets.sets()[0].clone();
+
+        let mut puzzle = grid.clone();
+        puzzle.unfix_all_values();
+        for &pos in &set.positions()[1..] {
+            puzzle[pos].delete();
+        }
+
+        let required_clues = unavoidable_sets.required_clues(&puzzle);
+        assert!(required_clues.contains(&set.positions()[0]));
+        for pos in required_clues {
+            let mut puzzle = puzzle.clone();
+            puzzle[pos].delete();
+            assert_eq!(puzzle.count_solutions_up_to(2).unwrap(), 2);
+        }
+    }
+}
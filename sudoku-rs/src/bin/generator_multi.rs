@@ -10,7 +10,7 @@ use sudoku::{
         DynamicGeneratorSettings, DynamicPruningSettings,
         multi_shot::{DynamicMultiShotGeneratorSettings, GoalOptimization, GridMetric},
     },
-    solver::strategic::strategies::{XWing, selection::StrategySet},
+    solver::strategic::strategies::{Fish, selection::StrategySet},
 };
 
 fn parse_generator_settings(s: &str) -> Result<DynamicMultiShotGeneratorSettings> {
@@ -30,7 +30,7 @@ fn default_generator_settings() -> DynamicMultiShotGeneratorSettings {
         },
         iterations: 10_000,
         metric: GridMetric::StrategyDeductionCountSingle {
-            strategy: XWing.into(),
+            strategy: Fish.into(),
         },
         optimize: GoalOptimization::Maximize,
         parallel: true,
@@ -0,0 +1,106 @@
+//! Cross-checks the solver backends on the sample grids and the tdoku datasets.
+//!
+//! The tdoku datasets are optional, see `tests/res/tdoku/.gitignore` for their source.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use clap::Parser;
+use indicatif::ParallelProgressIterator;
+use rayon::prelude::*;
+
+use sudoku::base::SudokuBase;
+use sudoku::base::consts::Base3;
+use sudoku::grid::Grid;
+use sudoku::grid::deserialization::read_grids_from_file;
+use sudoku::samples;
+use sudoku::solver::differential::{self, DEFAULT_SOLUTION_COUNT_LIMIT};
+
+#[derive(Parser, Debug)]
+#[command(name = "solver_differential")]
+#[command(about = "Checks that all solvers agree on the same grids")]
+struct Args {
+    /// Maximum number of grids checked per tdoku dataset
+    #[arg(short, long, default_value = "1000")]
+    max_grids_per_dataset: usize,
+
+    /// Number of solutions, up to which the solution counts are compared
+    #[arg(short, long, default_value_t = DEFAULT_SOLUTION_COUNT_LIMIT)]
+    solution_count_limit: usize,
+}
+
+/// Checks all `grids` and prints a minimized counterexample for each disagreement.
+///
+/// Returns the number of disagreements.
+fn check_grids<Base: SudokuBase>(
+    name: &str,
+    grids: &[Grid<Base>],
+    solution_count_limit: usize,
+) -> usize {
+    println!("Checking {} grids of {name}", grids.len());
+
+    let disagreements = grids
+        .par_iter()
+        .progress_count(grids.len() as u64)
+        .filter_map(|grid| differential::check_minimized(grid, solution_count_limit))
+        .collect::<Vec<_>>();
+
+    for disagreement in &disagreements {
+        println!("{disagreement}");
+    }
+
+    disagreements.len()
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let mut disagreement_count = 0;
+
+    let mut base_2_grids = samples::base_2();
+    base_2_grids.push(samples::base_2_solved());
+    disagreement_count += check_grids("samples::base_2", &base_2_grids, args.solution_count_limit);
+    disagreement_count += check_grids(
+        "samples::base_3",
+        &samples::base_3(),
+        args.solution_count_limit,
+    );
+    disagreement_count += check_grids(
+        "samples::base_4",
+        &samples::base_4(),
+        args.solution_count_limit,
+    );
+
+    let tdoku_datasets_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/res/tdoku");
+    let mut tdoku_datasets = fs::read_dir(&tdoku_datasets_dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    tdoku_datasets.retain(|path| path.file_name().is_some_and(|name| name != ".gitignore"));
+    tdoku_datasets.sort();
+
+    if tdoku_datasets.is_empty() {
+        println!(
+            "No tdoku datasets found in {}, skipping",
+            tdoku_datasets_dir.display()
+        );
+    }
+
+    for tdoku_dataset in tdoku_datasets {
+        let mut grids = read_grids_from_file::<Base3>(&tdoku_dataset)?;
+        grids.truncate(args.max_grids_per_dataset);
+        disagreement_count += check_grids(
+            &tdoku_dataset.display().to_string(),
+            &grids,
+            args.solution_count_limit,
+        );
+    }
+
+    if disagreement_count > 0 {
+        bail!("Solvers disagree on {disagreement_count} grids");
+    }
+
+    println!("All solvers agree");
+
+    Ok(())
+}
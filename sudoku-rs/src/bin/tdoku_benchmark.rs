@@ -7,7 +7,7 @@ use sudoku::base::consts::Base3;
 use sudoku::error::Result;
 use sudoku::grid::Grid;
 use sudoku::grid::deserialization::read_grids_from_file;
-use sudoku::solver::{FallibleSolver, InfallibleSolver, backtracking};
+use sudoku::solver::{FallibleSolver, InfallibleSolver, backtracking, bitboard};
 use sudoku::solver::{sat, strategic};
 
 #[allow(dead_code)]
@@ -15,6 +15,7 @@ enum SolverSelection {
     Strategic,
     Backtracking,
     Sat,
+    Bitboard,
 }
 
 fn main() -> Result<()> {
@@ -75,6 +76,11 @@ fn work(
             SolverSelection::Sat => {
                 sat::Solver::new(grid).try_solve().unwrap().unwrap();
             }
+            SolverSelection::Bitboard => {
+                let mut solver = bitboard::Solver::new(grid);
+                solver.solve().unwrap();
+                *total_backtrack_count += solver.backtrack_count;
+            }
         }
     }
 }
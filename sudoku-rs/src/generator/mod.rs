@@ -5,6 +5,7 @@ use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 
+use crate::base::BaseEnum;
 use crate::cell::Value;
 use crate::error::Result;
 use crate::grid::Grid;
@@ -12,7 +13,7 @@ use crate::position::Position;
 use crate::rng::{CrateRng, new_crate_rng_with_seed};
 use crate::solver::backtracking::DisallowedCandidateAtPosition;
 use crate::solver::strategic::strategies::BruteForce;
-use crate::solver::{backtracking, introspective};
+use crate::solver::{backtracking, introspective, sat};
 use crate::{base::SudokuBase, solver::strategic::strategies::selection::StrategySet};
 
 pub use settings::*;
@@ -49,6 +50,41 @@ pub struct GeneratorProgress {
     deleted_count: u16,
 }
 
+/// Checks if a grid has a solution, which differs from the expected one at a specific position.
+///
+/// Used while pruning, to ensure the uniqueness of the solution after deleting a value.
+enum AmbiguityChecker<Base: SudokuBase> {
+    /// Uses a new solver for each check.
+    Introspective,
+    /// Reuses the same SAT solver for all checks, including its learned clauses.
+    IncrementalSat(Box<sat::Solver<Base>>),
+}
+
+impl<Base: SudokuBase> AmbiguityChecker<Base> {
+    fn new() -> Self {
+        match Base::ENUM {
+            // Analogous to `introspective::Solver`
+            BaseEnum::Base2 | BaseEnum::Base3 => Self::Introspective,
+            BaseEnum::Base4 | BaseEnum::Base5 => Self::IncrementalSat(Box::new(
+                sat::Solver::incremental(sat::ClauseSet::default()),
+            )),
+        }
+    }
+
+    fn has_ambiguous_solution(
+        &mut self,
+        grid: &Grid<Base>,
+        filter: DisallowedCandidateAtPosition<Base>,
+    ) -> Result<bool> {
+        Ok(match self {
+            Self::Introspective => introspective::Solver::with_filter(grid, filter)
+                .next()
+                .is_some(),
+            Self::IncrementalSat(solver) => solver.try_solve_incremental(grid, &filter)?.is_some(),
+        })
+    }
+}
+
 struct NearMinimalGridReturn<Base: SudokuBase> {
     near_minimal_grid: Grid<Base>,
     deleted: Vec<(Position<Base>, Value<Base>)>,
@@ -101,23 +137,30 @@ impl<Base: SudokuBase> Generator<Base> {
     fn solved_grid(&self, rng: &mut CrateRng) -> Result<Grid<Base>> {
         debug!("Generating solution");
 
-        let grid = if let Some(solution_settings) = &self.settings.solution {
+        let mut grid = if let Some(solution_settings) = &self.settings.solution {
             solution_settings.values_grid.clone()
         } else {
             Grid::<Base>::new()
         };
 
-        let mut solver = backtracking::Solver::builder(&grid)
+        let solution = backtracking::Solver::builder(&grid)
             .rng(rng.clone())
-            .build();
+            .build()
+            .next()
+            .ok_or_else(|| {
+                if self.settings.solution.is_some() {
+                    format_err!("'solution.values_grid' has no solution")
+                } else {
+                    panic!("Expected empty grid to have at least one solution")
+                }
+            })?;
 
-        solver.next().ok_or_else(|| {
-            if self.settings.solution.is_some() {
-                format_err!("'solution.values_grid' has no solution")
-            } else {
-                panic!("Expected empty grid to have at least one solution")
-            }
-        })
+        // Retain the existing values, including their fixed state.
+        for pos in grid.all_candidates_positions() {
+            grid[pos].set_value(solution[pos]);
+        }
+
+        Ok(grid)
     }
 
     // TODO: use in prune_from_minimal
@@ -159,7 +202,7 @@ impl<Base: SudokuBase> Generator<Base> {
             // Check if the non pruning positions already result in a unique solution.
             if let Some(unique_solution) = near_minimal_grid.unique_solution() {
                 debug!("Non-pruning positions result in unique solution");
-                debug_assert_eq!(unique_solution, *solved_grid);
+                debug_assert!(unique_solution.is_solution_for(solved_grid));
                 return Ok(NearMinimalGridReturn {
                     near_minimal_grid,
                     deleted: pruning_positions
@@ -186,9 +229,9 @@ impl<Base: SudokuBase> Generator<Base> {
 
                 let mut solver = introspective::Solver::new(near_minimal_grid.clone());
                 if let Some(first_solution) = solver.next() {
-                    if &first_solution == solved_grid {
+                    if first_solution.is_solution_for(solved_grid) {
                         if let Some(second_solution) = solver.next() {
-                            debug_assert_ne!(&second_solution, solved_grid);
+                            debug_assert!(!second_solution.is_solution_for(solved_grid));
                             Some(second_solution)
                         } else {
                             None
@@ -201,7 +244,7 @@ impl<Base: SudokuBase> Generator<Base> {
                 }
             } {
                 let non_equal_value_positions: BTreeSet<_> = Position::<Base>::all()
-                    .filter(|&pos| ambiguous_solution[pos] != solved_grid[pos])
+                    .filter(|&pos| solved_grid[pos].value() != Some(ambiguous_solution[pos]))
                     .collect();
 
                 let Some(next_pruning_i) = pruning_positions
@@ -258,7 +301,7 @@ impl<Base: SudokuBase> Generator<Base> {
 
                 if let Some(unique_solution) = near_minimal_grid.unique_solution() {
                     debug!("Restored value resulted in unique solution, stop restoring");
-                    debug_assert_eq!(unique_solution, *solved_grid);
+                    debug_assert!(unique_solution.is_solution_for(solved_grid));
                     true
                 } else {
                     false
@@ -336,7 +379,8 @@ impl<Base: SudokuBase> Generator<Base> {
         grid: &mut Grid<Base>,
         pos: Position<Base>,
         prune_settings: &PruningSettings<Base>,
-    ) -> Option<Value<Base>> {
+        ambiguity_checker: &mut AmbiguityChecker<Base>,
+    ) -> Result<Option<Value<Base>>> {
         let cell = grid.get(pos);
 
         let Some(deleted_value) = cell.value() else {
@@ -353,26 +397,22 @@ impl<Base: SudokuBase> Generator<Base> {
                 grid
                 .is_solvable_with_strategies(prune_settings.strategies)
                 .is_ok_and(|solution| solution.is_some())
-        ) && {
-            let mut solver = introspective::Solver::with_filter(
-                grid.clone(),
-                DisallowedCandidateAtPosition {
-                    pos,
-                    candidate: deleted_value,
-                },
-            );
-            let has_ambiguous_solution = solver.next().is_some();
-            !has_ambiguous_solution
-        };
-
-        if can_be_deleted {
+        ) && !ambiguity_checker.has_ambiguous_solution(
+            grid,
+            DisallowedCandidateAtPosition {
+                pos,
+                candidate: deleted_value,
+            },
+        )?;
+
+        Ok(if can_be_deleted {
             // current position can be removed without losing uniqueness of the grid solution.
             Some(deleted_value)
         } else {
             // current position is necessary for unique solution
             grid.get_mut(pos).set_value(deleted_value);
             None
-        }
+        })
     }
 
     fn shuffle_vec<T>(rng: &mut impl Rng, mut vec: Vec<T>) -> Vec<T> {
@@ -471,6 +511,8 @@ impl<Base: SudokuBase> Generator<Base> {
         let pruning_positions: Vec<_> = self.pruning_positions(prune_settings, rng)?;
         let pruning_position_count = pruning_positions.len();
 
+        let mut ambiguity_checker = AmbiguityChecker::new();
+
         let mut deleted_count = 0;
         for (i, pos) in pruning_positions.into_iter().enumerate() {
             let pruning_position_index = i + 1;
@@ -479,7 +521,9 @@ impl<Base: SudokuBase> Generator<Base> {
                 break;
             }
 
-            if Self::try_delete_cell_at_pos(&mut grid, pos, prune_settings).is_some() {
+            if Self::try_delete_cell_at_pos(&mut grid, pos, prune_settings, &mut ambiguity_checker)?
+                .is_some()
+            {
                 deleted_count += 1;
                 debug!(
                     "Position {pruning_position_index}/{pruning_position_count} deleted, totaling {deleted_count}/{distance_from_filled} deleted positions"
@@ -544,15 +588,20 @@ impl<Base: SudokuBase> Generator<Base> {
 
         let remaining_pruning_position_count = remaining_pruning_positions.len();
 
+        let mut ambiguity_checker = AmbiguityChecker::new();
+
         // Reduce grid to a minimal solution.
         for (i, pos) in remaining_pruning_positions.into_iter().enumerate() {
             let pruning_position_index = i + 1;
 
             let deleted_count = u16::try_from(deleted.len()).unwrap();
 
-            if let Some(deleted_value) =
-                Self::try_delete_cell_at_pos(&mut grid, pos, prune_settings)
-            {
+            if let Some(deleted_value) = Self::try_delete_cell_at_pos(
+                &mut grid,
+                pos,
+                prune_settings,
+                &mut ambiguity_checker,
+            )? {
                 debug!(
                     "Position {pruning_position_index}/{remaining_pruning_position_count} deleted, totaling {deleted_count} deleted positions"
                 );
@@ -1114,10 +1163,10 @@ mod tests {
 
             assert!(grid.is_minimal());
 
-            let mut solution = grid.unique_solution().unwrap();
-            solution.unfix_all_values();
+            let solution = grid.unique_solution().unwrap();
             assert_eq!(
-                solution, values_grid,
+                solution.to_grid(),
+                values_grid,
                 "Solution differs from target solution:\n{solution}\n!=\n{values_grid}"
             );
         }
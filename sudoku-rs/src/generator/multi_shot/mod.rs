@@ -1,6 +1,8 @@
 #![allow(deprecated)]
 
+use crate::analysis::backdoor::Backdoor;
 use crate::grid::Grid;
+use crate::solver::sat::ClauseSet;
 use crate::solver::strategic::{self, strategies::StrategyEnum};
 use crate::solver::{FallibleSolver, InfallibleSolver, backtracking, sat};
 use crate::{base::SudokuBase, solver::strategic::strategies::selection::StrategySet};
@@ -26,6 +28,11 @@ pub const EVALUATED_GRID_METRIC_FIXED_POINT_SCALE_F64: f64 =
 
 static GENERATE_NO_GRIDS: &str = "at least one generation result";
 
+/// The largest backdoor searched by `GridMetric::BackdoorSize`.
+///
+/// The backdoor search is exponential in the size of the backdoor.
+pub const BACKDOOR_SIZE_LIMIT: usize = 3;
+
 /// A metric used to evaluate the difficulty of a grid.
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
 #[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
@@ -43,15 +50,23 @@ pub enum GridMetric {
     StrategyDeductionCountAny,
     /// Number of deductions by a single strategy used to solve the grid.
     StrategyDeductionCountSingle { strategy: StrategyEnum },
-    // FIXME: this produces counterintuitive results
+    // Note: this produces counterintuitive results
     //  if there are only single candidates left, all strategies except for *Singles don't make progress.
     //  The intention was to measure "needle point" strategies, which block further progress until spotted,
-    //  but this metric does not reflect that.
-    //  We need to somehow weigh the available strategies by their difficulty.
+    //  but this metric does not reflect that. Use `BackdoorSize` instead.
     /// The average number of strategies available to make progress. Scaled by a factor of `STRATEGY_SCORE_FIXED_POINT_SCALE`.
+    ///
+    /// Expanded strategies count separately, e.g. `LockedSets` counts once for each set type and size making progress.
     StrategyAverageOptions,
+    /// The minimal number of cells, whose solution values have to be revealed,
+    /// so that the strategies without `BruteForce` solve the grid. See `analysis::backdoor`.
+    ///
+    /// Backdoors larger than `BACKDOOR_SIZE_LIMIT` are not searched and evaluate to `BACKDOOR_SIZE_LIMIT + 1`.
+    BackdoorSize,
     /// The number of steps taken by `sat::Solver` to solve the grid.
     SatStepCount,
+    /// The number of steps taken by `sat::Solver` with `ClauseSet::Triads` to solve the grid.
+    SatTriadsStepCount,
     /// The number of backtracking steps taken by `backtracking::Solver` to solve the grid.
     BacktrackCount,
     /// The number of givens in the grid.
@@ -69,45 +84,59 @@ impl GridMetric {
         strategies: impl StrategySelection,
     ) -> Result<EvaluatedGridMetric> {
         static STRATEGIC_SOLVER_ERROR_MESSAGE: &str = "Strategic solver failed to solve the grid";
-        let get_strategic_solver = || {
+        let get_strategic_solver = |strategies| {
             strategic::SolverBuilder::new(grid.clone())
                 .strategies(strategies)
                 .build()
         };
+        let sat_step_count = |clause_set| -> Result<EvaluatedGridMetric> {
+            let mut solver = sat::Solver::with_clause_set(grid, clause_set);
+            solver
+                .try_solve()?
+                .context("SAT solver failed to solve the grid")?;
+            Ok(solver.step_count())
+        };
 
         // TODO: implement remaining metrics
         Ok(match self {
-            GridMetric::StrategyScore => get_strategic_solver()
+            GridMetric::StrategyScore => get_strategic_solver(strategies)
                 .solve_path()
                 .total_score()?
                 .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::StrategyApplicationCountAny => get_strategic_solver()
+            GridMetric::StrategyApplicationCountAny => get_strategic_solver(strategies)
                 .solve_path()
                 .application_count_any()?
                 .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::StrategyApplicationCountSingle { strategy } => get_strategic_solver()
-                .solve_path()
-                .application_count_single(strategy)?
-                .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::StrategyDeductionCountAny => get_strategic_solver()
+            GridMetric::StrategyApplicationCountSingle { strategy } => {
+                get_strategic_solver(strategies)
+                    .solve_path()
+                    .application_count_single(strategy)?
+                    .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?
+            }
+            GridMetric::StrategyDeductionCountAny => get_strategic_solver(strategies)
                 .solve_path()
                 .deduction_count_any()?
                 .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::StrategyDeductionCountSingle { strategy } => get_strategic_solver()
-                .solve_path()
-                .deduction_count_single(strategy)?
-                .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::StrategyAverageOptions => get_strategic_solver()
+            GridMetric::StrategyDeductionCountSingle { strategy } => {
+                get_strategic_solver(strategies)
+                    .solve_path()
+                    .deduction_count_single(strategy)?
+                    .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?
+            }
+            GridMetric::StrategyAverageOptions => get_strategic_solver(strategies)
                 .solve_path_all()
                 .average_options()?
                 .context(STRATEGIC_SOLVER_ERROR_MESSAGE)?,
-            GridMetric::SatStepCount => {
-                let mut solver = sat::Solver::new(grid);
-                solver
-                    .try_solve()?
-                    .context("SAT solver failed to solve the grid")?;
-                solver.step_count()
+            GridMetric::BackdoorSize => {
+                if let Some(backdoor) = Backdoor::find(grid, strategies, BACKDOOR_SIZE_LIMIT)? {
+                    backdoor.len().try_into()?
+                } else {
+                    warn!("Backdoor larger than the limit of {BACKDOOR_SIZE_LIMIT}");
+                    (BACKDOOR_SIZE_LIMIT + 1).try_into()?
+                }
             }
+            GridMetric::SatStepCount => sat_step_count(ClauseSet::General)?,
+            GridMetric::SatTriadsStepCount => sat_step_count(ClauseSet::Triads)?,
             GridMetric::BacktrackCount => {
                 let mut solver = backtracking::Solver::new(grid);
                 solver
@@ -568,16 +597,22 @@ mod tests {
             #[case::strategy_deduction_count(2, GridMetric::StrategyDeductionCountAny, 12)]
             // TODO: StrategyDeductionCountSingle
             #[case::strategy_average_options(0, GridMetric::StrategyAverageOptions, 2000)]
-            #[case::strategy_average_options(1, GridMetric::StrategyAverageOptions, 2750)]
-            #[case::strategy_average_options(2, GridMetric::StrategyAverageOptions, 3000)]
+            #[case::strategy_average_options(1, GridMetric::StrategyAverageOptions, 4500)]
+            #[case::strategy_average_options(2, GridMetric::StrategyAverageOptions, 5500)]
             // #[case::solve_graph_average_branching_factor(
             //     0,
             //     GridMetric::SolveGraphAverageBranchingFactor,
             //     0
             // )]
+            #[case::backdoor_size(0, GridMetric::BackdoorSize, 0)]
+            #[case::backdoor_size(1, GridMetric::BackdoorSize, 0)]
+            #[case::backdoor_size(2, GridMetric::BackdoorSize, 0)]
             #[case::sat_step_count(0, GridMetric::SatStepCount, 1)]
             #[case::sat_step_count(1, GridMetric::SatStepCount, 1)]
             #[case::sat_step_count(2, GridMetric::SatStepCount, 1)]
+            #[case::sat_triads_step_count(0, GridMetric::SatTriadsStepCount, 1)]
+            #[case::sat_triads_step_count(1, GridMetric::SatTriadsStepCount, 1)]
+            #[case::sat_triads_step_count(2, GridMetric::SatTriadsStepCount, 1)]
             #[case::backtrack_count(0, GridMetric::BacktrackCount, 0)]
             #[case::backtrack_count(1, GridMetric::BacktrackCount, 0)]
             #[case::backtrack_count(2, GridMetric::BacktrackCount, 0)]
@@ -628,15 +663,19 @@ mod tests {
             #[case::strategy_application_count_single_naked_pairs(1, GridMetric::StrategyApplicationCountSingle {strategy: NakedPairs.into() }, 0)]
             #[case::strategy_application_count_single_naked_singles(6, GridMetric::StrategyApplicationCountSingle {strategy: NakedSingles.into() }, 19)]
             #[case::strategy_application_count_single_hidden_singles(6, GridMetric::StrategyApplicationCountSingle {strategy: HiddenSingles.into() }, 10)]
-            #[case::strategy_application_count_single_naked_pairs(6, GridMetric::StrategyApplicationCountSingle {strategy: NakedPairs.into() }, 1)]
-            #[case::strategy_application_count_single_locked_sets(6, GridMetric::StrategyApplicationCountSingle {strategy: LockedSets.into() }, 2)]
+            #[case::strategy_application_count_single_naked_pairs(6, GridMetric::StrategyApplicationCountSingle {strategy: NakedPairs.into() }, 0)]
+            #[case::strategy_application_count_single_locked_sets(6, GridMetric::StrategyApplicationCountSingle {strategy: LockedSets::default().into() }, 3)]
+            #[case::strategy_application_count_single_locked_naked_pairs(6, GridMetric::StrategyApplicationCountSingle {strategy: LockedSets::naked(2).into() }, 1)]
+            #[case::strategy_application_count_single_locked_hidden_pairs(6, GridMetric::StrategyApplicationCountSingle {strategy: LockedSets::hidden(2).into() }, 2)]
             #[case::strategy_application_count_single_group_intersection_both(6, GridMetric::StrategyApplicationCountSingle {strategy: GroupIntersectionBoth.into() }, 2)]
-            #[case::strategy_application_count_single_x_wing(6, GridMetric::StrategyApplicationCountSingle {strategy: XWing.into() }, 0)]
+            #[case::strategy_application_count_single_fish(6, GridMetric::StrategyApplicationCountSingle {strategy: Fish.into() }, 0)]
             #[case::strategy_deduction_count_any(1, GridMetric::StrategyDeductionCountAny, 47)]
             // TODO: StrategyDeductionCountSingle
-            #[case::strategy_average_options(1, GridMetric::StrategyAverageOptions, 4000)]
+            #[case::strategy_average_options(1, GridMetric::StrategyAverageOptions, 11750)]
+            #[case::backdoor_size(1, GridMetric::BackdoorSize, 0)]
             #[case::sat_step_count(0, GridMetric::SatStepCount, 77)]
             #[case::sat_step_count(1, GridMetric::SatStepCount, 1)]
+            #[case::sat_triads_step_count(1, GridMetric::SatTriadsStepCount, 1)]
             #[case::backtrack_count(0, GridMetric::BacktrackCount, 13357)]
             #[case::backtrack_count(1, GridMetric::BacktrackCount, 0)]
             #[case::grid_givens_count(0, GridMetric::GridGivensCount, 21)]
@@ -660,6 +699,30 @@ mod tests {
                     expected
                 );
             }
+
+            #[rstest]
+            #[case::within_limit(2, 2)]
+            #[case::at_limit(BACKDOOR_SIZE_LIMIT, BACKDOOR_SIZE_LIMIT)]
+            #[case::exceeds_limit(BACKDOOR_SIZE_LIMIT + 2, BACKDOOR_SIZE_LIMIT + 1)]
+            fn test_backdoor_size(#[case] empty_cell_count: usize, #[case] expected: usize) {
+                let mut grid = samples::base_2_solved();
+                grid.unfix_all_values();
+                for pos in grid
+                    .all_value_positions()
+                    .into_iter()
+                    .take(empty_cell_count)
+                {
+                    grid[pos].delete();
+                }
+
+                // Without strategies, each empty cell has to be revealed.
+                assert_eq!(
+                    GridMetric::BackdoorSize
+                        .evaluate(&grid, StrategySet::with_all(false))
+                        .unwrap(),
+                    EvaluatedGridMetric::try_from(expected).unwrap()
+                );
+            }
         }
     }
 
@@ -7,13 +7,14 @@ use crate::error::{Error, Result};
 use crate::grid::format::{CandidatesGridANSIStyled, GridFormat, GridFormatEnum};
 use crate::position::Coordinate;
 use crate::position::Position;
-use crate::solver::{FallibleSolver, introspective, strategic};
+use crate::solver::{FallibleSolver, SolverBackend, introspective, strategic};
 use crate::unsafe_utils::{get_unchecked, get_unchecked_mut};
 use crate::{base::SudokuBase, solver::strategic::strategies::selection::StrategySelection};
 use anyhow::ensure;
 use ndarray::{Array2, ArrayView2, ArrayViewMut2};
 use serde::{Deserialize, Serialize};
 use serialization::SerializedGrid;
+use solution::Solution;
 use solution_state::SolutionState;
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -25,6 +26,7 @@ pub mod deserialization;
 pub mod dynamic;
 pub mod format;
 pub mod group;
+pub mod solution;
 pub mod solution_state;
 
 mod serialization {
@@ -418,7 +420,7 @@ impl<Base: SudokuBase> Grid<Base> {
         self.solution_state().is_unique()
     }
 
-    pub fn unique_solution(&self) -> Option<Self> {
+    pub fn unique_solution(&self) -> Option<Solution<Base>> {
         self.solution_state().into_unique_solution()
     }
 
@@ -434,13 +436,30 @@ impl<Base: SudokuBase> Grid<Base> {
         solver.count()
     }
 
+    /// The number of solutions, but at most `limit`.
+    ///
+    /// In contrast to [`Self::solution_count`], the search stops as soon as `limit` solutions are found,
+    /// which keeps the runtime bounded for sparse grids.
+    pub fn count_solutions_up_to(&self, limit: usize) -> Result<usize> {
+        self.count_solutions_up_to_with_backend(limit, SolverBackend::default())
+    }
+
+    /// Like [`Self::count_solutions_up_to`], but with a specific solver backend.
+    pub fn count_solutions_up_to_with_backend(
+        &self,
+        limit: usize,
+        backend: SolverBackend,
+    ) -> Result<usize> {
+        crate::solver::count_solutions_up_to(self, limit, backend)
+    }
+
     /// Try to solve this grid using the given strategies.
     ///
     /// Returns the solution if it is solvable using the given strategies, otherwise returns None.
     pub fn is_solvable_with_strategies(
         &self,
         strategies: impl StrategySelection,
-    ) -> Result<Option<Self>> {
+    ) -> Result<Option<Solution<Base>>> {
         let mut clone = self.clone();
         clone.fix_all_values();
         clone.set_all_direct_candidates();
@@ -448,22 +467,6 @@ impl<Base: SudokuBase> Grid<Base> {
 
         solver.try_solve()
     }
-
-    pub fn assert_is_solution_for(&self, puzzle: &Self) {
-        assert!(
-            self.is_solved(),
-            "The solution should be solved, instead got:\n{self}"
-        );
-
-        for value_pos in puzzle.all_value_positions() {
-            let puzzle_value = puzzle[value_pos].value().unwrap();
-            let solution_value = self[value_pos].value().unwrap();
-            assert_eq!(
-                puzzle_value, solution_value,
-                "The returned solution is not a valid solution for the puzzle: different values at {value_pos}"
-            );
-        }
-    }
 }
 
 impl<Base: SudokuBase, T: Default + Clone> Default for Grid<Base, T> {
@@ -1156,6 +1159,21 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_count_solutions_up_to() {
+        let grid = samples::base_2().remove(0);
+        assert_eq!(grid.count_solutions_up_to(2).unwrap(), 1);
+
+        let grid = Grid::<Base2>::new();
+        assert_eq!(grid.count_solutions_up_to(10).unwrap(), 10);
+        assert_eq!(grid.count_solutions_up_to(usize::MAX).unwrap(), 288);
+        assert_eq!(
+            grid.count_solutions_up_to_with_backend(2, SolverBackend::Sat)
+                .unwrap(),
+            2
+        );
+    }
+
     #[test]
     fn test_unique_solution_for_fixed_values() {
         let mut grid = samples::base_2().into_iter().next().unwrap();
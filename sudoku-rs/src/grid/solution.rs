@@ -0,0 +1,234 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Index;
+
+use anyhow::{bail, ensure};
+use serde::{Deserialize, Serialize};
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Cell, Value};
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// A solved grid, which contains only values.
+///
+/// In contrast to `Grid<Base>`, a solution can't contain empty or candidates cells
+/// and has no notion of fixed values. Each cell requires a single byte.
+///
+/// # Invariants
+///
+/// Each row, column and block contains each value exactly once.
+#[derive(Eq, PartialEq, Hash, Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    bound = "Base: SudokuBase",
+    into = "Grid<Base, Value<Base>>",
+    try_from = "Grid<Base, Value<Base>>"
+)]
+pub struct Solution<Base: SudokuBase> {
+    values: Grid<Base, Value<Base>>,
+}
+
+/// Constructors
+impl<Base: SudokuBase> Solution<Base> {
+    pub fn new(values: Grid<Base, Value<Base>>) -> Result<Self> {
+        let solution = Self { values };
+        solution.validate()?;
+        Ok(solution)
+    }
+
+    /// Used by solvers, which guarantee the invariants by construction.
+    pub(crate) fn with_values_unchecked(values: Grid<Base, Value<Base>>) -> Self {
+        let solution = Self { values };
+        debug_assert!({
+            solution.validate().unwrap();
+            true
+        });
+        solution
+    }
+}
+
+impl<Base: SudokuBase> Solution<Base> {
+    /// Checks that each row, column and block contains each value exactly once.
+    pub fn validate(&self) -> Result<()> {
+        for (group_index, group) in self.values.all_group_cells().enumerate() {
+            let mut seen_values = Candidates::<Base>::new();
+            for &value in group {
+                ensure!(
+                    !seen_values.has(value),
+                    "Duplicate value {value} in group {group_index}"
+                );
+                seen_values.insert(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Each value of `puzzle` is equal to the value of this solution at the same position.
+    pub fn is_solution_for(&self, puzzle: &Grid<Base>) -> bool {
+        Position::<Base>::all()
+            .all(|pos| puzzle[pos].value().is_none_or(|value| value == self[pos]))
+    }
+
+    pub fn assert_is_solution_for(&self, puzzle: &Grid<Base>) {
+        assert!(
+            self.is_solution_for(puzzle),
+            "The solution is not a valid solution for the puzzle:\n{self}\nPuzzle:\n{puzzle}"
+        );
+    }
+
+    pub fn get(&self, pos: Position<Base>) -> Value<Base> {
+        *self.values.get(pos)
+    }
+
+    pub fn values(&self) -> &Grid<Base, Value<Base>> {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Grid<Base, Value<Base>> {
+        self.values
+    }
+
+    /// A grid containing the values of this solution, all values are unfixed.
+    pub fn to_grid(&self) -> Grid<Base> {
+        self.into()
+    }
+}
+
+impl<Base: SudokuBase> Index<Position<Base>> for Solution<Base> {
+    type Output = Value<Base>;
+
+    fn index(&self, pos: Position<Base>) -> &Self::Output {
+        self.values.get(pos)
+    }
+}
+
+impl<Base: SudokuBase> TryFrom<Grid<Base, Value<Base>>> for Solution<Base> {
+    type Error = Error;
+
+    fn try_from(values: Grid<Base, Value<Base>>) -> Result<Self> {
+        Self::new(values)
+    }
+}
+
+impl<Base: SudokuBase> From<Solution<Base>> for Grid<Base, Value<Base>> {
+    fn from(solution: Solution<Base>) -> Self {
+        solution.values
+    }
+}
+
+impl<Base: SudokuBase> TryFrom<&Grid<Base>> for Solution<Base> {
+    type Error = Error;
+
+    fn try_from(grid: &Grid<Base>) -> Result<Self> {
+        let values = Position::<Base>::all()
+            .map(|pos| {
+                let Some(value) = grid[pos].value() else {
+                    bail!("Expected a value at {pos}, instead got: {}", grid[pos]);
+                };
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::new(Grid::with(values)?)
+    }
+}
+
+impl<Base: SudokuBase> From<&Solution<Base>> for Grid<Base> {
+    fn from(solution: &Solution<Base>) -> Self {
+        Grid::with(
+            solution
+                .values
+                .all_cells()
+                .map(|&value| Cell::with_value(value, false))
+                .collect(),
+        )
+        .unwrap()
+    }
+}
+
+impl<Base: SudokuBase> From<Solution<Base>> for Grid<Base> {
+    fn from(solution: Solution<Base>) -> Self {
+        Self::from(&solution)
+    }
+}
+
+impl<Base: SudokuBase> Display for Solution<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_grid(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::*;
+
+    use super::*;
+
+    fn solved_grid() -> Grid<Base2> {
+        crate::samples::base_2_solved()
+    }
+
+    #[test]
+    fn test_try_from_grid() {
+        let grid = solved_grid();
+        let solution = Solution::try_from(&grid).unwrap();
+
+        for pos in Position::<Base2>::all() {
+            assert_eq!(Some(solution[pos]), grid[pos].value());
+            assert_eq!(solution.get(pos), solution[pos]);
+        }
+
+        let mut unfixed_grid = grid.clone();
+        unfixed_grid.unfix_all_values();
+        assert_eq!(solution.to_grid(), unfixed_grid);
+        assert_eq!(Grid::from(solution), unfixed_grid);
+    }
+
+    #[test]
+    fn test_try_from_grid_missing_value() {
+        let mut grid = solved_grid();
+        grid.unfix_all_values();
+        grid[Position::top_left()].delete();
+
+        Solution::try_from(&grid).unwrap_err();
+    }
+
+    #[test]
+    fn test_validate() {
+        let solution = Solution::try_from(&solved_grid()).unwrap();
+        solution.validate().unwrap();
+
+        let mut values = solution.into_values();
+        values[Position::top_left()] = values[Position::try_from((0, 1)).unwrap()];
+        Solution::new(values).unwrap_err();
+
+        Solution::new(Grid::<Base2, Value<Base2>>::new()).unwrap_err();
+    }
+
+    #[test]
+    fn test_is_solution_for() {
+        let grid = solved_grid();
+        let solution = Solution::try_from(&grid).unwrap();
+
+        assert!(solution.is_solution_for(&grid));
+        assert!(solution.is_solution_for(&Grid::new()));
+
+        let mut puzzle = Grid::<Base2>::new();
+        let pos = Position::top_left();
+        let other_value = Value::all().find(|&value| value != solution[pos]).unwrap();
+        puzzle[pos].set_value(other_value);
+        assert!(!solution.is_solution_for(&puzzle));
+    }
+
+    #[test]
+    fn test_serde() {
+        let solution = Solution::try_from(&solved_grid()).unwrap();
+
+        let json = serde_json::to_string(&solution).unwrap();
+        let deserialized: Solution<Base2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, solution);
+
+        let invalid_json = serde_json::to_string(&Grid::<Base2, Value<Base2>>::new()).unwrap();
+        serde_json::from_str::<Solution<Base2>>(&invalid_json).unwrap_err();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::grid::solution::Solution;
 use crate::{base::SudokuBase, grid::Grid, solver::introspective};
 use serde::{Deserialize, Serialize};
 
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 pub enum SolutionState<Base: SudokuBase> {
     NoSolution,
     MultipleSolutions,
-    UniqueSolution(Grid<Base>),
+    UniqueSolution(Solution<Base>),
 }
 
 impl<Base: SudokuBase> SolutionState<Base> {
@@ -24,17 +25,17 @@ impl<Base: SudokuBase> SolutionState<Base> {
         }
     }
 
-    pub fn into_unique_solution(self) -> Option<Grid<Base>> {
-        if let SolutionState::UniqueSolution(grid) = self {
-            Some(grid)
+    pub fn into_unique_solution(self) -> Option<Solution<Base>> {
+        if let SolutionState::UniqueSolution(solution) = self {
+            Some(solution)
         } else {
             None
         }
     }
 
-    pub fn as_unique_solution(&self) -> Option<&Grid<Base>> {
-        if let SolutionState::UniqueSolution(grid) = self {
-            Some(grid)
+    pub fn as_unique_solution(&self) -> Option<&Solution<Base>> {
+        if let SolutionState::UniqueSolution(solution) = self {
+            Some(solution)
         } else {
             None
         }
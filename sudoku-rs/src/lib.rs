@@ -45,6 +45,7 @@
 
 pub use crate::sudoku::*;
 
+pub mod analysis;
 pub mod base;
 pub mod cell;
 pub mod error;
@@ -4,7 +4,7 @@ use itertools::Either;
 
 use crate::base::SudokuBase;
 use crate::cell::Candidates;
-use crate::position::{BlockCoordinate, Coordinate, Position};
+use crate::position::{BlockCoordinate, Coordinate, GroupCoordinate, Position};
 
 /// The position of a block segment inside a sudoku grid.
 ///
@@ -154,6 +154,19 @@ impl<Base: SudokuBase> BlockSegment<Base> {
         self.block
     }
 
+    /// The row or column containing this block segment.
+    pub fn axis_group(self) -> GroupCoordinate<Base> {
+        match self.orientation {
+            CellOrder::RowMajor => GroupCoordinate::Row(self.axis()),
+            CellOrder::ColumnMajor => GroupCoordinate::Column(self.axis()),
+        }
+    }
+
+    /// The block containing this block segment.
+    pub fn block_group(self) -> GroupCoordinate<Base> {
+        GroupCoordinate::Block(self.block)
+    }
+
     pub fn axis_positions(self) -> impl Iterator<Item = Position<Base>> {
         let axis = self.axis();
         match self.orientation {
@@ -163,6 +163,14 @@ impl<Base: SudokuBase> Position<Base> {
         (self.to_row(), self.to_column())
     }
 
+    /// Returns `true`, if `other` is a different position, which shares a row, column or block with this position.
+    pub fn sees(self, other: Self) -> bool {
+        self != other
+            && (self.to_row() == other.to_row()
+                || self.to_column() == other.to_column()
+                || self.to_block() == other.to_block())
+    }
+
     /// Get the block coordinate of block containing this position,
     /// as well as the index of the cell inside the block as a row- and column-major block coordinate.
     ///
@@ -426,6 +434,23 @@ mod tests {
                     );
                 });
         }
+
+        #[test]
+        fn test_sees() {
+            let pos = |row: u8, column: u8| Position::<Base3>::try_from((row, column)).unwrap();
+
+            // Self
+            assert!(!pos(4, 4).sees(pos(4, 4)));
+            // Row
+            assert!(pos(4, 4).sees(pos(4, 0)));
+            // Column
+            assert!(pos(4, 4).sees(pos(8, 4)));
+            // Block
+            assert!(pos(4, 4).sees(pos(3, 5)));
+            // Unrelated
+            assert!(!pos(4, 4).sees(pos(0, 0)));
+            assert!(!pos(4, 4).sees(pos(2, 5)));
+        }
     }
 
     mod iterators {
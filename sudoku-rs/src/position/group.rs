@@ -1,19 +1,129 @@
-#![allow(dead_code)]
+use std::fmt::{self, Display};
+
+use itertools::{Either, Itertools};
 
 use crate::base::SudokuBase;
+use crate::position::{Coordinate, Position};
+
+/// References a single row, column or block of a grid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum GroupCoordinate<Base: SudokuBase> {
+    Row(Coordinate<Base>),
+    Column(Coordinate<Base>),
+    Block(Coordinate<Base>),
+}
+
+impl<Base: SudokuBase> Display for GroupCoordinate<Base> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupCoordinate::Row(row) => write!(f, "row {row}"),
+            GroupCoordinate::Column(column) => write!(f, "column {column}"),
+            GroupCoordinate::Block(block) => write!(f, "block {block}"),
+        }
+    }
+}
+
+impl<Base: SudokuBase> GroupCoordinate<Base> {
+    /// The positions of the referenced group.
+    ///
+    /// Block positions are yielded in row-major order.
+    pub fn positions(self) -> impl Iterator<Item = Position<Base>> {
+        match self {
+            GroupCoordinate::Row(row) => Either::Left(Position::row(row)),
+            GroupCoordinate::Column(column) => {
+                Either::Right(Either::Left(Position::column(column)))
+            }
+            GroupCoordinate::Block(block) => Either::Right(Either::Right(Position::block(block))),
+        }
+    }
+
+    /// The row, column and block containing `pos`.
+    pub fn all_containing(pos: Position<Base>) -> [Self; 3] {
+        [
+            GroupCoordinate::Row(pos.to_row()),
+            GroupCoordinate::Column(pos.to_column()),
+            GroupCoordinate::Block(pos.to_block()),
+        ]
+    }
+
+    /// The first row, column or block containing all `positions`.
+    pub fn containing_all(positions: impl IntoIterator<Item = Position<Base>>) -> Option<Self> {
+        let positions = positions.into_iter().collect_vec();
+
+        Self::all_containing(*positions.first()?)
+            .into_iter()
+            .find(|group| positions.iter().all(|&pos| group.contains(pos)))
+    }
+
+    pub fn contains(self, pos: Position<Base>) -> bool {
+        match self {
+            GroupCoordinate::Row(row) => pos.to_row() == row,
+            GroupCoordinate::Column(column) => pos.to_column() == column,
+            GroupCoordinate::Block(block) => pos.to_block() == block,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::*;
+
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let coordinate = Coordinate::<Base2>::new(1).unwrap();
 
-use super::Coordinate;
+        for (group, expected_positions) in [
+            (
+                GroupCoordinate::Row(coordinate),
+                Position::row(coordinate).collect_vec(),
+            ),
+            (
+                GroupCoordinate::Column(coordinate),
+                Position::column(coordinate).collect_vec(),
+            ),
+            (
+                GroupCoordinate::Block(coordinate),
+                Position::block(coordinate).collect_vec(),
+            ),
+        ] {
+            assert_eq!(group.positions().collect_vec(), expected_positions);
+            for pos in Position::all() {
+                assert_eq!(group.contains(pos), expected_positions.contains(&pos));
+            }
+        }
+    }
 
-// TODO: naming: Group Pointer|Index|Coordinate
-// TODO: implement
-// TODO: integrate/interop with Grid/Position etc
-struct RowCoordinate<Base: SudokuBase>(Coordinate<Base>);
-struct ColumnCoordinate<Base: SudokuBase>(Coordinate<Base>);
-struct BoxCoordinate<Base: SudokuBase>(Coordinate<Base>);
+    #[test]
+    fn test_containing_all() {
+        let pos = |row, column| Position::<Base2>::try_from((row, column)).unwrap();
+        let coordinate = |coordinate| Coordinate::<Base2>::new(coordinate).unwrap();
 
-// Could be used for indicating the current group in all_group_positions
-enum GroupCoordinate<Base: SudokuBase> {
-    Row(RowCoordinate<Base>),
-    Column(ColumnCoordinate<Base>),
-    Box(BoxCoordinate<Base>),
+        assert_eq!(
+            GroupCoordinate::all_containing(pos(1, 2)),
+            [
+                GroupCoordinate::Row(coordinate(1)),
+                GroupCoordinate::Column(coordinate(2)),
+                GroupCoordinate::Block(coordinate(1)),
+            ]
+        );
+        assert_eq!(GroupCoordinate::<Base2>::containing_all([]), None);
+        assert_eq!(
+            GroupCoordinate::containing_all([pos(1, 2), pos(1, 0)]),
+            Some(GroupCoordinate::Row(coordinate(1)))
+        );
+        assert_eq!(
+            GroupCoordinate::containing_all([pos(1, 2), pos(3, 2)]),
+            Some(GroupCoordinate::Column(coordinate(2)))
+        );
+        assert_eq!(
+            GroupCoordinate::containing_all([pos(1, 2), pos(0, 3)]),
+            Some(GroupCoordinate::Block(coordinate(1)))
+        );
+        assert_eq!(
+            GroupCoordinate::containing_all([pos(1, 2), pos(2, 0)]),
+            None
+        );
+    }
 }
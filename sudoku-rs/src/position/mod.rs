@@ -12,6 +12,7 @@ pub use bounded_block_segment::{BlockSegment, CellOrder};
 pub use bounded_coordinate::Coordinate;
 pub use bounded_position::Position;
 pub use dynamic::DynamicPosition;
+pub use group::GroupCoordinate;
 pub use position_map::{Merge, PositionMap};
 
 mod bounded_block_coordinate;
@@ -99,6 +99,10 @@ impl<Base: SudokuBase, T: Merge> PositionMap<Base, T> {
         self.map.iter().map(|(pos, value)| (*pos, value))
     }
 
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.map.is_empty()
     }
@@ -9,13 +9,18 @@ use log::trace;
 pub use builder::SolverBuilder;
 use group_availability::GroupAvailability;
 pub use group_availability::GroupAvailabilityIndex;
+#[cfg(feature = "parallel")]
+pub use parallel::DEFAULT_SPLIT_DEPTH;
 
 use crate::base::SudokuBase;
-use crate::cell::{Candidates, CandidatesAscIter, CandidatesIterator, CandidatesRandIter};
+use crate::cell::{Candidates, CandidatesAscIter, CandidatesIterator, CandidatesRandIter, Value};
+use crate::error::Result;
 use crate::grid::Grid;
+use crate::grid::solution::Solution;
 use crate::position::Position;
 use crate::rng::CrateRng;
-use crate::solver::InfallibleSolver;
+use crate::solver::control::{AbortReason, SolverControl};
+use crate::solver::{ControlledSolver, InfallibleSolver, SolveOutcome};
 
 pub(crate) mod candidates_filter;
 pub(crate) mod group_availability;
@@ -52,6 +57,10 @@ pub struct Solver<
     pub backtrack_count: u64,
 
     has_returned_pre_filled_grid_solution: bool,
+
+    /// Limits the solver, a step is a backtrack.
+    control: SolverControl,
+    abort_reason: Option<AbortReason>,
 }
 
 mod builder {
@@ -67,6 +76,7 @@ mod builder {
         grid: GridRef,
         availability: GroupAvailability<Base, Filter>,
         candidates_iter_init_context: ICandidates::InitContext,
+        control: SolverControl,
     }
 
     impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>>
@@ -77,6 +87,7 @@ mod builder {
                 grid,
                 availability: GroupAvailability::all(),
                 candidates_iter_init_context: (),
+                control: SolverControl::default(),
             }
         }
     }
@@ -96,12 +107,14 @@ mod builder {
                 grid,
                 availability,
                 candidates_iter_init_context: (),
+                control,
             } = self;
 
             SolverBuilder {
                 grid,
                 availability,
                 candidates_iter_init_context: rng,
+                control,
             }
         }
     }
@@ -118,12 +131,14 @@ mod builder {
                 grid,
                 availability,
                 candidates_iter_init_context,
+                control,
             } = self;
 
             SolverBuilder {
                 grid,
                 availability: availability.with_filter(filter),
                 candidates_iter_init_context,
+                control,
             }
         }
     }
@@ -135,13 +150,23 @@ mod builder {
         Filter: CandidatesFilter<Base>,
     > SolverBuilder<Base, GridRef, ICandidates, Filter>
     {
+        /// Abort the solver once a limit of `control` is reached. A step is a backtrack.
+        ///
+        /// See [`ControlledSolver`].
+        #[must_use]
+        pub fn control(mut self, control: SolverControl) -> Self {
+            self.control = control;
+            self
+        }
+
         pub fn build(self) -> Solver<Base, GridRef, ICandidates, Filter> {
             let SolverBuilder {
                 grid,
                 availability,
                 candidates_iter_init_context,
+                control,
             } = self;
-            Solver::with(grid, availability, candidates_iter_init_context)
+            Solver::with(grid, availability, candidates_iter_init_context, control)
         }
     }
 }
@@ -160,7 +185,7 @@ impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>>
 }
 
 enum StepResult<Base: SudokuBase> {
-    Solution(Grid<Base>),
+    Solution(Solution<Base>),
     NextCell,
     Backtrack,
     Done,
@@ -177,6 +202,7 @@ impl<
         grid: GridRef,
         availability: GroupAvailability<Base, Filter>,
         candidates_iter_init_context: ICandidates::InitContext,
+        control: SolverControl,
     ) -> Self {
         let mut this = Self {
             grid,
@@ -186,6 +212,8 @@ impl<
             candidates_iter_init_context,
             backtrack_count: 0,
             has_returned_pre_filled_grid_solution: false,
+            control,
+            abort_reason: None,
         };
 
         this.initialize();
@@ -197,6 +225,11 @@ impl<
         self.grid.as_ref()
     }
 
+    /// The reason why the solver was aborted by its [`SolverControl`], if any.
+    pub fn abort_reason(&self) -> Option<AbortReason> {
+        self.abort_reason
+    }
+
     fn initialize(&mut self) {
         for pos in Position::<Base>::all() {
             let index: GroupAvailabilityIndex<Base> = pos.into();
@@ -267,19 +300,21 @@ impl<
         }
     }
 
-    fn build_solution_grid(&self) -> Grid<Base> {
-        let mut solution_grid = self.grid().clone();
+    fn build_solution(&self) -> Solution<Base> {
+        let mut solution_values = Grid::<Base, Value<Base>>::new();
+        for pos in Position::<Base>::all() {
+            if let Some(value) = self.grid()[pos].value() {
+                solution_values[pos] = value;
+            }
+        }
         for (candidates_iter, choice_index) in self
             .candidates_iters
             .iter()
             .zip(self.availability_indexes.iter())
         {
-            solution_grid
-                .get_mut((*choice_index).into())
-                .set_value(candidates_iter.peek().unwrap());
+            solution_values[(*choice_index).into()] = candidates_iter.peek().unwrap();
         }
-        debug_assert!(solution_grid.is_solved());
-        solution_grid
+        Solution::with_values_unchecked(solution_values)
     }
 
     fn step(&mut self) -> StepResult<Base> {
@@ -290,13 +325,13 @@ impl<
 
                 if self.candidates_iters.len() == self.availability_indexes.len() {
                     // Found solution
-                    let solution_grid = self.build_solution_grid();
+                    let solution = self.build_solution();
 
                     // Continue at next candidate
                     self.candidates_iters.last_mut().unwrap().next();
                     self.availability.insert(choice_index, candidate);
 
-                    StepResult::Solution(solution_grid)
+                    StepResult::Solution(solution)
                 } else {
                     // Next cell
                     let next_i = self.candidates_iters.len();
@@ -325,9 +360,8 @@ impl<
         {
             self.has_returned_pre_filled_grid_solution = true;
 
-            let grid = self.grid();
-            if grid.is_solved() {
-                StepResult::Solution(grid.clone())
+            if self.grid().is_solved() {
+                StepResult::Solution(self.build_solution())
             } else {
                 StepResult::Done
             }
@@ -344,17 +378,47 @@ impl<
     Filter: CandidatesFilter<Base>,
 > InfallibleSolver<Base> for Solver<Base, GridRef, ICandidates, Filter>
 {
-    fn solve(&mut self) -> Option<Grid<Base>> {
+    /// Returns `None` once the solver is aborted, see [`Self::abort_reason`].
+    fn solve(&mut self) -> Option<Solution<Base>> {
+        if self.abort_reason.is_some() {
+            return None;
+        }
+        // Abort without a single step, e.g. if cancelled before solving.
+        if let Err(abort_reason) = self.control.check(self.backtrack_count) {
+            self.abort_reason = Some(abort_reason);
+            return None;
+        }
+
         loop {
             match self.step() {
                 StepResult::Solution(solution) => return Some(solution),
                 StepResult::Done => return None,
-                _ => {}
+                StepResult::Backtrack => {
+                    if let Err(abort_reason) = self.control.check(self.backtrack_count) {
+                        self.abort_reason = Some(abort_reason);
+                        return None;
+                    }
+                }
+                StepResult::NextCell => {}
             }
         }
     }
 }
 
+impl<
+    Base: SudokuBase,
+    GridRef: AsRef<Grid<Base>>,
+    ICandidates: CandidatesIterator<Base>,
+    Filter: CandidatesFilter<Base>,
+> ControlledSolver<Base> for Solver<Base, GridRef, ICandidates, Filter>
+{
+    fn solve_controlled(&mut self) -> Result<SolveOutcome<Base>> {
+        let solution = self.solve();
+
+        Ok(SolveOutcome::new(solution, self.abort_reason))
+    }
+}
+
 impl<
     Base: SudokuBase,
     GridRef: AsRef<Grid<Base>>,
@@ -362,7 +426,7 @@ impl<
     Filter: CandidatesFilter<Base>,
 > Iterator for Solver<Base, GridRef, ICandidates, Filter>
 {
-    type Item = Grid<Base>;
+    type Item = Solution<Base>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.solve()
@@ -504,6 +568,11 @@ mod parallel {
 
     use super::*;
 
+    /// The default split depth of [`Solver::any_solution`].
+    ///
+    /// Tuned for `Base4`, seq: 314ms => pre split: 54.5ms
+    pub const DEFAULT_SPLIT_DEPTH: u8 = 12;
+
     impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, ICandidates: CandidatesIterator<Base>>
         IntoParallelIterator for Solver<Base, GridRef, ICandidates, DeniedCandidatesGrid<Base>>
     where
@@ -526,18 +595,45 @@ mod parallel {
             self.any_solution().is_some()
         }
 
-        pub fn any_solution(self) -> Option<Grid<Base>> {
-            self.any_solution_pre_split()
+        pub fn any_solution(self) -> Option<Solution<Base>> {
+            self.any_solution_with_split_depth(DEFAULT_SPLIT_DEPTH)
         }
 
-        fn pre_split_solvers(self) -> Vec<Self> {
+        pub fn any_solution_with_split_depth(self, split_depth: u8) -> Option<Solution<Base>> {
+            self.pre_split_solvers(split_depth)
+                .into_par_iter()
+                .find_map_any(|mut solver| solver.next())
+        }
+
+        /// All solutions, enumerated in parallel. The order of the solutions is unspecified.
+        ///
+        /// The solver is split `split_depth` times up front. Afterwards, the resulting solvers are
+        /// split further on demand, whenever an idle rayon thread steals work.
+        pub fn par_solutions(
+            self,
+            split_depth: u8,
+        ) -> impl ParallelIterator<Item = Solution<Base>> {
+            self.pre_split_solvers(split_depth)
+                .into_par_iter()
+                .flat_map(IntoParallelIterator::into_par_iter)
+                .flat_map_iter(|solver| solver)
+        }
+
+        /// Counts all solutions in parallel, see [`Self::par_solutions`].
+        pub fn par_count_solutions(self, split_depth: u8) -> u64 {
+            self.pre_split_solvers(split_depth)
+                .into_par_iter()
+                .flat_map(IntoParallelIterator::into_par_iter)
+                .map(|solver| solver.count() as u64)
+                .sum()
+        }
+
+        /// Splits the solver up to `split_depth` times, resulting in at most `2^split_depth` solvers
+        /// with distinct search spaces.
+        pub fn pre_split_solvers(self, split_depth: u8) -> Vec<Self> {
             let mut split_solvers = vec![self];
 
-            // TODO: expose initial parallel factor
-            //  define for each base?
-            // current value tuned for Base4
-            // seq: 314ms => any_solution_pre_split: 54.5ms
-            for _ in 0..12 {
+            for _ in 0..split_depth {
                 split_solvers = split_solvers
                     .into_iter()
                     .flat_map(|solver| {
@@ -549,13 +645,6 @@ mod parallel {
             }
             split_solvers
         }
-
-        fn any_solution_pre_split(self) -> Option<Grid<Base>> {
-            let split_solvers = self.pre_split_solvers();
-            split_solvers
-                .into_par_iter()
-                .find_map_any(|mut solver| solver.next())
-        }
     }
 }
 
@@ -667,12 +756,82 @@ mod tests {
         let solver = Solver::builder(&grid).candidates_filter(denylist).build();
 
         for solution in solver.clone() {
-            assert!(![1, 3].contains(&solution.get(Position::default()).value().unwrap().get()));
+            assert!(![1, 3].contains(&solution.get(Position::default()).get()));
         }
 
         assert_eq!(solver.count(), 144);
     }
 
+    /// Solves until no solution is returned, returns the number of solutions and the final outcome.
+    fn solve_controlled_all<Base: SudokuBase>(
+        solver: &mut impl ControlledSolver<Base>,
+    ) -> (usize, SolveOutcome<Base>) {
+        let mut solution_count = 0;
+        loop {
+            match solver.solve_controlled().unwrap() {
+                SolveOutcome::Solution(_) => solution_count += 1,
+                outcome => return (solution_count, outcome),
+            }
+        }
+    }
+
+    #[test]
+    fn test_control_default() {
+        let grid = Grid::<Base2>::new();
+        let mut solver = Solver::builder(&grid)
+            .control(SolverControl::default())
+            .build();
+
+        assert_eq!(
+            solve_controlled_all(&mut solver),
+            (288, SolveOutcome::NoSolution)
+        );
+    }
+
+    #[test]
+    fn test_control_max_steps() {
+        let grid = Grid::<Base2>::new();
+        let mut solver = Solver::builder(&grid)
+            .control(SolverControl::new().with_max_steps(10))
+            .build();
+
+        let (solution_count, outcome) = solve_controlled_all(&mut solver);
+        assert!(solution_count < 288);
+        assert_eq!(
+            outcome,
+            SolveOutcome::Aborted(AbortReason::StepBudgetExhausted)
+        );
+        assert_eq!(solver.backtrack_count, 11);
+
+        // The solver stays aborted
+        assert_eq!(solver.next(), None);
+        assert_eq!(
+            solver.abort_reason(),
+            Some(AbortReason::StepBudgetExhausted)
+        );
+    }
+
+    #[test]
+    fn test_control_cancellation_token() {
+        use crate::solver::control::CancellationToken;
+
+        let grid = Grid::<Base2>::new();
+        let cancellation_token = CancellationToken::new();
+        let mut solver = Solver::builder(&grid)
+            .control(SolverControl::new().with_cancellation_token(cancellation_token.clone()))
+            .build();
+
+        assert!(matches!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Solution(_)
+        ));
+        cancellation_token.cancel();
+        assert_eq!(
+            solve_controlled_all(&mut solver).1,
+            SolveOutcome::Aborted(AbortReason::Cancelled)
+        );
+    }
+
     fn assert_single_solution_with_split<Base: SudokuBase>(
         puzzle: &Grid<Base>,
         assert_is_splittable: bool,
@@ -781,4 +940,61 @@ mod tests {
                 .into_iter(),
         );
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_solutions() {
+        type Base = Base2;
+
+        use rayon::prelude::*;
+
+        let grid = Grid::<Base>::new();
+
+        for split_depth in [0, 1, 4, DEFAULT_SPLIT_DEPTH] {
+            let solver = Solver::builder(&grid)
+                .candidates_filter(Grid::new())
+                .build();
+
+            assert_infallible_solution_iter_all_solutions_base_2(
+                solver
+                    .par_solutions(split_depth)
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            );
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_count_solutions() {
+        let grid = Grid::<Base2>::new();
+        for split_depth in [0, 1, 4, DEFAULT_SPLIT_DEPTH] {
+            let solver = Solver::builder(&grid)
+                .candidates_filter(Grid::new())
+                .build();
+            assert_eq!(solver.par_count_solutions(split_depth), 288);
+        }
+
+        for grid in crate::samples::base_3() {
+            let solver = Solver::builder(&grid)
+                .candidates_filter(Grid::new())
+                .build();
+            assert_eq!(solver.par_count_solutions(4), 1);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_pre_split_solvers() {
+        let grid = Grid::<Base2>::new();
+        let solver = Solver::builder(&grid)
+            .candidates_filter(Grid::new())
+            .build();
+
+        assert_eq!(solver.clone().pre_split_solvers(0).len(), 1);
+
+        let split_solvers = solver.pre_split_solvers(3);
+        assert_eq!(split_solvers.len(), 8);
+        assert_infallible_solution_iter_all_solutions_base_2(split_solvers.into_iter().flatten());
+    }
 }
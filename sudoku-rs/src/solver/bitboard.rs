@@ -0,0 +1,515 @@
+//! A specialized solver for [`Base3`] sudokus using bitboards,
+//! in the style of [tdoku](https://github.com/t-dillon/tdoku) and
+//! [JCZSolve](http://forum.enjoysudoku.com/3-77us-solver-2-8g-cpu-testcase-17sodoku-t30470-210.html#p249309).
+//!
+//! For each digit, the candidates of a band (three consecutive rows) are stored in the lower 27 bits of a `u32`,
+//! where bit `9 * row_in_band + column` represents a cell.
+//! The cell of a placed digit keeps its bit in the bitboard of this digit.
+//!
+//! Propagation combines naked singles, hidden singles and locked candidates,
+//! before guessing a candidate in a cell with the fewest candidates.
+
+use crate::base::consts::Base3;
+use crate::cell::Value;
+use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::position::Position;
+use crate::solver::InfallibleSolver;
+
+const DIGIT_COUNT: usize = 9;
+const BAND_COUNT: usize = 3;
+const CELLS_PER_BAND: usize = 27;
+
+/// All cells of a band.
+const BAND_MASK: u32 = (1 << CELLS_PER_BAND) - 1;
+
+/// The cells of each row within a band.
+const ROW_MASKS: [u32; 3] = [0x1FF, 0x1FF << 9, 0x1FF << 18];
+
+/// The cells of each block within a band.
+const BLOCK_MASKS: [u32; 3] = [0o007_007_007, 0o007_007_007 << 3, 0o007_007_007 << 6];
+
+/// The cells of each column within a band.
+const COLUMN_MASKS: [u32; 9] = {
+    let mut masks = [0; 9];
+    let mut column = 0;
+    while column < 9 {
+        masks[column] = 0o001_001_001 << column;
+        column += 1;
+    }
+    masks
+};
+
+/// For each pattern of occupied intersections in a 3x3 matrix (bit `3 * i + j`),
+/// the intersections which are part of a permutation matrix contained in the pattern.
+///
+/// A digit occupies exactly one intersection of each row and block in a band,
+/// and of each band and column in a stack. Intersections outside of every permutation can be eliminated,
+/// which implements the locked candidates strategy (pointing and claiming).
+const PERMUTATION_MASKS: [u16; 512] = {
+    const PERMUTATIONS: [[usize; 3]; 6] = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+
+    let mut masks = [0; 512];
+    let mut pattern: u16 = 0;
+    while pattern < 512 {
+        let mut i = 0;
+        while i < PERMUTATIONS.len() {
+            let [j0, j1, j2] = PERMUTATIONS[i];
+            let permutation: u16 = (1 << j0) | (1 << (3 + j1)) | (1 << (6 + j2));
+            if pattern & permutation == permutation {
+                masks[pattern as usize] |= permutation;
+            }
+            i += 1;
+        }
+        pattern += 1;
+    }
+    masks
+};
+
+/// The search reached a state without a solution.
+#[derive(Debug)]
+struct Contradiction;
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// For each digit and band, the cells which can contain the digit.
+    candidates: [[u32; BAND_COUNT]; DIGIT_COUNT],
+    /// For each band, the cells without a placed digit.
+    unsolved: [u32; BAND_COUNT],
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            candidates: [[BAND_MASK; BAND_COUNT]; DIGIT_COUNT],
+            unsolved: [BAND_MASK; BAND_COUNT],
+        }
+    }
+
+    fn is_solved(&self) -> bool {
+        self.unsolved.iter().all(|&unsolved| unsolved == 0)
+    }
+
+    /// Places `digit` in `cell` of `band` and removes it from all other cells in the same row, column and block.
+    fn place(&mut self, digit: usize, band: usize, cell: usize) -> Result<(), Contradiction> {
+        let bit = 1 << cell;
+        if self.candidates[digit][band] & bit == 0 {
+            return Err(Contradiction);
+        }
+
+        for digit_candidates in &mut self.candidates {
+            digit_candidates[band] &= !bit;
+        }
+
+        let column = cell % 9;
+        let digit_candidates = &mut self.candidates[digit];
+        for band_candidates in digit_candidates.iter_mut() {
+            *band_candidates &= !COLUMN_MASKS[column];
+        }
+        digit_candidates[band] &= !(ROW_MASKS[cell / 9] | BLOCK_MASKS[column / 3]);
+        digit_candidates[band] |= bit;
+
+        self.unsolved[band] &= !bit;
+
+        Ok(())
+    }
+
+    /// Applies naked singles, hidden singles and locked candidates, until no further progress can be made.
+    fn propagate(&mut self) -> Result<(), Contradiction> {
+        loop {
+            if self.place_naked_singles()? || self.place_hidden_singles()? {
+                continue;
+            }
+            if !self.eliminate_locked_candidates()? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Returns `true` if at least one digit was placed.
+    fn place_naked_singles(&mut self) -> Result<bool, Contradiction> {
+        let mut has_placed = false;
+
+        for band in 0..BAND_COUNT {
+            let [at_least_one, at_least_two, _] = self.candidate_counts(band);
+
+            if self.unsolved[band] & !at_least_one != 0 {
+                return Err(Contradiction);
+            }
+
+            let mut singles = self.unsolved[band] & !at_least_two;
+            while singles != 0 {
+                let cell = singles.trailing_zeros() as usize;
+                singles &= singles - 1;
+
+                let digit = (0..DIGIT_COUNT)
+                    .find(|&digit| self.candidates[digit][band] & (1 << cell) != 0)
+                    .ok_or(Contradiction)?;
+                self.place(digit, band, cell)?;
+                has_placed = true;
+            }
+        }
+
+        Ok(has_placed)
+    }
+
+    /// Returns `true` if at least one digit was placed.
+    fn place_hidden_singles(&mut self) -> Result<bool, Contradiction> {
+        let mut has_placed = false;
+
+        for digit in 0..DIGIT_COUNT {
+            // A placed digit has no other candidates in its groups,
+            // therefore a group with a single unsolved candidate contains a hidden single.
+            for band in 0..BAND_COUNT {
+                for group_mask in ROW_MASKS.into_iter().chain(BLOCK_MASKS) {
+                    let cells = self.candidates[digit][band] & group_mask;
+                    if cells == 0 {
+                        return Err(Contradiction);
+                    }
+                    let unsolved_cells = cells & self.unsolved[band];
+                    if unsolved_cells.is_power_of_two() {
+                        self.place(digit, band, unsolved_cells.trailing_zeros() as usize)?;
+                        has_placed = true;
+                    }
+                }
+            }
+
+            let mut columns = 0;
+            let mut unsolved_columns = [0; 2];
+            for band in 0..BAND_COUNT {
+                let band_candidates = self.candidates[digit][band];
+                let unsolved_candidates = band_candidates & self.unsolved[band];
+                columns |= fold_rows(band_candidates);
+                for row in 0..3 {
+                    let row_candidates = (unsolved_candidates >> (9 * row)) & ROW_MASKS[0];
+                    unsolved_columns[1] |= unsolved_columns[0] & row_candidates;
+                    unsolved_columns[0] |= row_candidates;
+                }
+            }
+            if columns != ROW_MASKS[0] {
+                return Err(Contradiction);
+            }
+
+            let mut single_columns = unsolved_columns[0] & !unsolved_columns[1];
+            while single_columns != 0 {
+                let column_mask = COLUMN_MASKS[single_columns.trailing_zeros() as usize];
+                single_columns &= single_columns - 1;
+
+                if let Some(band) = (0..BAND_COUNT).find(|&band| {
+                    self.candidates[digit][band] & self.unsolved[band] & column_mask != 0
+                }) {
+                    let cell = self.candidates[digit][band] & column_mask;
+                    self.place(digit, band, cell.trailing_zeros() as usize)?;
+                    has_placed = true;
+                }
+            }
+        }
+
+        Ok(has_placed)
+    }
+
+    /// Returns `true` if at least one candidate was eliminated.
+    fn eliminate_locked_candidates(&mut self) -> Result<bool, Contradiction> {
+        let mut has_eliminated = false;
+
+        for digit in 0..DIGIT_COUNT {
+            // Intersections of rows and blocks within a band.
+            for band in 0..BAND_COUNT {
+                let pattern = band_pattern(self.candidates[digit][band]);
+                let eliminated = non_permutation_intersections(pattern)?;
+                for index in bit_indexes(eliminated) {
+                    self.candidates[digit][band] &=
+                        !(ROW_MASKS[index / 3] & BLOCK_MASKS[index % 3]);
+                }
+                has_eliminated |= eliminated != 0;
+            }
+
+            // Intersections of bands and columns within a stack.
+            let columns_per_band = self.candidates[digit].map(fold_rows);
+            for stack in 0..3 {
+                let pattern = (0..BAND_COUNT).fold(0, |pattern, band| {
+                    pattern | (((columns_per_band[band] >> (3 * stack)) & 0b111) << (3 * band))
+                });
+                let eliminated = non_permutation_intersections(pattern)?;
+                for index in bit_indexes(eliminated) {
+                    self.candidates[digit][index / 3] &= !COLUMN_MASKS[3 * stack + index % 3];
+                }
+                has_eliminated |= eliminated != 0;
+            }
+        }
+
+        Ok(has_eliminated)
+    }
+
+    /// The cells of `band` with at least one, two and three candidates.
+    fn candidate_counts(&self, band: usize) -> [u32; 3] {
+        let mut counts = [0; 3];
+        for digit_candidates in &self.candidates {
+            let band_candidates = digit_candidates[band];
+            counts[2] |= counts[1] & band_candidates;
+            counts[1] |= counts[0] & band_candidates;
+            counts[0] |= band_candidates;
+        }
+        counts
+    }
+
+    /// A candidate `(digit, band, cell)` of an unsolved cell with the fewest candidates.
+    ///
+    /// Returns `None` if all cells are solved.
+    fn choose_guess(&self) -> Option<(usize, usize, usize)> {
+        let candidate_count = |band: usize, cell: usize| {
+            self.candidates
+                .iter()
+                .filter(|digit_candidates| digit_candidates[band] & (1 << cell) != 0)
+                .count()
+        };
+
+        // Cells with exactly two candidates are the most common choice, and can be found without counting.
+        let bi_value_cell = (0..BAND_COUNT).find_map(|band| {
+            let [_, at_least_two, at_least_three] = self.candidate_counts(band);
+            let bi_value_cells = self.unsolved[band] & at_least_two & !at_least_three;
+            (bi_value_cells != 0).then(|| (band, bi_value_cells.trailing_zeros() as usize))
+        });
+
+        let (band, cell) = bi_value_cell.or_else(|| {
+            (0..BAND_COUNT)
+                .flat_map(|band| {
+                    (0..CELLS_PER_BAND)
+                        .filter(move |&cell| self.unsolved[band] & (1 << cell) != 0)
+                        .map(move |cell| (band, cell))
+                })
+                .min_by_key(|&(band, cell)| candidate_count(band, cell))
+        })?;
+
+        let digit = (0..DIGIT_COUNT)
+            .find(|&digit| self.candidates[digit][band] & (1 << cell) != 0)
+            .unwrap();
+
+        Some((digit, band, cell))
+    }
+
+    /// The digit placed in `cell` of `band`.
+    fn placed_digit(&self, band: usize, cell: usize) -> usize {
+        debug_assert_eq!(self.unsolved[band] & (1 << cell), 0);
+
+        (0..DIGIT_COUNT)
+            .find(|&digit| self.candidates[digit][band] & (1 << cell) != 0)
+            .unwrap()
+    }
+}
+
+/// The columns containing at least one of `band_cells`.
+fn fold_rows(band_cells: u32) -> u32 {
+    (band_cells | (band_cells >> 9) | (band_cells >> 18)) & ROW_MASKS[0]
+}
+
+/// The intersections of rows and blocks containing at least one of `band_cells`,
+/// as a pattern for [`PERMUTATION_MASKS`].
+fn band_pattern(band_cells: u32) -> u32 {
+    let segments = band_cells | (band_cells >> 1) | (band_cells >> 2);
+    // The first cell of the intersection `(row, block)` is `9 * row + 3 * block`.
+    (0..9).fold(0, |pattern, index| {
+        pattern | (((segments >> (3 * index)) & 1) << index)
+    })
+}
+
+/// The intersections of `pattern`, which are not part of any permutation.
+fn non_permutation_intersections(pattern: u32) -> Result<u32, Contradiction> {
+    let permutations = u32::from(PERMUTATION_MASKS[pattern as usize]);
+    if permutations == 0 {
+        return Err(Contradiction);
+    }
+    Ok(pattern & !permutations)
+}
+
+/// The indexes of the set bits of `mask`, in ascending order.
+fn bit_indexes(mut mask: u32) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        (mask != 0).then(|| {
+            let index = mask.trailing_zeros() as usize;
+            mask &= mask - 1;
+            index
+        })
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Solver<GridRef: AsRef<Grid<Base3>>> {
+    /// Grid to be solved
+    grid: GridRef,
+    /// States which remain to be searched, the last state is searched next.
+    ///
+    /// The states are disjoint, each one is either a guess or the negation of a guess.
+    stack: Vec<State>,
+    pub backtrack_count: u64,
+}
+
+impl<GridRef: AsRef<Grid<Base3>>> Solver<GridRef> {
+    pub fn new(grid: GridRef) -> Self {
+        let mut state = State::new();
+
+        let is_valid = grid.as_ref().all_value_positions().into_iter().all(|pos| {
+            let (band, cell) = Self::pos_to_band_and_cell(pos);
+            let digit = usize::from(grid.as_ref()[pos].value().unwrap().get() - 1);
+            state.place(digit, band, cell).is_ok()
+        });
+
+        Self {
+            grid,
+            stack: if is_valid { vec![state] } else { vec![] },
+            backtrack_count: 0,
+        }
+    }
+}
+
+/// Helpers
+impl<GridRef: AsRef<Grid<Base3>>> Solver<GridRef> {
+    fn pos_to_band_and_cell(pos: Position<Base3>) -> (usize, usize) {
+        let cell_index = usize::from(pos.cell_index());
+        (cell_index / CELLS_PER_BAND, cell_index % CELLS_PER_BAND)
+    }
+
+    fn build_solution(&self, state: &State) -> Solution<Base3> {
+        let mut solution_values = Grid::<Base3, Value<Base3>>::new();
+        for pos in Position::<Base3>::all() {
+            solution_values[pos] = self.grid.as_ref()[pos].value().unwrap_or_else(|| {
+                let (band, band_cell) = Self::pos_to_band_and_cell(pos);
+                let digit = state.placed_digit(band, band_cell);
+                Value::try_from(u8::try_from(digit + 1).unwrap()).unwrap()
+            });
+        }
+        Solution::with_values_unchecked(solution_values)
+    }
+}
+
+impl<GridRef: AsRef<Grid<Base3>>> InfallibleSolver<Base3> for Solver<GridRef> {
+    fn solve(&mut self) -> Option<Solution<Base3>> {
+        while let Some(mut state) = self.stack.pop() {
+            if state.propagate().is_err() {
+                self.backtrack_count += 1;
+                continue;
+            }
+
+            let Some((digit, band, cell)) = state.choose_guess() else {
+                debug_assert!(state.is_solved());
+                return Some(self.build_solution(&state));
+            };
+
+            let mut negated_guess = state;
+            negated_guess.candidates[digit][band] &= !(1 << cell);
+            self.stack.push(negated_guess);
+
+            state.place(digit, band, cell).unwrap();
+            self.stack.push(state);
+        }
+
+        None
+    }
+}
+
+impl<GridRef: AsRef<Grid<Base3>>> Iterator for Solver<GridRef> {
+    type Item = Solution<Base3>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.solve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::solver::backtracking;
+    use crate::solver::test_util::{
+        assert_infallible_solution_iter_single_solution, assert_infallible_solver_single_solution,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_permutation_masks() {
+        assert_eq!(PERMUTATION_MASKS[0b111_111_111], 0b111_111_111);
+        assert_eq!(PERMUTATION_MASKS[0b100_010_001], 0b100_010_001);
+        // The first row can only be covered by its first intersection,
+        // which eliminates the other intersections of the first column.
+        assert_eq!(PERMUTATION_MASKS[0b111_110_001], 0b110_110_001);
+        // The last column is empty.
+        assert_eq!(PERMUTATION_MASKS[0b011_011_011], 0);
+    }
+
+    #[test]
+    fn test_samples_base_3() {
+        for grid in crate::samples::base_3() {
+            let mut solver = Solver::new(&grid);
+            assert_infallible_solver_single_solution(&mut solver, &grid);
+
+            assert_infallible_solution_iter_single_solution(Solver::new(&grid), &grid);
+        }
+    }
+
+    #[test]
+    fn test_solved_grid() {
+        let grid = crate::samples::base_3().remove(0);
+        let solution = Solver::new(&grid).next().unwrap().to_grid();
+
+        assert_infallible_solution_iter_single_solution(Solver::new(&solution), &solution);
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid = Grid::<Base3>::new();
+
+        let solutions = Solver::new(&grid).take(100).collect::<Vec<_>>();
+
+        assert_eq!(solutions.len(), 100);
+        for solution in &solutions {
+            solution.validate().unwrap();
+        }
+        assert_eq!(solutions.into_iter().collect::<HashSet<_>>().len(), 100);
+    }
+
+    #[test]
+    fn test_all_solutions_equal_backtracking() {
+        let grid = crate::samples::base_3().remove(0);
+        let mut puzzle = Solver::new(&grid).next().unwrap().to_grid();
+        for pos in Position::<Base3>::all().take(3 * 9) {
+            puzzle[pos].delete();
+        }
+        puzzle.fix_all_values();
+
+        let solutions = Solver::new(&puzzle).collect::<HashSet<_>>();
+        let expected_solutions = backtracking::Solver::new(&puzzle).collect::<HashSet<_>>();
+
+        assert!(solutions.len() > 1);
+        assert_eq!(solutions, expected_solutions);
+    }
+
+    #[test]
+    fn test_conflicting_values() {
+        let mut grid = Grid::<Base3>::new();
+        let value = Value::default();
+        grid[Position::try_from((0, 0)).unwrap()].set_value(value);
+        grid[Position::try_from((0, 8)).unwrap()].set_value(value);
+
+        assert_eq!(Solver::new(&grid).next(), None);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // r1c1 can't contain any value.
+        let mut grid = Grid::<Base3>::new();
+        for (column, value) in (1..9).zip(1..9) {
+            grid[Position::try_from((0, column)).unwrap()].set_value(value.try_into().unwrap());
+        }
+        grid[Position::try_from((1, 0)).unwrap()].set_value(9.try_into().unwrap());
+
+        assert_eq!(Solver::new(&grid).next(), None);
+    }
+}
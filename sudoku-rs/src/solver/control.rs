@@ -0,0 +1,258 @@
+//! Shared limits for long-running solvers.
+//!
+//! A [`SolverControl`] combines a [`CancellationToken`], a step budget and a [`Deadline`].
+//! Solvers configured with a control check it regularly and abort with an [`AbortReason`],
+//! once any of its limits is reached.
+
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Allows cancelling a solver from another thread.
+///
+/// Clones share the same cancellation state.
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests all solvers observing this token to abort.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A point in time, after which a solver aborts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Deadline {
+    /// Time since an arbitrary, but fixed reference point.
+    since_reference: Duration,
+}
+
+impl Deadline {
+    /// The deadline `timeout` from now.
+    pub fn after(timeout: Duration) -> Self {
+        Self {
+            since_reference: Self::now().saturating_add(timeout),
+        }
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        Self::now() >= self.since_reference
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn now() -> Duration {
+        use std::sync::LazyLock;
+        use std::time::Instant;
+
+        static REFERENCE: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+        REFERENCE.elapsed()
+    }
+
+    // `std::time::Instant` is unsupported on `wasm32-unknown-unknown`.
+    #[cfg(target_family = "wasm")]
+    fn now() -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.)
+    }
+}
+
+/// The limit of a [`SolverControl`], which caused a solver to abort.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AbortReason {
+    /// The [`CancellationToken`] was cancelled.
+    Cancelled,
+    /// The solver exceeded the maximum number of steps.
+    StepBudgetExhausted,
+    /// The [`Deadline`] was exceeded.
+    DeadlineExceeded,
+}
+
+impl Display for AbortReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AbortReason::Cancelled => "solver was cancelled",
+            AbortReason::StepBudgetExhausted => "solver exhausted its step budget",
+            AbortReason::DeadlineExceeded => "solver exceeded its deadline",
+        })
+    }
+}
+
+impl std::error::Error for AbortReason {}
+
+/// Limits, which are respected by solvers supporting [`ControlledSolver`](super::ControlledSolver).
+///
+/// What a step is depends on the solver:
+/// - [`backtracking::Solver`](super::backtracking::Solver): a backtrack
+/// - [`sat::Solver`](super::sat::Solver): a conflict
+/// - [`strategic::Solver`](super::strategic::Solver): an applied [`SolveStep`](super::strategic::SolveStep),
+///   while its `BruteForce` strategy spends the remaining steps on its own solver, see [`Self::remaining`]
+///
+/// Step counts of different solvers are not comparable.
+///
+/// The default control has no limits.
+#[derive(Debug, Default, Clone)]
+pub struct SolverControl {
+    cancellation_token: Option<CancellationToken>,
+    max_steps: Option<u64>,
+    deadline: Option<Deadline>,
+}
+
+impl SolverControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Abort once `cancellation_token` is cancelled.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Abort once the solver needs more than `max_steps` steps.
+    #[must_use]
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Abort once `deadline` is exceeded.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Abort once `timeout` has passed, starting now.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Deadline::after(timeout))
+    }
+
+    /// Whether any limit is set.
+    pub fn has_limits(&self) -> bool {
+        self.cancellation_token.is_some() || self.max_steps.is_some() || self.deadline.is_some()
+    }
+
+    /// The control of a nested solver, after its parent has taken `step_count` steps.
+    ///
+    /// The nested solver shares the cancellation token and the deadline,
+    /// and may take the remaining steps of the step budget.
+    #[must_use]
+    pub fn remaining(&self, step_count: u64) -> Self {
+        Self {
+            max_steps: self
+                .max_steps
+                .map(|max_steps| max_steps.saturating_sub(step_count)),
+            ..self.clone()
+        }
+    }
+
+    /// Checks the limits, which don't depend on the step count.
+    pub fn check_cancellation_and_deadline(&self) -> Result<(), AbortReason> {
+        // No step budget is exceeded by zero steps.
+        self.check(0)
+    }
+
+    /// Checks all limits for a solver, which has taken `step_count` steps so far.
+    pub fn check(&self, step_count: u64) -> Result<(), AbortReason> {
+        if self
+            .cancellation_token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Err(AbortReason::Cancelled);
+        }
+        if self
+            .max_steps
+            .is_some_and(|max_steps| step_count > max_steps)
+        {
+            return Err(AbortReason::StepBudgetExhausted);
+        }
+        if self.deadline.as_ref().is_some_and(Deadline::is_exceeded) {
+            return Err(AbortReason::DeadlineExceeded);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_has_no_limits() {
+        assert!(!SolverControl::new().has_limits());
+        assert_eq!(SolverControl::new().check(u64::MAX), Ok(()));
+    }
+
+    #[test]
+    fn test_cancellation_token() {
+        let cancellation_token = CancellationToken::new();
+        let control = SolverControl::new().with_cancellation_token(cancellation_token.clone());
+
+        assert_eq!(control.check(0), Ok(()));
+        cancellation_token.clone().cancel();
+        assert!(cancellation_token.is_cancelled());
+        assert_eq!(control.check(0), Err(AbortReason::Cancelled));
+    }
+
+    #[test]
+    fn test_max_steps() {
+        let control = SolverControl::new().with_max_steps(10);
+
+        assert!(control.has_limits());
+        assert_eq!(control.check(10), Ok(()));
+        assert_eq!(control.check(11), Err(AbortReason::StepBudgetExhausted));
+    }
+
+    #[test]
+    fn test_deadline() {
+        assert_eq!(
+            SolverControl::new()
+                .with_timeout(Duration::from_secs(3600))
+                .check(0),
+            Ok(())
+        );
+        assert_eq!(
+            SolverControl::new().with_timeout(Duration::ZERO).check(0),
+            Err(AbortReason::DeadlineExceeded)
+        );
+    }
+
+    #[test]
+    fn test_remaining() {
+        let control = SolverControl::new().with_max_steps(10);
+
+        assert_eq!(control.remaining(4).check(6), Ok(()));
+        assert_eq!(
+            control.remaining(4).check(7),
+            Err(AbortReason::StepBudgetExhausted)
+        );
+        assert_eq!(
+            control.remaining(11).check(1),
+            Err(AbortReason::StepBudgetExhausted)
+        );
+        assert!(!SolverControl::new().remaining(4).has_limits());
+
+        let cancellation_token = CancellationToken::new();
+        let control = SolverControl::new()
+            .with_cancellation_token(cancellation_token.clone())
+            .remaining(4);
+        cancellation_token.cancel();
+        assert_eq!(
+            control.check_cancellation_and_deadline(),
+            Err(AbortReason::Cancelled)
+        );
+    }
+}
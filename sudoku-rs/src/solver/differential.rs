@@ -0,0 +1,623 @@
+//! Differential testing of the solver backends.
+//!
+//! Runs multiple solvers on the same grid and checks, that they agree on the solution state,
+//! the solution count and the solution itself.
+//! If they disagree, the grid is minimized into a smaller counterexample.
+
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::grid::solution_state::SolutionState;
+use crate::solver::strategic::strategies::selection::StrategySet;
+use crate::solver::{FallibleSolver, backtracking, introspective, sat, strategic};
+
+/// The default number of solutions, up to which the enumerating solvers are compared.
+pub const DEFAULT_SOLUTION_COUNT_LIMIT: usize = 10;
+
+/// A solver under test.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SolverKind {
+    /// [`backtracking::Solver`]
+    Backtracking,
+    /// [`introspective::Solver`]
+    Introspective,
+    /// [`sat::Solver`]
+    Sat,
+    /// [`strategic::Solver`] with the default strategies, including `BruteForce`.
+    ///
+    /// In contrast to the other solvers, it finds at most one solution.
+    Strategic,
+}
+
+impl SolverKind {
+    pub const ALL: [Self; 4] = [
+        Self::Backtracking,
+        Self::Introspective,
+        Self::Sat,
+        Self::Strategic,
+    ];
+
+    /// Whether the solver enumerates all solutions, which allows comparing solution counts.
+    pub fn is_enumerating(self) -> bool {
+        !matches!(self, Self::Strategic)
+    }
+
+    /// The solutions of `grid`, at most `solution_count_limit`.
+    pub fn solutions<Base: SudokuBase>(
+        self,
+        grid: &Grid<Base>,
+        solution_count_limit: usize,
+    ) -> Result<Vec<Solution<Base>>> {
+        Ok(match self {
+            Self::Backtracking => backtracking::Solver::new(grid)
+                .take(solution_count_limit)
+                .collect(),
+            Self::Introspective => introspective::Solver::new(grid)
+                .take(solution_count_limit)
+                .collect(),
+            Self::Sat => sat::Solver::new(grid)
+                .into_iter()
+                .take(solution_count_limit)
+                .collect::<Result<_>>()?,
+            Self::Strategic => {
+                let mut grid = grid.clone();
+                // Strategies relying on a unique solution are unsound for the other grids.
+                let strategies =
+                    StrategySet::default_solver_strategies().without_uniqueness_strategies();
+                strategic::Solver::builder(&mut grid)
+                    .strategies(strategies)
+                    .build()
+                    .try_solve()?
+                    .into_iter()
+                    .take(solution_count_limit)
+                    .collect()
+            }
+        })
+    }
+}
+
+impl Display for SolverKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Backtracking => "backtracking",
+            Self::Introspective => "introspective",
+            Self::Sat => "sat",
+            Self::Strategic => "strategic",
+        })
+    }
+}
+
+/// The result of a single solver.
+#[derive(Debug)]
+pub struct SolverReport<Base: SudokuBase> {
+    pub solver: SolverKind,
+    pub solutions: Result<Vec<Solution<Base>>>,
+}
+
+impl<Base: SudokuBase> SolverReport<Base> {
+    pub fn new(solver: SolverKind, grid: &Grid<Base>, solution_count_limit: usize) -> Self {
+        Self {
+            solver,
+            solutions: solver.solutions(grid, solution_count_limit),
+        }
+    }
+
+    /// The solution state, if the solver succeeded and enumerates all solutions.
+    pub fn solution_state(&self) -> Option<SolutionState<Base>> {
+        if !self.solver.is_enumerating() {
+            return None;
+        }
+        let solutions = self.solutions.as_ref().ok()?;
+        Some(match solutions.as_slice() {
+            [] => SolutionState::NoSolution,
+            [solution] => SolutionState::UniqueSolution(solution.clone()),
+            _ => SolutionState::MultipleSolutions,
+        })
+    }
+}
+
+impl<Base: SudokuBase> Display for SolverReport<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.solutions {
+            Ok(solutions) => write!(f, "{}: {} solution(s)", self.solver, solutions.len()),
+            Err(err) => write!(f, "{}: error: {err}", self.solver),
+        }
+    }
+}
+
+/// A grid, for which the solvers disagree.
+#[derive(Debug)]
+pub struct Disagreement<Base: SudokuBase> {
+    pub grid: Grid<Base>,
+    /// A description of the first detected disagreement.
+    pub reason: String,
+    pub reports: Vec<SolverReport<Base>>,
+}
+
+impl<Base: SudokuBase> Display for Disagreement<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Solvers disagree: {}", self.reason)?;
+        writeln!(f, "{}", self.grid)?;
+        for report in &self.reports {
+            writeln!(f, "{report}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs all solvers on `grid` and compares their results.
+///
+/// The enumerating solvers are compared up to `solution_count_limit` solutions.
+pub fn check<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    solution_count_limit: usize,
+) -> Option<Disagreement<Base>> {
+    check_with(grid, solution_count_limit, &run_all_solvers)
+}
+
+/// Runs each solver of [`SolverKind::ALL`].
+fn run_all_solvers<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    solution_count_limit: usize,
+) -> Vec<SolverReport<Base>> {
+    SolverKind::ALL
+        .into_iter()
+        .map(|solver| SolverReport::new(solver, grid, solution_count_limit))
+        .collect()
+}
+
+/// Like [`check`], but with the reports of `run_solvers`.
+fn check_with<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    solution_count_limit: usize,
+    run_solvers: &impl Fn(&Grid<Base>, usize) -> Vec<SolverReport<Base>>,
+) -> Option<Disagreement<Base>> {
+    assert!(
+        solution_count_limit >= 2,
+        "A unique solution can't be detected with less than two solutions"
+    );
+
+    let mut grid = grid.clone();
+    // The solvers differ in whether they use the candidates of the grid.
+    grid.set_all_direct_candidates();
+
+    let reports = run_solvers(&grid, solution_count_limit);
+
+    find_disagreement(&grid, &reports, solution_count_limit).map(|reason| Disagreement {
+        grid,
+        reason,
+        reports,
+    })
+}
+
+/// Like [`check`], but minimizes the grid of the disagreement with [`minimize`].
+pub fn check_minimized<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    solution_count_limit: usize,
+) -> Option<Disagreement<Base>> {
+    check(grid, solution_count_limit)
+        .map(|disagreement| minimize(disagreement, solution_count_limit))
+}
+
+/// Removes values from the grid of `disagreement`, as long as the solvers still disagree.
+///
+/// The resulting grid is minimal: removing any of its values resolves the disagreement.
+pub fn minimize<Base: SudokuBase>(
+    disagreement: Disagreement<Base>,
+    solution_count_limit: usize,
+) -> Disagreement<Base> {
+    minimize_with(disagreement, solution_count_limit, &run_all_solvers)
+}
+
+/// Like [`minimize`], but with the reports of `run_solvers`.
+fn minimize_with<Base: SudokuBase>(
+    disagreement: Disagreement<Base>,
+    solution_count_limit: usize,
+    run_solvers: &impl Fn(&Grid<Base>, usize) -> Vec<SolverReport<Base>>,
+) -> Disagreement<Base> {
+    let mut disagreement = disagreement;
+    minimize_grid(disagreement.grid.clone(), |grid| {
+        if let Some(smaller_disagreement) = check_with(grid, solution_count_limit, run_solvers) {
+            disagreement = smaller_disagreement;
+            true
+        } else {
+            false
+        }
+    });
+    disagreement
+}
+
+/// Greedily deletes values of `grid`, while `is_counterexample` holds.
+fn minimize_grid<Base: SudokuBase>(
+    mut grid: Grid<Base>,
+    mut is_counterexample: impl FnMut(&Grid<Base>) -> bool,
+) -> Grid<Base> {
+    grid.unfix_all_values();
+
+    loop {
+        let mut has_deleted_value = false;
+        for pos in grid.all_value_positions() {
+            let mut smaller_grid = grid.clone();
+            smaller_grid[pos].delete();
+            if is_counterexample(&smaller_grid) {
+                grid = smaller_grid;
+                has_deleted_value = true;
+            }
+        }
+        if !has_deleted_value {
+            return grid;
+        }
+    }
+}
+
+/// Describes the first disagreement between `reports`.
+///
+/// The enumerating solvers must agree on the solution count.
+/// If they found less than `solution_count_limit` solutions, they must also agree on the solutions.
+/// Otherwise, each solver may have found a different subset of the solutions.
+fn find_disagreement<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    reports: &[SolverReport<Base>],
+    solution_count_limit: usize,
+) -> Option<String> {
+    for report in reports {
+        let Ok(solutions) = &report.solutions else {
+            continue;
+        };
+        if let Some(solution) = solutions
+            .iter()
+            .find(|solution| solution.validate().is_err() || !solution.is_solution_for(grid))
+        {
+            return Some(format!(
+                "{} returned an invalid solution:\n{solution}",
+                report.solver
+            ));
+        }
+        if !solutions.iter().all_unique() {
+            return Some(format!("{} returned duplicate solutions", report.solver));
+        }
+    }
+
+    let (enumerating_reports, other_reports): (Vec<_>, Vec<_>) = reports
+        .iter()
+        .partition(|report| report.solver.is_enumerating());
+
+    if let Some(report) = enumerating_reports
+        .iter()
+        .find(|report| report.solutions.is_err())
+    {
+        return Some(format!("{report}"));
+    }
+
+    let enumerated_solutions = enumerating_reports
+        .iter()
+        .map(|report| report.solutions.as_ref().unwrap())
+        .collect_vec();
+    if !enumerated_solutions
+        .iter()
+        .map(|solutions| solutions.len())
+        .all_equal()
+    {
+        return Some(format!(
+            "different solution counts: {}",
+            enumerating_reports.iter().join(", ")
+        ));
+    }
+    let solution_count = enumerated_solutions.first()?.len();
+    if solution_count < solution_count_limit
+        && !enumerated_solutions
+            .iter()
+            .map(|solutions| solutions.iter().collect::<HashSet<_>>())
+            .all_equal()
+    {
+        return Some(format!(
+            "different solutions: {}",
+            enumerating_reports.iter().join(", ")
+        ));
+    }
+    let reference_solution_state = enumerating_reports[0].solution_state().unwrap();
+
+    for report in other_reports {
+        match (&report.solutions, &reference_solution_state) {
+            // Strategies may detect an inconsistent grid.
+            (Err(_), SolutionState::NoSolution) => {}
+            (Err(_), _) => return Some(format!("{report}")),
+            (Ok(solutions), SolutionState::UniqueSolution(reference_solution)) => {
+                if solutions.as_slice() != [reference_solution.clone()] {
+                    return Some(format!("{report}, expected the unique solution"));
+                }
+            }
+            (Ok(solutions), _) => {
+                if solutions.is_empty() != (solution_count == 0) {
+                    return Some(format!("{report}, expected {solution_count} solution(s)"));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::*;
+    use crate::cell::Value;
+    use crate::position::Position;
+
+    use super::*;
+
+    fn assert_agree<Base: SudokuBase>(grid: &Grid<Base>) {
+        if let Some(disagreement) = check_minimized(grid, DEFAULT_SOLUTION_COUNT_LIMIT) {
+            panic!("{disagreement}");
+        }
+    }
+
+    #[test]
+    fn test_samples_base_2() {
+        for grid in crate::samples::base_2() {
+            assert_agree(&grid);
+        }
+        assert_agree(&crate::samples::base_2_solved());
+    }
+
+    #[test]
+    fn test_samples_base_3() {
+        for grid in crate::samples::base_3() {
+            assert_agree(&grid);
+        }
+    }
+
+    #[test]
+    fn test_multiple_solutions() {
+        assert_agree(&Grid::<Base2>::new());
+
+        let mut grid = crate::samples::base_3().remove(0);
+        grid.unfix_all_values();
+        for pos in grid.all_value_positions().into_iter().take(4) {
+            grid[pos].delete();
+        }
+        assert_agree(&grid);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let mut grid = Grid::<Base2>::new();
+        let value = Value::default();
+        grid[Position::try_from((0, 0)).unwrap()].set_value(value);
+        grid[Position::try_from((0, 3)).unwrap()].set_value(value);
+
+        assert_agree(&grid);
+    }
+
+    #[test]
+    fn test_find_disagreement() {
+        let solution = Solution::try_from(&crate::samples::base_2_solved()).unwrap();
+        // Swap the values 1 <-> 4 and 2 <-> 3
+        let other_values = solution
+            .values()
+            .all_cells()
+            .map(|value| Value::try_from(Base2::SIDE_LENGTH + 1 - value.get()).unwrap())
+            .collect();
+        let other_solution = Solution::new(Grid::with(other_values).unwrap()).unwrap();
+        // Swap the values 1 <-> 2 and 3 <-> 4
+        let third_values = solution
+            .values()
+            .all_cells()
+            .map(|value| Value::try_from(((value.get() - 1) ^ 1) + 1).unwrap())
+            .collect();
+        let third_solution = Solution::new(Grid::with(third_values).unwrap()).unwrap();
+
+        let report = |solver, solutions| SolverReport { solver, solutions };
+
+        // Different solution counts
+        let disagreement = find_disagreement(
+            &Grid::new(),
+            &[
+                report(
+                    SolverKind::Backtracking,
+                    Ok(vec![
+                        solution.clone(),
+                        other_solution.clone(),
+                        third_solution.clone(),
+                    ]),
+                ),
+                report(
+                    SolverKind::Sat,
+                    Ok(vec![solution.clone(), other_solution.clone()]),
+                ),
+            ],
+            DEFAULT_SOLUTION_COUNT_LIMIT,
+        )
+        .unwrap();
+        assert!(disagreement.starts_with("different solution counts"));
+        // Same solution count, different solutions
+        let disagreement = find_disagreement(
+            &Grid::new(),
+            &[
+                report(
+                    SolverKind::Backtracking,
+                    Ok(vec![solution.clone(), other_solution.clone()]),
+                ),
+                report(
+                    SolverKind::Sat,
+                    Ok(vec![solution.clone(), third_solution.clone()]),
+                ),
+            ],
+            DEFAULT_SOLUTION_COUNT_LIMIT,
+        )
+        .unwrap();
+        assert!(disagreement.starts_with("different solutions"));
+        // Same solutions in a different order
+        assert_eq!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(
+                        SolverKind::Backtracking,
+                        Ok(vec![solution.clone(), other_solution.clone()]),
+                    ),
+                    report(
+                        SolverKind::Sat,
+                        Ok(vec![other_solution.clone(), solution.clone()]),
+                    ),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT,
+            ),
+            None
+        );
+        // Different subsets of the solutions, if the limit is reached
+        assert_eq!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(
+                        SolverKind::Backtracking,
+                        Ok(vec![solution.clone(), other_solution.clone()]),
+                    ),
+                    report(
+                        SolverKind::Sat,
+                        Ok(vec![solution.clone(), third_solution.clone()]),
+                    ),
+                ],
+                2,
+            ),
+            None
+        );
+
+        assert_eq!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(SolverKind::Backtracking, Ok(vec![solution.clone()])),
+                    report(SolverKind::Sat, Ok(vec![solution.clone()])),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            ),
+            None
+        );
+        assert!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(SolverKind::Backtracking, Ok(vec![solution.clone()])),
+                    report(SolverKind::Sat, Ok(vec![other_solution.clone()])),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_some()
+        );
+        assert!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(
+                        SolverKind::Backtracking,
+                        Ok(vec![solution.clone(), other_solution.clone()])
+                    ),
+                    report(SolverKind::Sat, Ok(vec![solution.clone()])),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_some()
+        );
+        assert!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(SolverKind::Backtracking, Ok(vec![solution.clone()])),
+                    report(SolverKind::Strategic, Ok(vec![other_solution.clone()])),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_some()
+        );
+        // Invalid solution for the grid
+        assert!(
+            find_disagreement(
+                &crate::samples::base_2_solved(),
+                &[report(SolverKind::Backtracking, Ok(vec![other_solution]))],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_some()
+        );
+        assert!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(SolverKind::Backtracking, Ok(vec![])),
+                    report(SolverKind::Strategic, Err(anyhow::anyhow!("inconsistent"))),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_none()
+        );
+        assert!(
+            find_disagreement(
+                &Grid::new(),
+                &[
+                    report(SolverKind::Backtracking, Ok(vec![solution])),
+                    report(SolverKind::Strategic, Err(anyhow::anyhow!("inconsistent"))),
+                ],
+                DEFAULT_SOLUTION_COUNT_LIMIT
+            )
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn test_minimize_fake_disagreement() {
+        // The faulty solver drops the last solution, if the top left cell has a value.
+        let run_solvers = |grid: &Grid<Base2>, solution_count_limit| {
+            let solutions = SolverKind::Backtracking
+                .solutions(grid, solution_count_limit)
+                .unwrap();
+            let mut faulty_solutions = solutions.clone();
+            if grid[Position::top_left()].has_value() {
+                faulty_solutions.pop();
+            }
+            vec![
+                SolverReport {
+                    solver: SolverKind::Backtracking,
+                    solutions: Ok(solutions),
+                },
+                SolverReport {
+                    solver: SolverKind::Sat,
+                    solutions: Ok(faulty_solutions),
+                },
+            ]
+        };
+
+        let disagreement = check_with(
+            &crate::samples::base_2_solved(),
+            DEFAULT_SOLUTION_COUNT_LIMIT,
+            &run_solvers,
+        )
+        .unwrap();
+        assert!(disagreement.reason.starts_with("different solution counts"));
+
+        let minimized = minimize_with(disagreement, DEFAULT_SOLUTION_COUNT_LIMIT, &run_solvers);
+
+        assert_eq!(
+            minimized.grid.all_value_positions(),
+            vec![Position::top_left()]
+        );
+        assert!(minimized.reason.starts_with("different solution counts"));
+    }
+
+    #[test]
+    fn test_minimize_grid() {
+        let grid = crate::samples::base_2_solved();
+        let pos = Position::top_left();
+        let value = grid[pos].value().unwrap();
+
+        let minimized_grid = minimize_grid(grid, |grid| grid[pos].value() == Some(value));
+
+        assert_eq!(minimized_grid.all_value_positions(), vec![pos]);
+    }
+}
@@ -0,0 +1,288 @@
+//! Knuth's [Algorithm X](https://en.wikipedia.org/wiki/Knuth%27s_Algorithm_X) using Dancing Links.
+//!
+//! The links are stored as indexes into vectors, instead of pointers.
+
+/// Index of the root header.
+const ROOT: usize = 0;
+
+/// An exact cover problem, which can be searched for all solutions.
+///
+/// A solution is a set of rows, which together contain each column exactly once.
+#[derive(Debug, Clone)]
+pub(super) struct ExactCover {
+    // Nodes `1..=column_count` are the column headers, the remaining nodes are the ones of the rows.
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    /// The column header of each node.
+    column: Vec<usize>,
+    /// The row index of each node, `usize::MAX` for headers.
+    row: Vec<usize>,
+    /// The number of nodes in each column, indexed by the column header.
+    size: Vec<usize>,
+    row_count: usize,
+    /// The nodes of the currently selected rows, in order of selection.
+    ///
+    /// The columns of each selected row are covered.
+    selected: Vec<usize>,
+    /// The selected rows form a solution, which was already returned.
+    has_returned_solution: bool,
+    is_exhausted: bool,
+    pub(super) backtrack_count: u64,
+}
+
+impl ExactCover {
+    pub(super) fn new(column_count: usize) -> Self {
+        let header_count = column_count + 1;
+
+        Self {
+            left: (0..header_count)
+                .map(|i| if i == ROOT { column_count } else { i - 1 })
+                .collect(),
+            right: (0..header_count)
+                .map(|i| if i == column_count { ROOT } else { i + 1 })
+                .collect(),
+            up: (0..header_count).collect(),
+            down: (0..header_count).collect(),
+            column: (0..header_count).collect(),
+            row: vec![usize::MAX; header_count],
+            size: vec![0; header_count],
+            row_count: 0,
+            selected: vec![],
+            has_returned_solution: false,
+            is_exhausted: false,
+            backtrack_count: 0,
+        }
+    }
+
+    /// Adds a row containing the given columns, returns its index.
+    ///
+    /// Rows must be added before searching for solutions.
+    pub(super) fn add_row(&mut self, columns: impl IntoIterator<Item = usize>) -> usize {
+        debug_assert!(self.selected.is_empty() && !self.has_returned_solution);
+
+        let row = self.row_count;
+        self.row_count += 1;
+
+        let mut first_node = None;
+        for column in columns {
+            let header = column + 1;
+            debug_assert!(header < self.size.len());
+
+            let node = self.column.len();
+            let (left, right) = if let Some(first_node) = first_node {
+                (self.left[first_node], first_node)
+            } else {
+                first_node = Some(node);
+                (node, node)
+            };
+            self.left.push(left);
+            self.right.push(right);
+            self.right[left] = node;
+            self.left[right] = node;
+
+            let up = self.up[header];
+            self.up.push(up);
+            self.down.push(header);
+            self.down[up] = node;
+            self.up[header] = node;
+
+            self.column.push(header);
+            self.row.push(row);
+            self.size[header] += 1;
+        }
+
+        row
+    }
+
+    /// Finds the next solution and returns its rows in order of selection.
+    pub(super) fn next_solution(&mut self) -> Option<Vec<usize>> {
+        if self.is_exhausted {
+            return None;
+        }
+
+        if self.has_returned_solution && !self.select_next_row() {
+            return None;
+        }
+
+        loop {
+            if self.right[ROOT] == ROOT {
+                self.has_returned_solution = true;
+                return Some(self.selected.iter().map(|&node| self.row[node]).collect());
+            }
+
+            let header = self.choose_column();
+            if self.size[header] == 0 {
+                if !self.select_next_row() {
+                    return None;
+                }
+                continue;
+            }
+
+            self.cover(header);
+            let node = self.down[header];
+            self.cover_row(node);
+            self.selected.push(node);
+        }
+    }
+
+    /// Deselects the last selected row and selects the next row of its column.
+    /// Backtracks if the column is exhausted.
+    ///
+    /// Returns `false` if the search space is exhausted.
+    fn select_next_row(&mut self) -> bool {
+        self.has_returned_solution = false;
+
+        while let Some(node) = self.selected.pop() {
+            self.uncover_row(node);
+
+            let next_node = self.down[node];
+            let header = self.column[node];
+            if next_node != header {
+                self.cover_row(next_node);
+                self.selected.push(next_node);
+                return true;
+            }
+
+            self.uncover(header);
+            self.backtrack_count += 1;
+        }
+
+        self.is_exhausted = true;
+        false
+    }
+
+    /// The uncovered column with the fewest nodes.
+    fn choose_column(&self) -> usize {
+        let mut best_header = self.right[ROOT];
+        let mut header = self.right[best_header];
+
+        while header != ROOT && self.size[best_header] > 1 {
+            if self.size[header] < self.size[best_header] {
+                best_header = header;
+            }
+            header = self.right[header];
+        }
+
+        best_header
+    }
+
+    /// Covers the columns of all other nodes in the row of `node`.
+    fn cover_row(&mut self, node: usize) {
+        let mut other = self.right[node];
+        while other != node {
+            self.cover(self.column[other]);
+            other = self.right[other];
+        }
+    }
+
+    /// Reverts `cover_row`.
+    fn uncover_row(&mut self, node: usize) {
+        let mut other = self.left[node];
+        while other != node {
+            self.uncover(self.column[other]);
+            other = self.left[other];
+        }
+    }
+
+    fn cover(&mut self, header: usize) {
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = right;
+        self.left[right] = left;
+
+        let mut row_node = self.down[header];
+        while row_node != header {
+            let mut node = self.right[row_node];
+            while node != row_node {
+                let (up, down) = (self.up[node], self.down[node]);
+                self.down[up] = down;
+                self.up[down] = up;
+                self.size[self.column[node]] -= 1;
+                node = self.right[node];
+            }
+            row_node = self.down[row_node];
+        }
+    }
+
+    fn uncover(&mut self, header: usize) {
+        let mut row_node = self.up[header];
+        while row_node != header {
+            let mut node = self.left[row_node];
+            while node != row_node {
+                let (up, down) = (self.up[node], self.down[node]);
+                self.down[up] = node;
+                self.up[down] = node;
+                self.size[self.column[node]] += 1;
+                node = self.left[node];
+            }
+            row_node = self.up[row_node];
+        }
+
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = header;
+        self.left[right] = header;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    /// Example from Knuth's paper "Dancing Links".
+    #[test]
+    fn test_knuth_example() {
+        let mut exact_cover = ExactCover::new(7);
+        for columns in [
+            vec![2, 4, 5],
+            vec![0, 3, 6],
+            vec![1, 2, 5],
+            vec![0, 3],
+            vec![1, 6],
+            vec![3, 4, 6],
+        ] {
+            exact_cover.add_row(columns);
+        }
+
+        let solution = exact_cover.next_solution().unwrap();
+        assert_eq!(solution.into_iter().sorted().collect_vec(), vec![0, 3, 4]);
+        assert_eq!(exact_cover.next_solution(), None);
+        assert_eq!(exact_cover.next_solution(), None);
+    }
+
+    #[test]
+    fn test_all_solutions() {
+        // Rows {0}, {1}, {0, 1}: the solutions are {0} + {1} and {0, 1}.
+        let mut exact_cover = ExactCover::new(2);
+        exact_cover.add_row([0]);
+        exact_cover.add_row([1]);
+        exact_cover.add_row([0, 1]);
+
+        let solutions = std::iter::from_fn(|| exact_cover.next_solution())
+            .map(|solution| solution.into_iter().sorted().collect_vec())
+            .sorted()
+            .collect_vec();
+        assert_eq!(solutions, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let mut exact_cover = ExactCover::new(3);
+        exact_cover.add_row([0, 1]);
+        exact_cover.add_row([1, 2]);
+
+        assert_eq!(exact_cover.next_solution(), None);
+    }
+
+    #[test]
+    fn test_empty_row() {
+        let mut exact_cover = ExactCover::new(1);
+        exact_cover.add_row([0]);
+        exact_cover.add_row([]);
+
+        assert_eq!(exact_cover.next_solution(), Some(vec![0]));
+        assert_eq!(exact_cover.next_solution(), None);
+    }
+}
@@ -0,0 +1,229 @@
+//! An exact cover solver based on [Dancing Links](https://arxiv.org/abs/cs/0011047).
+//!
+//! A sudoku is an exact cover problem, each candidate `(pos, value)` is a row,
+//! which covers four constraints (columns):
+//! - the cell at `pos` contains a value
+//! - the row of `pos` contains `value`
+//! - the column of `pos` contains `value`
+//! - the block of `pos` contains `value`
+
+use std::marker::PhantomData;
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::position::Position;
+use crate::solver::InfallibleSolver;
+use crate::solver::backtracking::CandidatesFilter;
+use exact_cover::ExactCover;
+
+mod exact_cover;
+
+/// The number of constraint kinds: cell, row, column and block.
+const CONSTRAINT_KIND_COUNT: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct Solver<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> {
+    /// Grid to be solved
+    grid: GridRef,
+    exact_cover: ExactCover,
+    /// The candidate of each row of the exact cover problem.
+    row_candidates: Vec<(Position<Base>, Value<Base>)>,
+    _base: PhantomData<Base>,
+}
+
+/// Public API
+impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> Solver<Base, GridRef> {
+    pub fn new(grid: GridRef) -> Self {
+        Self::with_candidates_filter(grid, &())
+    }
+
+    /// Filter the available candidates which the solver can use to find a solution.
+    pub fn with_candidates_filter<Filter: CandidatesFilter<Base>>(
+        grid: GridRef,
+        filter: &Filter,
+    ) -> Self {
+        let side_length = usize::from(Base::SIDE_LENGTH);
+
+        let mut exact_cover = ExactCover::new(CONSTRAINT_KIND_COUNT * side_length * side_length);
+        let mut row_candidates = vec![];
+
+        for pos in Position::<Base>::all() {
+            let candidates = match grid.as_ref()[pos].value() {
+                Some(value) => Candidates::with_single(value),
+                None => grid
+                    .as_ref()
+                    .direct_candidates(pos)
+                    .without(filter.denied_candidates(pos)),
+            };
+
+            for value in candidates {
+                exact_cover.add_row(Self::constraint_columns(pos, value));
+                row_candidates.push((pos, value));
+            }
+        }
+
+        Self {
+            grid,
+            exact_cover,
+            row_candidates,
+            _base: PhantomData,
+        }
+    }
+
+    /// The number of times the solver backtracked while searching for solutions.
+    pub fn backtrack_count(&self) -> u64 {
+        self.exact_cover.backtrack_count
+    }
+}
+
+/// Helpers
+impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> Solver<Base, GridRef> {
+    /// The columns of the four constraints satisfied by `value` at `pos`.
+    fn constraint_columns(
+        pos: Position<Base>,
+        value: Value<Base>,
+    ) -> [usize; CONSTRAINT_KIND_COUNT] {
+        let side_length = usize::from(Base::SIDE_LENGTH);
+        let cell_count = side_length * side_length;
+
+        let (row, column) = pos.to_row_and_column();
+        let block = pos.to_block();
+        let value_index = usize::from(value.get() - 1);
+        let group_constraint = |coordinate: u8| usize::from(coordinate) * side_length + value_index;
+
+        [
+            usize::from(pos.cell_index()),
+            cell_count + group_constraint(row.get()),
+            2 * cell_count + group_constraint(column.get()),
+            3 * cell_count + group_constraint(block.get()),
+        ]
+    }
+
+    fn build_solution(&self, rows: Vec<usize>) -> Solution<Base> {
+        let mut solution_values = Grid::<Base, Value<Base>>::new();
+        // Each cell is covered by exactly one row, including the cells of the given values.
+        for row in rows {
+            let (pos, value) = self.row_candidates[row];
+            solution_values[pos] = value;
+        }
+        let solution = Solution::with_values_unchecked(solution_values);
+        debug_assert!(solution.is_solution_for(self.grid.as_ref()));
+        solution
+    }
+}
+
+impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> InfallibleSolver<Base>
+    for Solver<Base, GridRef>
+{
+    fn solve(&mut self) -> Option<Solution<Base>> {
+        let rows = self.exact_cover.next_solution()?;
+
+        Some(self.build_solution(rows))
+    }
+}
+
+impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> Iterator for Solver<Base, GridRef> {
+    type Item = Solution<Base>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.solve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::*;
+    use crate::solver::backtracking::DisallowedCandidateAtPosition;
+    use crate::solver::test_util::{
+        assert_infallible_solution_iter_all_solutions_base_2,
+        assert_infallible_solution_iter_single_solution, assert_infallible_solver_single_solution,
+        tests_solver_samples,
+    };
+
+    use super::*;
+
+    mod samples {
+        use super::*;
+
+        mod infallible_solver {
+            use super::*;
+            tests_solver_samples! {
+                |grid| {
+                    let mut solver = Solver::new(&grid);
+                    assert_infallible_solver_single_solution(&mut solver, &grid);
+                }
+            }
+        }
+
+        mod infallible_solution_iter {
+            use super::*;
+            tests_solver_samples! {
+                |grid| {
+                    let solver = Solver::new(&grid);
+                    assert_infallible_solution_iter_single_solution(solver, &grid);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_iter_all_solutions() {
+        let grid = Grid::<Base2>::new();
+        let solver = Solver::new(&grid);
+
+        assert_infallible_solution_iter_all_solutions_base_2(solver);
+    }
+
+    #[test]
+    fn test_candidates_filter_denied_candidates_grid() {
+        type Base = Base2;
+
+        let grid = Grid::<Base>::new();
+        let mut denylist = Grid::new();
+        denylist[Position::default()] = vec![1, 3]
+            .into_iter()
+            .map(|v| Value::try_from(v).unwrap())
+            .collect();
+        let solver = Solver::with_candidates_filter(&grid, &denylist);
+
+        for solution in solver.clone() {
+            assert!(![1, 3].contains(&solution.get(Position::default()).get()));
+        }
+
+        assert_eq!(solver.count(), 144);
+    }
+
+    #[test]
+    fn test_candidates_filter_disallowed_candidate_at_position() {
+        let grid = crate::samples::base_3().remove(0);
+        let solution = Solver::new(&grid).next().unwrap();
+
+        let pos = grid.all_candidates_positions()[0];
+        let filter = DisallowedCandidateAtPosition {
+            pos,
+            candidate: solution[pos],
+        };
+
+        // The sample has a unique solution, which is denied by the filter.
+        assert_eq!(Solver::with_candidates_filter(&grid, &filter).next(), None);
+    }
+
+    #[test]
+    fn test_solved_grid() {
+        let grid = crate::samples::base_2_solved();
+
+        assert_infallible_solution_iter_single_solution(Solver::new(&grid), &grid);
+    }
+
+    #[test]
+    fn test_conflicting_values() {
+        let mut grid = Grid::<Base2>::new();
+        let value = Value::default();
+        grid[Position::try_from((0, 0)).unwrap()].set_value(value);
+        grid[Position::try_from((0, 3)).unwrap()].set_value(value);
+
+        assert_eq!(Solver::new(&grid).next(), None);
+    }
+}
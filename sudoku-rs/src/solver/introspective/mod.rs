@@ -1,9 +1,12 @@
 use crate::base::{BaseEnum, SudokuBase};
 use crate::cell::CandidatesAscIter;
+use crate::error::{Error, Result};
 use crate::grid::Grid;
+use crate::grid::solution::Solution;
 use crate::solver::backtracking::CandidatesFilter;
+use crate::solver::control::{AbortReason, SolverControl};
 use crate::solver::sat;
-use crate::solver::{InfallibleSolver, backtracking};
+use crate::solver::{ControlledSolver, InfallibleSolver, SolveOutcome, backtracking};
 
 #[derive(Debug, Default)]
 enum SolverImpl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base>> {
@@ -19,6 +22,9 @@ enum SolverImpl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: Candidates
 #[derive(Debug)]
 pub struct Solver<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base>> {
     solver_impl: SolverImpl<Base, GridRef, Filter>,
+    abort_reason: Option<AbortReason>,
+    /// An error of the delegated solver, which is not caused by the control.
+    error: Option<Error>,
 }
 
 impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>> Solver<Base, GridRef, ()> {
@@ -31,51 +37,96 @@ impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base
     Solver<Base, GridRef, Filter>
 {
     pub fn with_filter(grid: GridRef, filter: Filter) -> Self {
-        match Base::ENUM {
+        Self::with_filter_and_control(grid, filter, SolverControl::default())
+    }
+
+    /// Abort the solver once a limit of `control` is reached.
+    ///
+    /// The meaning of a step depends on the solver delegated to, see [`SolverControl`].
+    pub fn with_filter_and_control(grid: GridRef, filter: Filter, control: SolverControl) -> Self {
+        let solver_impl = match Base::ENUM {
             // Base 2 and 3 are small enough,
             // that the overhead of the strategy evaluation is slower than the naive backtracking solver.
-            BaseEnum::Base2 | BaseEnum::Base3 => Self {
-                solver_impl: SolverImpl::Backtracking(
-                    backtracking::Solver::builder(grid)
-                        .candidates_filter(filter)
-                        .build(),
-                ),
-            },
+            BaseEnum::Base2 | BaseEnum::Base3 => SolverImpl::Backtracking(
+                backtracking::Solver::builder(grid)
+                    .candidates_filter(filter)
+                    .control(control)
+                    .build(),
+            ),
             // For base >= 4, sat solver is faster
-            BaseEnum::Base4 | BaseEnum::Base5 => Self {
-                solver_impl: SolverImpl::Sat(
-                    sat::Solver::with_candidates_filter(grid, &filter).into_iter(),
-                ),
-            },
+            BaseEnum::Base4 | BaseEnum::Base5 => SolverImpl::Sat(
+                sat::Solver::with_candidates_filter(grid, &filter)
+                    .control(control)
+                    .into_iter(),
+            ),
+        };
+
+        Self {
+            solver_impl,
+            abort_reason: None,
+            error: None,
         }
     }
+
+    /// The reason why the solver was aborted by its [`SolverControl`], if any.
+    pub fn abort_reason(&self) -> Option<AbortReason> {
+        self.abort_reason
+    }
 }
 
 impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base>>
     InfallibleSolver<Base> for Solver<Base, GridRef, Filter>
 {
-    fn solve(&mut self) -> Option<Grid<Base>> {
+    /// Returns `None` once the solver is aborted, see [`Self::abort_reason`],
+    /// or if the delegated solver failed, see [`ControlledSolver::solve_controlled`].
+    fn solve(&mut self) -> Option<Solution<Base>> {
         let solver_impl = std::mem::take(&mut self.solver_impl);
         match solver_impl {
             SolverImpl::Backtracking(mut solver) => {
                 let res = solver.next();
+                self.abort_reason = solver.abort_reason();
                 self.solver_impl = SolverImpl::Backtracking(solver);
                 res
             }
             SolverImpl::Sat(mut solver) => {
                 let res = solver.next();
                 self.solver_impl = SolverImpl::Sat(solver);
-                res.transpose().unwrap()
+                match res.transpose() {
+                    Ok(res) => res,
+                    Err(err) => {
+                        match err.downcast::<AbortReason>() {
+                            Ok(abort_reason) => self.abort_reason = Some(abort_reason),
+                            Err(err) => self.error = Some(err),
+                        }
+                        self.solver_impl = SolverImpl::Done;
+                        None
+                    }
+                }
             }
             SolverImpl::Done => None,
         }
     }
 }
 
+impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base>>
+    ControlledSolver<Base> for Solver<Base, GridRef, Filter>
+{
+    /// Returns the error of the delegated solver once, afterwards the solver finds no solution.
+    fn solve_controlled(&mut self) -> Result<SolveOutcome<Base>> {
+        let solution = self.solve();
+
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        Ok(SolveOutcome::new(solution, self.abort_reason))
+    }
+}
+
 impl<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Filter: CandidatesFilter<Base>> Iterator
     for Solver<Base, GridRef, Filter>
 {
-    type Item = Grid<Base>;
+    type Item = Solution<Base>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.solve()
@@ -138,4 +189,80 @@ mod tests {
 
         assert_infallible_solution_iter_all_solutions_base_2(solver);
     }
+
+    #[test]
+    fn test_control() {
+        use crate::solver::control::{CancellationToken, SolverControl};
+
+        // Delegates to the backtracking solver
+        let grid = Grid::<Base2>::new();
+        let mut solver =
+            Solver::with_filter_and_control(grid, (), SolverControl::new().with_max_steps(0));
+        while solver.next().is_some() {}
+        assert_eq!(
+            solver.abort_reason(),
+            Some(AbortReason::StepBudgetExhausted)
+        );
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::StepBudgetExhausted)
+        );
+
+        // Delegates to the SAT solver
+        let grid = crate::samples::base_4().remove(0);
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let mut solver = Solver::with_filter_and_control(
+            grid,
+            (),
+            SolverControl::new().with_cancellation_token(cancellation_token),
+        );
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::Cancelled)
+        );
+        assert_eq!(solver.next(), None);
+    }
+
+    #[test]
+    fn test_control_base_5() {
+        use crate::solver::control::SolverControl;
+        use std::time::Duration;
+
+        let grid = crate::samples::base_5().remove(0);
+
+        let mut solver = Solver::with_filter_and_control(
+            &grid,
+            (),
+            SolverControl::new().with_timeout(Duration::ZERO),
+        );
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::DeadlineExceeded)
+        );
+
+        let mut solver = Solver::new(&grid);
+        assert!(matches!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Solution(_)
+        ));
+    }
+
+    #[test]
+    fn test_count_solutions_sat_failure() {
+        use crate::solver::control::SolverControl;
+        use crate::solver::count_controlled_solutions_up_to;
+        use std::time::Duration;
+
+        // A SAT solver, which fails on its first call.
+        let sat_solver = sat::Solver::new(Grid::<Base2>::new())
+            .control(SolverControl::new().with_timeout(Duration::ZERO));
+        let mut solver = Solver::<Base2, Grid<Base2>, ()> {
+            solver_impl: SolverImpl::Sat(sat_solver.into_iter()),
+            abort_reason: None,
+            error: None,
+        };
+
+        assert!(count_controlled_solutions_up_to(&mut solver, 10).is_err());
+    }
 }
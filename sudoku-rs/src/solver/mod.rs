@@ -3,37 +3,20 @@ use std::fmt::Debug;
 use crate::base::SudokuBase;
 use crate::error::Result;
 use crate::grid::Grid;
+use crate::grid::solution::Solution;
+use crate::solver::control::AbortReason;
 
 pub mod backtracking;
+pub mod bitboard;
+pub mod control;
+pub mod differential;
+pub mod dlx;
 pub mod strategic;
 
 pub mod introspective;
 
 pub mod sat;
 
-// TODO: exact-cover based solver:
-//  Could be more performant for bigger sudokus
-//  References:
-//  Section 4.4 Sequential DLX algorithm (0.3 seconds?)
-//   https://github.com/huaminghuangtw/Parallel-Sudoku-Solver/blob/master/Project_Report.pdf
-//  Rust exact cover solver
-//   https://github.com/jw013/exact-cover-rs
-
-// TODO: change solution type
-//  Grid<Base> => Grid<Base, Value<Base>>
-//  Pros:
-//   solutions are smaller
-//   solutions are guaranteed to contain only values, no empty/candidates cells
-//   no confusion about fix/unfixed values
-//   grid could provide solution-specific helpers
-//    is_solution_for(grid: Grid<Base>)
-//    is_valid_(solution)
-//  Cons:
-//   requires more conversions/allocations?
-//    evaluate usages of solutions
-//   many Grid methods are unavailable
-//    implement more generally where necessary
-
 /// A infallible sudoku solver.
 pub trait InfallibleSolver<Base: SudokuBase> {
     /// Attempt to find a single solution.
@@ -46,7 +29,7 @@ pub trait InfallibleSolver<Base: SudokuBase> {
     /// A solver *may*:
     /// - return the same solution forever
     /// - return all valid solutions for the sudoku in some order, then `None`.
-    fn solve(&mut self) -> Option<Grid<Base>>;
+    fn solve(&mut self) -> Option<Solution<Base>>;
 }
 
 /// A fallible sudoku solver.
@@ -64,20 +47,220 @@ pub trait FallibleSolver<Base: SudokuBase> {
     /// A solver *may*:
     /// - return the same solution forever
     /// - return all valid solutions for the sudoku in some order, then `None`.
-    fn try_solve(&mut self) -> Result<Option<Grid<Base>>, Self::Error>;
+    fn try_solve(&mut self) -> Result<Option<Solution<Base>>, Self::Error>;
+}
+
+/// The outcome of a [`ControlledSolver`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolveOutcome<Base: SudokuBase> {
+    Solution(Solution<Base>),
+    NoSolution,
+    /// The solver was aborted by its [`SolverControl`](control::SolverControl),
+    /// before it could decide if there is another solution.
+    Aborted(AbortReason),
+}
+
+impl<Base: SudokuBase> SolveOutcome<Base> {
+    /// Combines the result of a solver, which returns no solution after being aborted.
+    pub(crate) fn new(solution: Option<Solution<Base>>, abort_reason: Option<AbortReason>) -> Self {
+        match (solution, abort_reason) {
+            (Some(solution), _) => Self::Solution(solution),
+            (None, Some(abort_reason)) => Self::Aborted(abort_reason),
+            (None, None) => Self::NoSolution,
+        }
+    }
+
+    /// Separates an [`AbortReason`] from the other errors of a fallible solver.
+    pub(crate) fn from_fallible(result: Result<Option<Solution<Base>>>) -> Result<Self> {
+        match result {
+            Ok(solution) => Ok(Self::new(solution, None)),
+            Err(err) => match err.downcast::<AbortReason>() {
+                Ok(abort_reason) => Ok(Self::Aborted(abort_reason)),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    pub fn solution(self) -> Option<Solution<Base>> {
+        match self {
+            Self::Solution(solution) => Some(solution),
+            Self::NoSolution | Self::Aborted(_) => None,
+        }
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        matches!(self, Self::Aborted(_))
+    }
+}
+
+/// A sudoku solver, which respects the limits of its [`SolverControl`](control::SolverControl).
+pub trait ControlledSolver<Base: SudokuBase> {
+    /// Attempt to find a single solution.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SolveOutcome::Solution(solution))` if the solver found a solution.
+    /// - `Ok(SolveOutcome::NoSolution)` if the solver found no solution.
+    /// - `Ok(SolveOutcome::Aborted(reason))` if a limit of the control was reached.
+    /// - `Err(err)` if the solver encountered an error while solving.
+    ///
+    /// Once aborted, the solver stays aborted.
+    fn solve_controlled(&mut self) -> Result<SolveOutcome<Base>>;
+}
+
+/// A solver which can enumerate all solutions of a grid.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SolverBackend {
+    /// [`backtracking::Solver`]
+    Backtracking,
+    /// [`sat::Solver`]
+    Sat,
+    /// [`introspective::Solver`]
+    #[default]
+    Introspective,
+}
+
+/// Counts the solutions of `grid` using `backend`, but at most `limit` solutions.
+///
+/// Returns the exact number of solutions, if it is less than `limit`.
+/// Otherwise, the search stops as soon as `limit` solutions are found and `limit` is returned.
+/// Returns an error, if the SAT solver fails.
+pub fn count_solutions_up_to<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    limit: usize,
+    backend: SolverBackend,
+) -> Result<usize> {
+    if limit == 0 {
+        return Ok(0);
+    }
+
+    Ok(match backend {
+        SolverBackend::Backtracking => backtracking::Solver::new(grid).take(limit).count(),
+        SolverBackend::Sat => sat::Solver::new(grid)
+            .into_iter()
+            .take(limit)
+            .try_fold(0, |count, solution| solution.map(|_| count + 1))?,
+        SolverBackend::Introspective => {
+            count_controlled_solutions_up_to(&mut introspective::Solver::new(grid), limit)?
+        }
+    })
+}
+
+/// Counts the solutions found by `solver`, but at most `limit` solutions.
+///
+/// `solver` must return each solution once, then [`SolveOutcome::NoSolution`].
+/// Returns an error, if the solver fails or is aborted.
+pub(crate) fn count_controlled_solutions_up_to<Base: SudokuBase>(
+    solver: &mut impl ControlledSolver<Base>,
+    limit: usize,
+) -> Result<usize> {
+    let mut count = 0;
+    while count < limit {
+        match solver.solve_controlled()? {
+            SolveOutcome::Solution(_) => count += 1,
+            SolveOutcome::NoSolution => break,
+            SolveOutcome::Aborted(abort_reason) => return Err(abort_reason.into()),
+        }
+    }
+    Ok(count)
 }
 
 /// An iterator over solutions.
 ///
-/// Yields solutions as `Grid<Base>`
-pub trait InfallibleSolutionIter<Base: SudokuBase>: Iterator<Item = Grid<Base>> {}
-impl<Base: SudokuBase, S: Iterator<Item = Grid<Base>>> InfallibleSolutionIter<Base> for S {}
+/// Yields solutions as `Solution<Base>`
+pub trait InfallibleSolutionIter<Base: SudokuBase>: Iterator<Item = Solution<Base>> {}
+impl<Base: SudokuBase, S: Iterator<Item = Solution<Base>>> InfallibleSolutionIter<Base> for S {}
 
 /// An fallible iterator over solutions
 ///
-/// Yields solutions as `Result<Grid<Base>>`
-pub trait FallibleSolutionIter<Base: SudokuBase>: Iterator<Item = Result<Grid<Base>>> {}
-impl<Base: SudokuBase, S: Iterator<Item = Result<Grid<Base>>>> FallibleSolutionIter<Base> for S {}
+/// Yields solutions as `Result<Solution<Base>>`
+pub trait FallibleSolutionIter<Base: SudokuBase>: Iterator<Item = Result<Solution<Base>>> {}
+impl<Base: SudokuBase, S: Iterator<Item = Result<Solution<Base>>>> FallibleSolutionIter<Base>
+    for S
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::*;
+    use crate::cell::Value;
+    use crate::position::Position;
+
+    use super::*;
+
+    const BACKENDS: [SolverBackend; 3] = [
+        SolverBackend::Backtracking,
+        SolverBackend::Sat,
+        SolverBackend::Introspective,
+    ];
+
+    #[test]
+    fn test_count_solutions_up_to() {
+        let grid = Grid::<Base2>::new();
+
+        for backend in BACKENDS {
+            assert_eq!(count_solutions_up_to(&grid, 0, backend).unwrap(), 0);
+            assert_eq!(count_solutions_up_to(&grid, 1, backend).unwrap(), 1);
+            assert_eq!(count_solutions_up_to(&grid, 10, backend).unwrap(), 10);
+            assert_eq!(count_solutions_up_to(&grid, 1000, backend).unwrap(), 288);
+        }
+    }
+
+    #[test]
+    fn test_count_controlled_solutions_up_to_error() {
+        /// Finds a single solution, then fails.
+        struct FailingSolver(Option<Solution<Base2>>);
+
+        impl ControlledSolver<Base2> for FailingSolver {
+            fn solve_controlled(&mut self) -> Result<SolveOutcome<Base2>> {
+                match self.0.take() {
+                    Some(solution) => Ok(SolveOutcome::Solution(solution)),
+                    None => Err(anyhow::anyhow!("SAT solver failed")),
+                }
+            }
+        }
+
+        let solution = Solution::try_from(&crate::samples::base_2_solved()).unwrap();
+
+        assert_eq!(
+            count_controlled_solutions_up_to(&mut FailingSolver(Some(solution.clone())), 1)
+                .unwrap(),
+            1
+        );
+        assert!(count_controlled_solutions_up_to(&mut FailingSolver(Some(solution)), 2).is_err());
+    }
+
+    #[test]
+    fn test_count_solutions_up_to_unique() {
+        let grid = crate::samples::base_3().remove(0);
+
+        for backend in BACKENDS {
+            assert_eq!(count_solutions_up_to(&grid, 2, backend).unwrap(), 1);
+        }
+    }
+
+    #[test]
+    fn test_count_solutions_up_to_no_solution() {
+        let mut grid = Grid::<Base2>::new();
+        let value = Value::try_from(1).unwrap();
+        grid[Position::try_from((0, 0)).unwrap()].set_value(value);
+        grid[Position::try_from((0, 1)).unwrap()].set_value(value);
+
+        for backend in BACKENDS {
+            assert_eq!(count_solutions_up_to(&grid, 2, backend).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_count_solutions_up_to_sparse_base_3() {
+        // The empty grid has ~6.67×10^21 solutions.
+        let grid = Grid::<Base3>::new();
+
+        for backend in [SolverBackend::Backtracking, SolverBackend::Introspective] {
+            assert_eq!(count_solutions_up_to(&grid, 10, backend).unwrap(), 10);
+        }
+    }
+}
 
 #[cfg(test)]
 pub(crate) mod test_util {
@@ -152,7 +335,11 @@ pub(crate) mod test_util {
         assert_solution(&solution, puzzle);
     }
 
-    pub(crate) fn assert_solution<Base: SudokuBase>(solution: &Grid<Base>, puzzle: &Grid<Base>) {
+    pub(crate) fn assert_solution<Base: SudokuBase>(
+        solution: &Solution<Base>,
+        puzzle: &Grid<Base>,
+    ) {
+        solution.validate().unwrap();
         solution.assert_is_solution_for(puzzle);
     }
 
@@ -184,7 +371,7 @@ pub(crate) mod test_util {
         assert_eq!(solutions.len(), NUMBER_OF_BASE_2_SOLUTIONS);
 
         for solution in &solutions {
-            assert!(solution.is_solved());
+            solution.validate().unwrap();
         }
 
         let unique_solutions = solutions.into_iter().collect::<HashSet<_>>();
@@ -210,7 +397,7 @@ pub(crate) mod test_util {
         where
             Base: SudokuBase,
         {
-            type Item = Grid<Base>;
+            type Item = Solution<Base>;
 
             fn next(&mut self) -> Option<Self::Item> {
                 self.fallible_solution_iter.next().map(|solution_res| {
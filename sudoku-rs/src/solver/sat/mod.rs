@@ -6,16 +6,19 @@ use std::path::Path;
 
 use itertools::Itertools;
 use log::trace;
-use varisat::{CnfFormula, ExtendFormula, Lit, Solver as SatSolver};
+use varisat::{CnfFormula, ExtendFormula, Lit, Solver as SatSolver, SolverError};
 
 use crate::base::{BaseEnum, SudokuBase};
-use crate::cell::{Cell, Value};
+use crate::cell::Value;
 use crate::error::{Error, Result};
 use crate::grid::Grid;
+use crate::grid::solution::Solution;
 use crate::position::Position;
-use crate::solver::FallibleSolver;
 use crate::solver::backtracking::CandidatesFilter;
+use crate::solver::control::SolverControl;
 use crate::solver::sat::cell_variable::CellVariable;
+use crate::solver::sat::control_proof_processor::ControlProofProcessor;
+use crate::solver::{ControlledSolver, FallibleSolver, SolveOutcome};
 
 mod initialized_sat_solver {
     use super::*;
@@ -23,13 +26,79 @@ mod initialized_sat_solver {
     use std::sync::LazyLock;
 
     pub(super) static SOLVER_BASE_2: LazyLock<SatSolver> =
-        LazyLock::new(Solver::<Base2>::init_sat_solver);
+        LazyLock::new(|| Solver::<Base2>::init_sat_solver(ClauseSet::General));
     pub(super) static SOLVER_BASE_3: LazyLock<SatSolver> =
-        LazyLock::new(Solver::<Base3>::init_sat_solver);
+        LazyLock::new(|| Solver::<Base3>::init_sat_solver(ClauseSet::General));
     pub(super) static SOLVER_BASE_4: LazyLock<SatSolver> =
-        LazyLock::new(Solver::<Base4>::init_sat_solver);
+        LazyLock::new(|| Solver::<Base4>::init_sat_solver(ClauseSet::General));
     pub(super) static SOLVER_BASE_5: LazyLock<SatSolver> =
-        LazyLock::new(Solver::<Base5>::init_sat_solver);
+        LazyLock::new(|| Solver::<Base5>::init_sat_solver(ClauseSet::General));
+
+    pub(super) static SOLVER_BASE_2_TRIADS: LazyLock<SatSolver> =
+        LazyLock::new(|| Solver::<Base2>::init_sat_solver(ClauseSet::Triads));
+    pub(super) static SOLVER_BASE_3_TRIADS: LazyLock<SatSolver> =
+        LazyLock::new(|| Solver::<Base3>::init_sat_solver(ClauseSet::Triads));
+    pub(super) static SOLVER_BASE_4_TRIADS: LazyLock<SatSolver> =
+        LazyLock::new(|| Solver::<Base4>::init_sat_solver(ClauseSet::Triads));
+    pub(super) static SOLVER_BASE_5_TRIADS: LazyLock<SatSolver> =
+        LazyLock::new(|| Solver::<Base5>::init_sat_solver(ClauseSet::Triads));
+}
+
+/// Checks the [`SolverControl`] of a [`Solver`] during a single call of the SAT solver.
+///
+/// The SAT solver reports each step of its proof, e.g. a learned clause, to its proof processors.
+/// An error of a proof processor interrupts the SAT solver.
+mod control_proof_processor {
+    use std::cell::RefCell;
+
+    use varisat::checker::{CheckedProofStep, CheckerData, ProofProcessor};
+
+    use crate::solver::control::SolverControl;
+
+    thread_local! {
+        static ACTIVE_CONTROL: RefCell<Option<SolverControl>> = const { RefCell::new(None) };
+    }
+
+    /// Checks the control passed to [`with_active_control`].
+    pub(super) struct ControlProofProcessor;
+
+    impl ProofProcessor for ControlProofProcessor {
+        fn process_step(
+            &mut self,
+            _step: &CheckedProofStep,
+            _data: CheckerData,
+        ) -> Result<(), anyhow::Error> {
+            ACTIVE_CONTROL.with_borrow(|control| {
+                control
+                    .as_ref()
+                    .map_or(Ok(()), SolverControl::check_cancellation_and_deadline)
+                    .map_err(Into::into)
+            })
+        }
+    }
+
+    /// Calls `f`, while each [`ControlProofProcessor`] of this thread checks `control`.
+    pub(super) fn with_active_control<T>(control: &SolverControl, f: impl FnOnce() -> T) -> T {
+        let previous_control = ACTIVE_CONTROL.replace(Some(control.clone()));
+        let res = f();
+        ACTIVE_CONTROL.set(previous_control);
+        res
+    }
+}
+
+/// The set of clauses used to encode a sudoku.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ClauseSet {
+    /// Cell and group constraints.
+    #[default]
+    General,
+    /// The general clauses, extended by band and stack triad constraints.
+    ///
+    /// The additional variables allow the solver to reason about the intersections of lines and blocks
+    /// (locked candidates) by unit propagation, which should reduce the number of conflicts on hard puzzles.
+    /// Compare `GridMetric::SatTriadsStepCount` with `GridMetric::SatStepCount` for the effect on a grid,
+    /// the `SolverTdoku` benchmark compares the solve times on the tdoku datasets.
+    Triads,
 }
 
 type Clause = Vec<Lit>;
@@ -37,8 +106,14 @@ type Clause = Vec<Lit>;
 mod cell_variable;
 
 #[derive(Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct Solver<Base: SudokuBase> {
     sat_solver: SatSolver<'static>,
+    clause_set: ClauseSet,
+    /// The assumptions of the grid and filter, passed to `sat_solver`.
+    assumptions: Vec<Lit>,
+    /// Limits the solver, a step is a conflict.
+    control: SolverControl,
     _base: PhantomData<Base>,
 }
 
@@ -46,6 +121,9 @@ impl<Base: SudokuBase> Debug for Solver<Base> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Solver")
             .field("sat_solver", &"<missing>")
+            .field("clause_set", &self.clause_set)
+            .field("assumptions", &self.assumptions)
+            .field("control", &self.control)
             .field("_base", &self._base)
             .finish()
     }
@@ -61,10 +139,33 @@ impl<Base: SudokuBase> Solver<Base> {
         grid: GridRef,
         filter: &Filter,
     ) -> Self {
-        let sat_solver = Self::init_sat_solver_for_grid(grid.as_ref(), filter);
+        Self::with_candidates_filter_and_clause_set(grid, filter, ClauseSet::default())
+    }
+
+    pub fn with_clause_set<GridRef: AsRef<Grid<Base>>>(
+        grid: GridRef,
+        clause_set: ClauseSet,
+    ) -> Self {
+        Self::with_candidates_filter_and_clause_set(grid, &(), clause_set)
+    }
+
+    pub fn with_candidates_filter_and_clause_set<
+        GridRef: AsRef<Grid<Base>>,
+        Filter: CandidatesFilter<Base>,
+    >(
+        grid: GridRef,
+        filter: &Filter,
+        clause_set: ClauseSet,
+    ) -> Self {
+        let assumptions = Self::assumptions(grid.as_ref(), filter);
+        let mut sat_solver = Self::get_initialized_sat_solver(clause_set);
+        sat_solver.assume(&assumptions);
 
         Self {
             sat_solver,
+            clause_set,
+            assumptions,
+            control: SolverControl::default(),
             _base: PhantomData,
         }
     }
@@ -94,16 +195,69 @@ impl<Base: SudokuBase> Solver<Base> {
     pub fn step_count(&self) -> u64 {
         self.sat_solver.stats().conflicts
     }
+
+    /// Abort the solver once a limit of `control` is reached. A step is a conflict.
+    ///
+    /// The cancellation token and the deadline are checked on each proof step of the underlying SAT solver,
+    /// which interrupts a single long call. The step budget is checked before searching for the next solution.
+    /// If aborted, solving returns an [`AbortReason`](crate::solver::control::AbortReason) as error.
+    ///
+    /// Checking the proof steps slows down solving, therefore a control with limits
+    /// replaces the SAT solver with an uncached one, which reports its proof steps.
+    /// Must be called before solving.
+    ///
+    /// See [`ControlledSolver`].
+    #[must_use]
+    pub fn control(mut self, control: SolverControl) -> Self {
+        if control.has_limits() {
+            self.sat_solver = Self::init_controlled_sat_solver(self.clause_set);
+            self.sat_solver.assume(&self.assumptions);
+        }
+        self.control = control;
+        self
+    }
 }
 
-/// Helpers
+/// Incremental mode
 impl<Base: SudokuBase> Solver<Base> {
-    fn init_sat_solver_for_grid<Filter: CandidatesFilter<Base>>(
+    /// A solver without a grid, which can be reused to solve a sequence of related grids,
+    /// e.g. while removing clues during generation.
+    ///
+    /// See [`Self::try_solve_incremental`].
+    pub fn incremental(clause_set: ClauseSet) -> Self {
+        Self {
+            sat_solver: Self::get_initialized_sat_solver(clause_set),
+            clause_set,
+            assumptions: vec![],
+            control: SolverControl::default(),
+            _base: PhantomData,
+        }
+    }
+
+    /// Attempt to find a single solution for `grid`, which respects `filter`.
+    ///
+    /// The values of `grid` and the denied candidates of `filter` are passed to the SAT solver as assumptions,
+    /// replacing the ones of the previous call. No clauses are added,
+    /// therefore the same SAT solver instance, including the clauses learned in previous calls,
+    /// is used for all grids.
+    ///
+    /// [`Self::step_count`] accumulates over all calls.
+    pub fn try_solve_incremental<Filter: CandidatesFilter<Base>>(
+        &mut self,
         grid: &Grid<Base>,
         filter: &Filter,
-    ) -> SatSolver<'static> {
-        let mut sat_solver = Self::get_initialized_sat_solver();
+    ) -> Result<Option<Solution<Base>>> {
+        self.assumptions = Self::assumptions(grid, filter);
+        self.sat_solver.assume(&self.assumptions);
 
+        self.try_solve()
+    }
+}
+
+/// Helpers
+impl<Base: SudokuBase> Solver<Base> {
+    /// The values of `grid` and the denied candidates of `filter` as assumptions.
+    fn assumptions<Filter: CandidatesFilter<Base>>(grid: &Grid<Base>, filter: &Filter) -> Vec<Lit> {
         // Add grid assumptions
         let mut assumptions: Vec<Lit> = grid
             .all_value_positions()
@@ -136,50 +290,86 @@ impl<Base: SudokuBase> Solver<Base> {
                 }),
         );
 
-        sat_solver.assume(&assumptions);
-
-        sat_solver
+        assumptions
     }
 
     /// `Base`-cached version of `Self::init_sat_solver`
     #[allow(clippy::unnecessary_box_returns)]
-    fn get_initialized_sat_solver() -> SatSolver<'static> {
-        match Base::ENUM {
-            BaseEnum::Base2 => initialized_sat_solver::SOLVER_BASE_2.deref().clone(),
-            BaseEnum::Base3 => initialized_sat_solver::SOLVER_BASE_3.deref().clone(),
-            BaseEnum::Base4 => initialized_sat_solver::SOLVER_BASE_4.deref().clone(),
-            BaseEnum::Base5 => initialized_sat_solver::SOLVER_BASE_5.deref().clone(),
-        }
+    fn get_initialized_sat_solver(clause_set: ClauseSet) -> SatSolver<'static> {
+        use initialized_sat_solver::*;
+
+        let initialized_sat_solver = match (Base::ENUM, clause_set) {
+            (BaseEnum::Base2, ClauseSet::General) => &SOLVER_BASE_2,
+            (BaseEnum::Base3, ClauseSet::General) => &SOLVER_BASE_3,
+            (BaseEnum::Base4, ClauseSet::General) => &SOLVER_BASE_4,
+            (BaseEnum::Base5, ClauseSet::General) => &SOLVER_BASE_5,
+            (BaseEnum::Base2, ClauseSet::Triads) => &SOLVER_BASE_2_TRIADS,
+            (BaseEnum::Base3, ClauseSet::Triads) => &SOLVER_BASE_3_TRIADS,
+            (BaseEnum::Base4, ClauseSet::Triads) => &SOLVER_BASE_4_TRIADS,
+            (BaseEnum::Base5, ClauseSet::Triads) => &SOLVER_BASE_5_TRIADS,
+        };
+        initialized_sat_solver.deref().clone()
     }
 
-    fn init_sat_solver() -> SatSolver<'static> {
-        let clauses = Self::general_clauses();
+    fn init_sat_solver(clause_set: ClauseSet) -> SatSolver<'static> {
+        let mut sat_solver = SatSolver::new();
 
-        let mut formula = CnfFormula::new();
+        sat_solver.add_formula(&Self::formula(clause_set));
 
-        for clause in clauses {
-            formula.add_clause(&clause);
-        }
+        sat_solver
+    }
 
+    /// Like `Self::init_sat_solver`, but the SAT solver checks the control of its [`Solver`] on each proof step.
+    fn init_controlled_sat_solver(clause_set: ClauseSet) -> SatSolver<'static> {
         let mut sat_solver = SatSolver::new();
 
-        sat_solver.add_formula(&formula);
+        // Proof processors have to be added before any clause.
+        // `ControlProofProcessor` is zero-sized, therefore leaking it doesn't allocate.
+        sat_solver.add_proof_processor(Box::leak(Box::new(ControlProofProcessor)));
+        sat_solver.add_formula(&Self::formula(clause_set));
 
         sat_solver
     }
 
+    fn formula(clause_set: ClauseSet) -> CnfFormula {
+        let mut clauses = Self::general_clauses();
+        if clause_set == ClauseSet::Triads {
+            clauses.extend(Self::triad_clauses());
+        }
+
+        let mut formula = CnfFormula::new();
+
+        for clause in clauses {
+            formula.add_clause(&clause);
+        }
+
+        formula
+    }
+
     fn solve_with_assignment(&mut self) -> Result<Option<Vec<Lit>>> {
-        Ok(self.sat_solver.solve()?.then(|| {
+        self.control.check(self.step_count())?;
+
+        let is_satisfiable =
+            control_proof_processor::with_active_control(&self.control, || self.sat_solver.solve())
+                .map_err(|err| match err {
+                    // The `AbortReason` of `ControlProofProcessor`
+                    SolverError::ProofProcessorError { cause } => cause,
+                    err => err.into(),
+                })?;
+
+        Ok(is_satisfiable.then(|| {
             self.sat_solver
                 .model()
                 .expect("SatSolver should return model on successful solve")
         }))
     }
 
-    pub fn assigment_to_solution(assignment: &[Lit]) -> Result<Grid<Base>> {
+    pub fn assigment_to_solution(assignment: &[Lit]) -> Result<Solution<Base>> {
         let variables = assignment
             .iter()
             .copied()
+            // Skip auxiliary variables, e.g. of triads.
+            .filter(|&lit| lit.to_dimacs().unsigned_abs() <= Self::cell_variable_count())
             .map(CellVariable::<Base>::try_from)
             .collect::<Result<Vec<_>>>()?;
 
@@ -197,26 +387,15 @@ impl<Base: SudokuBase> Solver<Base> {
             true
         });
 
-        let solution = Grid::<Base>::with(
-            true_cell_variables
-                .map(|var| Cell::with_value(var.value, false))
-                .collect(),
-        )?;
-
-        debug_assert!(
-            solution.is_solved(),
-            "Solution should be solved:\n{solution}"
-        );
-
-        Ok(solution)
+        Ok(Solution::with_values_unchecked(Grid::with(
+            true_cell_variables.map(|var| var.value).collect(),
+        )?))
     }
 }
 
 /// Clauses
 impl<Base: SudokuBase> Solver<Base> {
     // TODO: test clauses
-    // TODO: implement other constraints from tdoku
-    //  especially triad based-constraints
     /// All clauses which only depend on the base of the sudoku.
     ///
     /// Reference: [tdoku blog](https://t-dillon.github.io/tdoku/)
@@ -323,12 +502,161 @@ impl<Base: SudokuBase> Solver<Base> {
             })
         })
     }
+
+    /// Triad-based clauses, which only depend on the base of the sudoku.
+    ///
+    /// A band triad is the intersection of a row and a block, a stack triad the one of a column and a block.
+    /// For each triad and value, an auxiliary variable expresses that the triad contains the value.
+    ///
+    /// Base3: 54 triads, 486 variables, 3240 clauses
+    ///
+    /// Reference: [tdoku blog](https://t-dillon.github.io/tdoku/#:~:text=triad)
+    fn triad_clauses() -> Vec<Clause> {
+        let mut clauses: Vec<Clause> = vec![];
+
+        clauses.extend(Self::triad_contains_value_definition_clauses());
+        clauses.extend(Self::each_group_contains_each_value_in_one_triad_clauses());
+
+        clauses
+    }
+
+    /// A triad contains a value, if and only if one of its cells contains the value.
+    ///
+    /// Base3: `54×9=486` positive clauses, 4 literals each and `3×486=1458` binary clauses
+    fn triad_contains_value_definition_clauses() -> impl Iterator<Item = Clause> {
+        Self::triads()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(triad_index, triad)| {
+                Value::<Base>::all().flat_map(move |value| {
+                    let triad_lit = Self::triad_lit(triad_index, value);
+                    let cell_lits = triad.clone().into_iter().map(move |pos| {
+                        Lit::from(CellVariable {
+                            pos,
+                            value,
+                            is_true: true,
+                        })
+                    });
+
+                    std::iter::once(
+                        std::iter::once(!triad_lit)
+                            .chain(cell_lits.clone())
+                            .collect(),
+                    )
+                    .chain(cell_lits.map(move |cell_lit| vec![!cell_lit, triad_lit]))
+                })
+            })
+    }
+
+    /// Each row, column and block contains each value in exactly one of its triads.
+    ///
+    /// Base3: `4×81=324` positive clauses, 3 literals each and `3×324=972` binary clauses
+    fn each_group_contains_each_value_in_one_triad_clauses() -> impl Iterator<Item = Clause> {
+        Self::groups_of_triads()
+            .into_iter()
+            .flat_map(|triad_indexes| {
+                Value::<Base>::all().flat_map(move |value| {
+                    let triad_lits = triad_indexes
+                        .iter()
+                        .map(|&triad_index| Self::triad_lit(triad_index, value))
+                        .collect_vec();
+
+                    std::iter::once(triad_lits.clone()).chain(
+                        triad_lits
+                            .into_iter()
+                            .tuple_combinations()
+                            .map(|(triad_lit1, triad_lit2)| vec![!triad_lit1, !triad_lit2]),
+                    )
+                })
+            })
+    }
+
+    /// The positions of each triad, indexed by the triad index.
+    ///
+    /// Band triads are ordered by row and then block column,
+    /// followed by the stack triads, ordered by column and then block row.
+    fn triads() -> Vec<Vec<Position<Base>>> {
+        let base = Base::BASE;
+        let triad = |(line, block_coordinate): (u8, u8), is_band: bool| {
+            (0..base)
+                .map(|offset| {
+                    let along_line = block_coordinate * base + offset;
+                    let (row, column) = if is_band {
+                        (line, along_line)
+                    } else {
+                        (along_line, line)
+                    };
+                    Position::try_from((row, column)).unwrap()
+                })
+                .collect()
+        };
+
+        let lines_and_block_coordinates = (0..Base::SIDE_LENGTH).cartesian_product(0..base);
+        lines_and_block_coordinates
+            .clone()
+            .map(|line_and_block_coordinate| triad(line_and_block_coordinate, true))
+            .chain(
+                lines_and_block_coordinates
+                    .map(|line_and_block_coordinate| triad(line_and_block_coordinate, false)),
+            )
+            .collect()
+    }
+
+    /// For each row, column and block, the indexes of the triads which partition it.
+    ///
+    /// Blocks are partitioned by both their band and their stack triads.
+    fn groups_of_triads() -> Vec<Vec<usize>> {
+        let base = usize::from(Base::BASE);
+        let side_length = usize::from(Base::SIDE_LENGTH);
+        let stack_triads_offset = side_length * base;
+
+        let lines = (0..side_length).flat_map(|line| {
+            [0, stack_triads_offset].map(|offset| {
+                (0..base)
+                    .map(|block_coordinate| offset + line * base + block_coordinate)
+                    .collect()
+            })
+        });
+        let blocks = (0..base)
+            .cartesian_product(0..base)
+            .flat_map(|(block_row, block_column)| {
+                [
+                    (0..base)
+                        .map(|offset| (block_row * base + offset) * base + block_column)
+                        .collect(),
+                    (0..base)
+                        .map(|offset| {
+                            stack_triads_offset + (block_column * base + offset) * base + block_row
+                        })
+                        .collect(),
+                ]
+            });
+
+        lines.chain(blocks).collect()
+    }
+
+    /// The number of variables of the form "cell contains value".
+    fn cell_variable_count() -> usize {
+        usize::from(Base::CELL_COUNT) * usize::from(Base::SIDE_LENGTH)
+    }
+
+    /// A positive literal for the auxiliary variable expressing that the triad contains `value`.
+    ///
+    /// Numbered after all cell variables.
+    fn triad_lit(triad_index: usize, value: Value<Base>) -> Lit {
+        let variable = Self::cell_variable_count()
+            + triad_index * usize::from(Base::SIDE_LENGTH)
+            + usize::from(value.get() - 1)
+            + 1;
+
+        Lit::from_dimacs(variable.try_into().unwrap())
+    }
 }
 
 impl<Base: SudokuBase> FallibleSolver<Base> for Solver<Base> {
     type Error = Error;
 
-    fn try_solve(&mut self) -> Result<Option<Grid<Base>>> {
+    fn try_solve(&mut self) -> Result<Option<Solution<Base>>> {
         Ok(if let Some(assignment) = self.solve_with_assignment()? {
             let solution = Self::assigment_to_solution(&assignment)?;
             Some(solution)
@@ -338,8 +666,14 @@ impl<Base: SudokuBase> FallibleSolver<Base> for Solver<Base> {
     }
 }
 
+impl<Base: SudokuBase> ControlledSolver<Base> for Solver<Base> {
+    fn solve_controlled(&mut self) -> Result<SolveOutcome<Base>> {
+        SolveOutcome::from_fallible(self.try_solve())
+    }
+}
+
 impl<Base: SudokuBase> IntoIterator for Solver<Base> {
-    type Item = Result<Grid<Base>>;
+    type Item = Result<Solution<Base>>;
 
     type IntoIter = SolverIter<Base>;
 
@@ -358,7 +692,7 @@ pub struct SolverIter<Base: SudokuBase> {
 }
 
 impl<Base: SudokuBase> Iterator for SolverIter<Base> {
-    type Item = Result<Grid<Base>>;
+    type Item = Result<Solution<Base>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(last_assignment) = self.last_assignment.take() {
@@ -385,11 +719,12 @@ impl<Base: SudokuBase> Iterator for SolverIter<Base> {
 
 #[cfg(test)]
 mod tests {
-    use crate::base::consts::Base2;
+    use crate::base::consts::{Base2, Base3};
+    use crate::solver::backtracking::DisallowedCandidateAtPosition;
     use crate::solver::test_util::{
         assert_fallible_solution_iter_as_infallible, assert_fallible_solver_single_solution,
         assert_infallible_solution_iter_all_solutions_base_2,
-        assert_infallible_solution_iter_single_solution, tests_solver_samples,
+        assert_infallible_solution_iter_single_solution, assert_solution, tests_solver_samples,
     };
     use crate::test_util::init_test_logger;
 
@@ -422,6 +757,30 @@ mod tests {
                 }
             }
         }
+
+        mod fallible_solver_triads {
+            use super::*;
+
+            tests_solver_samples! {
+                |grid| {
+                    let mut solver = Solver::with_clause_set(&grid, ClauseSet::Triads);
+                    assert_fallible_solver_single_solution(&mut solver, &grid);
+                }
+            }
+        }
+
+        mod fallible_solution_iter_triads {
+            use super::*;
+
+            tests_solver_samples! {
+                |grid| {
+                    let solver = Solver::with_clause_set(&grid, ClauseSet::Triads);
+                    assert_infallible_solution_iter_single_solution(
+                        assert_fallible_solution_iter_as_infallible(solver.into_iter()), &grid
+                    );
+                }
+            }
+        }
     }
 
     #[test]
@@ -434,6 +793,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_iter_all_solutions_triads() {
+        let grid = Grid::<Base2>::new();
+        let solver = Solver::with_clause_set(&grid, ClauseSet::Triads);
+
+        assert_infallible_solution_iter_all_solutions_base_2(
+            assert_fallible_solution_iter_as_infallible(solver.into_iter()),
+        );
+    }
+
+    #[test]
+    fn test_triads() {
+        type Base = Base3;
+
+        let triads = Solver::<Base>::triads();
+        assert_eq!(triads.len(), 54);
+        assert_eq!(
+            triads[4],
+            vec![
+                Position::try_from((1, 3)).unwrap(),
+                Position::try_from((1, 4)).unwrap(),
+                Position::try_from((1, 5)).unwrap()
+            ]
+        );
+        assert_eq!(
+            triads[27 + 4],
+            vec![
+                Position::try_from((3, 1)).unwrap(),
+                Position::try_from((4, 1)).unwrap(),
+                Position::try_from((5, 1)).unwrap()
+            ]
+        );
+
+        // Each group of triads partitions a row, column or block.
+        let groups_of_triads = Solver::<Base>::groups_of_triads();
+        assert_eq!(groups_of_triads.len(), 36);
+        let all_groups = Position::<Base>::all_groups()
+            .map(|group| group.sorted().collect_vec())
+            .collect_vec();
+        for triad_indexes in groups_of_triads {
+            let positions = triad_indexes
+                .into_iter()
+                .flat_map(|triad_index| triads[triad_index].clone())
+                .sorted()
+                .collect_vec();
+            assert!(all_groups.contains(&positions), "{positions:?}");
+        }
+    }
+
+    #[test]
+    fn test_triads_same_solution() {
+        fn assert_same_solution<Base: SudokuBase>(grids: Vec<Grid<Base>>) {
+            for grid in grids {
+                let general_solution = Solver::with_clause_set(&grid, ClauseSet::General)
+                    .try_solve()
+                    .unwrap()
+                    .unwrap();
+                let triads_solution = Solver::with_clause_set(&grid, ClauseSet::Triads)
+                    .try_solve()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(triads_solution, general_solution);
+            }
+        }
+
+        assert_same_solution(crate::samples::base_3());
+        assert_same_solution(crate::samples::base_4());
+    }
+
+    #[test]
+    fn test_incremental() {
+        let mut solver = Solver::incremental(ClauseSet::default());
+
+        for grid in crate::samples::base_3() {
+            let solution = solver.try_solve_incremental(&grid, &()).unwrap().unwrap();
+            assert_solution(&solution, &grid);
+        }
+    }
+
+    #[test]
+    fn test_incremental_remove_givens() {
+        type Base = Base3;
+
+        let mut solver = Solver::incremental(ClauseSet::default());
+
+        let puzzle = crate::samples::base_3().remove(0);
+        let mut grid = Solver::new(&puzzle).try_solve().unwrap().unwrap().to_grid();
+
+        // Remove givens, while the grid has a unique solution.
+        for pos in Position::<Base>::all() {
+            let value = grid[pos].value().unwrap();
+            grid[pos].delete();
+
+            let filter = DisallowedCandidateAtPosition {
+                pos,
+                candidate: value,
+            };
+            let other_solution = solver.try_solve_incremental(&grid, &filter).unwrap();
+            let expected_other_solution = Solver::with_candidates_filter(&grid, &filter)
+                .try_solve()
+                .unwrap();
+            assert_eq!(other_solution.is_some(), expected_other_solution.is_some());
+
+            if let Some(other_solution) = other_solution {
+                assert_solution(&other_solution, &grid);
+                assert_ne!(other_solution[pos], value);
+                grid[pos].set_value(value);
+            }
+        }
+
+        assert!(grid.has_unique_solution());
+        // The assumptions of the previous calls are replaced.
+        assert!(solver.try_solve_incremental(&grid, &()).unwrap().is_some());
+    }
+
     #[test]
     fn test_candidates_filter_denied_candidates_grid() {
         type Base = Base2;
@@ -447,18 +921,74 @@ mod tests {
         let solver = Solver::with_candidates_filter(&grid, &denylist);
 
         for solution in solver.clone() {
-            assert!(
-                ![1, 3].contains(
-                    &solution
-                        .unwrap()
-                        .get(Position::top_left())
-                        .value()
-                        .unwrap()
-                        .get()
-                )
-            );
+            assert!(![1, 3].contains(&solution.unwrap().get(Position::top_left()).get()));
         }
 
         assert_eq!(solver.clone().into_iter().count(), 144);
     }
+
+    #[test]
+    fn test_control() {
+        use crate::solver::control::{AbortReason, CancellationToken, SolverControl};
+        use std::time::Duration;
+
+        let grid = crate::samples::base_3().remove(0);
+
+        let mut solver = Solver::new(&grid).control(SolverControl::default());
+        assert!(matches!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Solution(_)
+        ));
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let mut solver = Solver::new(&grid)
+            .control(SolverControl::new().with_cancellation_token(cancellation_token));
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::Cancelled)
+        );
+        let err = solver.try_solve().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AbortReason>(),
+            Some(&AbortReason::Cancelled)
+        );
+
+        let mut solver =
+            Solver::new(&grid).control(SolverControl::new().with_timeout(Duration::ZERO));
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::DeadlineExceeded)
+        );
+
+        let mut solver = Solver::new(&grid).control(SolverControl::new().with_max_steps(u64::MAX));
+        assert!(matches!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Solution(_)
+        ));
+    }
+
+    #[test]
+    fn test_control_interrupts_single_call() {
+        use crate::solver::control::{AbortReason, CancellationToken, SolverControl};
+        use std::thread;
+        use std::time::Duration;
+
+        let grid = crate::samples::base_5().remove(0);
+
+        let cancellation_token = CancellationToken::new();
+        let mut solver = Solver::new(&grid)
+            .control(SolverControl::new().with_cancellation_token(cancellation_token.clone()));
+
+        // Cancels the token, while the first call of the SAT solver is running.
+        let outcome = thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(1));
+                cancellation_token.cancel();
+            });
+            solver.solve_controlled().unwrap()
+        });
+
+        assert_eq!(outcome, SolveOutcome::Aborted(AbortReason::Cancelled));
+    }
 }
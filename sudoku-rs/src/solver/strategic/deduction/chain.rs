@@ -0,0 +1,283 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{Context, ensure};
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::transport::{
+    TransportChainNode, TransportLink, TransportLinkType,
+};
+
+/// A single candidate inside a chain.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ChainNode<Base: SudokuBase> {
+    pub position: Position<Base>,
+    pub candidate: Value<Base>,
+}
+
+impl<Base: SudokuBase> ChainNode<Base> {
+    pub fn new(position: Position<Base>, candidate: Value<Base>) -> Self {
+        Self {
+            position,
+            candidate,
+        }
+    }
+}
+
+impl<Base: SudokuBase> TryFrom<TransportChainNode> for ChainNode<Base> {
+    type Error = Error;
+
+    fn try_from(transport_chain_node: TransportChainNode) -> Result<Self> {
+        let TransportChainNode {
+            position,
+            candidate,
+        } = transport_chain_node;
+        Ok(Self::new(position.try_into()?, candidate.try_into()?))
+    }
+}
+
+impl<Base: SudokuBase> Display for ChainNode<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.position, self.candidate)
+    }
+}
+
+/// The type of inference between two candidates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LinkType {
+    /// At least one of the candidates is true.
+    Strong,
+    /// At most one of the candidates is true.
+    Weak,
+}
+
+impl From<TransportLinkType> for LinkType {
+    fn from(transport_kind: TransportLinkType) -> Self {
+        match transport_kind {
+            TransportLinkType::Strong => Self::Strong,
+            TransportLinkType::Weak => Self::Weak,
+        }
+    }
+}
+
+impl Display for LinkType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkType::Strong => write!(f, "="),
+            LinkType::Weak => write!(f, "-"),
+        }
+    }
+}
+
+/// A strong or weak link between two candidates.
+/// Used to highlight the inference between two candidates in the UI.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Link<Base: SudokuBase> {
+    pub from: ChainNode<Base>,
+    pub to: ChainNode<Base>,
+    pub kind: LinkType,
+}
+
+impl<Base: SudokuBase> TryFrom<TransportLink> for Link<Base> {
+    type Error = Error;
+
+    fn try_from(transport_link: TransportLink) -> Result<Self> {
+        let TransportLink { from, to, kind } = transport_link;
+        Ok(Self::new(from.try_into()?, to.try_into()?, kind.into()))
+    }
+}
+
+impl<Base: SudokuBase> Display for Link<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.from, self.kind, self.to)
+    }
+}
+
+impl<Base: SudokuBase> Link<Base> {
+    pub fn new(from: ChainNode<Base>, to: ChainNode<Base>, kind: LinkType) -> Self {
+        Self { from, to, kind }
+    }
+
+    pub fn strong(from: ChainNode<Base>, to: ChainNode<Base>) -> Self {
+        Self::new(from, to, LinkType::Strong)
+    }
+
+    pub fn weak(from: ChainNode<Base>, to: ChainNode<Base>) -> Self {
+        Self::new(from, to, LinkType::Weak)
+    }
+
+    /// Both candidates must exist in the grid and either share a cell or be the same candidate in cells,
+    /// which see each other.
+    pub fn validate(&self, grid: &Grid<Base>) -> Result<()> {
+        (|| {
+            for node in [self.from, self.to] {
+                ensure!(
+                    grid[node.position]
+                        .candidates()
+                        .is_some_and(|candidates| candidates.has(node.candidate)),
+                    "expected candidate at {node}"
+                );
+            }
+            if self.from.position == self.to.position {
+                ensure!(
+                    self.from.candidate != self.to.candidate,
+                    "expected different candidates in the same cell"
+                );
+            } else {
+                ensure!(
+                    self.from.candidate == self.to.candidate
+                        && self.from.position.sees(self.to.position),
+                    "expected the same candidate in cells, which see each other"
+                );
+            }
+            Ok(())
+        })()
+        .with_context(|| format!("Invalid link {self}"))
+    }
+}
+
+/// An ordered alternating inference chain, used by chain-based strategies.
+///
+/// Consecutive nodes are connected by alternating strong and weak links, starting and ending with a strong link.
+/// Therefore a chain always contains an even number of nodes, and either the first or the last node must be true.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Chain<Base: SudokuBase> {
+    nodes: Vec<ChainNode<Base>>,
+}
+
+impl<Base: SudokuBase> TryFrom<Vec<ChainNode<Base>>> for Chain<Base> {
+    type Error = Error;
+
+    fn try_from(nodes: Vec<ChainNode<Base>>) -> Result<Self> {
+        ensure!(
+            nodes.len() >= 2 && nodes.len().is_multiple_of(2),
+            "expected a chain with an even number of nodes, got {}",
+            nodes.len()
+        );
+        Ok(Self { nodes })
+    }
+}
+
+impl<Base: SudokuBase> Display for Chain<Base> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.nodes[0])?;
+        for link in self.links() {
+            write!(f, " {} {}", link.kind, link.to)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Base: SudokuBase> Chain<Base> {
+    pub fn nodes(&self) -> &[ChainNode<Base>] {
+        &self.nodes
+    }
+
+    /// The links between consecutive nodes, alternating between strong and weak links.
+    pub fn links(&self) -> impl Iterator<Item = Link<Base>> + '_ {
+        self.nodes
+            .iter()
+            .tuple_windows()
+            .enumerate()
+            .map(|(i, (&from, &to))| {
+                let kind = if i % 2 == 0 {
+                    LinkType::Strong
+                } else {
+                    LinkType::Weak
+                };
+                Link::new(from, to, kind)
+            })
+    }
+
+    /// The first and the last node of the chain. At least one of them must be true.
+    pub fn ends(&self) -> (ChainNode<Base>, ChainNode<Base>) {
+        (
+            *self.nodes.first().expect("chain to be non-empty"),
+            *self.nodes.last().expect("chain to be non-empty"),
+        )
+    }
+
+    pub fn validate(&self, grid: &Grid<Base>) -> Result<()> {
+        (|| {
+            for node in &self.nodes {
+                ensure!(
+                    grid[node.position]
+                        .candidates()
+                        .is_some_and(|candidates| candidates.has(node.candidate)),
+                    "expected candidate at {node}"
+                );
+            }
+            ensure!(self.nodes.iter().all_unique(), "expected unique nodes");
+            Ok(())
+        })()
+        .with_context(|| format!("Invalid chain {self}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::consts::Base2;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let node = |row: u8, column: u8, candidate: u8| {
+            ChainNode::<Base2>::new(
+                (row, column).try_into().unwrap(),
+                candidate.try_into().unwrap(),
+            )
+        };
+
+        let chain: Chain<Base2> = vec![node(0, 0, 1), node(0, 3, 1), node(1, 3, 1), node(1, 3, 2)]
+            .try_into()
+            .unwrap();
+
+        assert_eq!(chain.to_string(), "r0c0(1) = r0c3(1) - r1c3(1) = r1c3(2)");
+        Chain::try_from(vec![node(0, 0, 1)]).unwrap_err();
+
+        assert_eq!(
+            chain.links().collect_vec(),
+            vec![
+                Link::strong(node(0, 0, 1), node(0, 3, 1)),
+                Link::weak(node(0, 3, 1), node(1, 3, 1)),
+                Link::strong(node(1, 3, 1), node(1, 3, 2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_link_validate() {
+        let node = |row: u8, column: u8, candidate: u8| {
+            ChainNode::<Base2>::new(
+                (row, column).try_into().unwrap(),
+                candidate.try_into().unwrap(),
+            )
+        };
+        let mut grid = Grid::<Base2>::new();
+        grid.set_all_direct_candidates();
+
+        Link::strong(node(0, 0, 1), node(0, 3, 1))
+            .validate(&grid)
+            .unwrap();
+        Link::weak(node(0, 0, 1), node(0, 0, 2))
+            .validate(&grid)
+            .unwrap();
+        // Same candidate
+        Link::weak(node(0, 0, 1), node(0, 0, 1))
+            .validate(&grid)
+            .unwrap_err();
+        // Different candidates in different cells
+        Link::weak(node(0, 0, 1), node(0, 1, 2))
+            .validate(&grid)
+            .unwrap_err();
+        // Cells don't see each other
+        Link::strong(node(0, 0, 1), node(3, 3, 1))
+            .validate(&grid)
+            .unwrap_err();
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter};
 
 use anyhow::ensure;
@@ -6,11 +7,11 @@ use itertools::Itertools;
 use crate::base::SudokuBase;
 use crate::error::{Error, Result};
 use crate::grid::Grid;
-use crate::position::{Position, PositionMap};
+use crate::position::{GroupCoordinate, Position, PositionMap};
 use crate::solver::strategic::deduction::transport::{
     PositionedTransportAction, PositionedTransportReason, TransportDeduction,
 };
-use crate::solver::strategic::deduction::{Action, Reason};
+use crate::solver::strategic::deduction::{Action, Chain, Link, Reason};
 
 // TODO: make generic over the position/index type.
 //  use-case: reporting Deduction for a single group.
@@ -25,10 +26,16 @@ use crate::solver::strategic::deduction::{Action, Reason};
 pub struct Deduction<Base: SudokuBase> {
     pub actions: PositionMap<Base, Action<Base>>,
     pub reasons: PositionMap<Base, Reason<Base>>,
+    /// The ordered chain, which led to this deduction.
+    /// Only reported by chain-based strategies.
+    pub chain: Option<Chain<Base>>,
+    /// Whole rows, columns or blocks, which were a reason for the actions.
+    pub groups: BTreeSet<GroupCoordinate<Base>>,
+    /// Strong or weak links between two candidates, which were a reason for the actions.
+    pub links: BTreeSet<Link<Base>>,
     // TODO: cost for this deduction
     //  we currently only support cost per Strategy.
     //  Different deductions produced by a strategy could vary in difficulty, e.g.:
-    //  - LockedSets/LockedCandidates: naked vs hidden, set size
     //  - Chain-based strategies: length of chain
 }
 
@@ -36,7 +43,13 @@ impl<Base: SudokuBase> TryFrom<TransportDeduction> for Deduction<Base> {
     type Error = Error;
 
     fn try_from(transport_deduction: TransportDeduction) -> Result<Self> {
-        let TransportDeduction { actions, reasons } = transport_deduction;
+        let TransportDeduction {
+            actions,
+            reasons,
+            chain,
+            groups,
+            links,
+        } = transport_deduction;
         Ok(Self {
             actions: PositionMap::try_from_iter(
                 actions
@@ -48,6 +61,25 @@ impl<Base: SudokuBase> TryFrom<TransportDeduction> for Deduction<Base> {
                     .into_iter()
                     .map(|PositionedTransportReason { position, reason }| (position, reason)),
             )?,
+            chain: chain
+                .map(|chain| {
+                    chain
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>>>()?
+                        .try_into()
+                })
+                .transpose()?,
+            groups: groups
+                .into_iter()
+                .flatten()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            links: links
+                .into_iter()
+                .flatten()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -65,7 +97,17 @@ impl<Base: SudokuBase> Display for Deduction<Base> {
                 .iter()
                 .map(|(pos, reason)| format!("{pos}: {reason}"))
                 .join(", ")
-        )
+        )?;
+        if !self.groups.is_empty() {
+            write!(f, ", groups: {}", self.groups.iter().join(", "))?;
+        }
+        if !self.links.is_empty() {
+            write!(f, ", links: {}", self.links.iter().join(", "))?;
+        }
+        if let Some(chain) = &self.chain {
+            write!(f, ", chain: {chain}")?;
+        }
+        Ok(())
     }
 }
 
@@ -80,6 +122,9 @@ impl<Base: SudokuBase> Deduction<Base> {
         Self {
             actions: PositionMap::new(),
             reasons: PositionMap::new(),
+            chain: None,
+            groups: BTreeSet::new(),
+            links: BTreeSet::new(),
         }
     }
 
@@ -94,6 +139,7 @@ impl<Base: SudokuBase> Deduction<Base> {
         Self {
             actions: PositionMap::with_single(pos, action),
             reasons,
+            ..Default::default()
         }
     }
 
@@ -132,9 +178,28 @@ impl<Base: SudokuBase> Deduction<Base> {
         Ok(Self {
             reasons: PositionMap::try_from_iter(reasons)?,
             actions: PositionMap::try_from_iter(actions)?,
+            ..Default::default()
         })
     }
 
+    #[must_use]
+    pub fn with_chain(mut self, chain: Chain<Base>) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    #[must_use]
+    pub fn with_group(mut self, group: GroupCoordinate<Base>) -> Self {
+        self.groups.insert(group);
+        self
+    }
+
+    #[must_use]
+    pub fn with_link(mut self, link: Link<Base>) -> Self {
+        self.links.insert(link);
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.actions.is_empty() && self.reasons.is_empty()
     }
@@ -153,6 +218,14 @@ impl<Base: SudokuBase> Deduction<Base> {
             reason.validate(grid.get(pos))?;
         }
 
+        for link in &self.links {
+            link.validate(grid)?;
+        }
+
+        if let Some(chain) = &self.chain {
+            chain.validate(grid)?;
+        }
+
         // TODO: validate that actions and reasons are not in conflict, e.g. for the same position:
         //  - SetValue and Reason
         //  - DeleteCandidate and Reason share candidate
@@ -4,9 +4,9 @@ use std::fmt::{Display, Formatter};
 use crate::base::SudokuBase;
 use crate::error::{Error, Result};
 use crate::grid::Grid;
-use crate::position::PositionMap;
+use crate::position::{GroupCoordinate, PositionMap};
 use crate::solver::strategic::deduction::transport::TransportDeductions;
-use crate::solver::strategic::deduction::{Action, Deduction, Reason};
+use crate::solver::strategic::deduction::{Action, Deduction, Link, Reason};
 
 /// A list of deductions made by a strategy.
 /// Some strategies can be applied multiple times on a single grid, e.g.:
@@ -24,6 +24,13 @@ pub struct Deductions<Base: SudokuBase> {
     deductions: BTreeSet<Deduction<Base>>,
 }
 
+/// The positioned reasons, groups and links of a deduction.
+type DeductionReasons<Base> = (
+    PositionMap<Base, Reason<Base>>,
+    BTreeSet<GroupCoordinate<Base>>,
+    BTreeSet<Link<Base>>,
+);
+
 impl<Base: SudokuBase> TryFrom<TransportDeductions> for Deductions<Base> {
     type Error = Error;
 
@@ -76,6 +83,8 @@ impl<Base: SudokuBase> Deductions<Base> {
             for (pos, reasons) in &deduction.reasons {
                 merged_deduction.reasons.insert(pos, *reasons)?;
             }
+            merged_deduction.groups.extend(&deduction.groups);
+            merged_deduction.links.extend(&deduction.links);
         }
         Ok(merged_deduction)
     }
@@ -83,43 +92,70 @@ impl<Base: SudokuBase> Deductions<Base> {
     /// If two deductions contain the same reasons, merge them into a single deduction by merging their actions.
     pub fn merge_deductions_by_reasons(self) -> Result<Self> {
         let mut reasons_to_actions: BTreeMap<
-            PositionMap<Base, Reason<Base>>,
+            DeductionReasons<Base>,
             PositionMap<Base, Action<Base>>,
         > = BTreeMap::new();
 
-        for Deduction { reasons, actions } in self {
-            if let Some(existing_actions) = reasons_to_actions.get_mut(&reasons) {
+        for Deduction {
+            reasons,
+            actions,
+            groups,
+            links,
+            ..
+        } in self
+        {
+            let key = (reasons, groups, links);
+            if let Some(existing_actions) = reasons_to_actions.get_mut(&key) {
                 existing_actions.merge(actions)?;
             } else {
-                reasons_to_actions.insert(reasons, actions);
+                reasons_to_actions.insert(key, actions);
             }
         }
 
         Ok(reasons_to_actions
             .into_iter()
-            .map(|(reasons, actions)| Deduction { actions, reasons })
+            .map(|((reasons, groups, links), actions)| Deduction {
+                actions,
+                reasons,
+                groups,
+                links,
+                ..Default::default()
+            })
             .collect())
     }
 
     /// If two deductions contain the same actions, merge them into a single deduction by merging their reasons.
     pub fn merge_deductions_by_actions(self) -> Result<Self> {
-        let mut actions_to_reasons: BTreeMap<
-            PositionMap<Base, Action<Base>>,
-            PositionMap<Base, Reason<Base>>,
-        > = BTreeMap::new();
-
-        for Deduction { reasons, actions } in self {
-            if let Some(existing_reasons) = actions_to_reasons.get_mut(&actions) {
-                existing_reasons.merge(reasons)?;
+        let mut actions_to_deduction: BTreeMap<PositionMap<Base, Action<Base>>, Deduction<Base>> =
+            BTreeMap::new();
+
+        for Deduction {
+            reasons,
+            actions,
+            groups,
+            links,
+            ..
+        } in self
+        {
+            if let Some(existing_deduction) = actions_to_deduction.get_mut(&actions) {
+                existing_deduction.reasons.merge(reasons)?;
+                existing_deduction.groups.extend(groups);
+                existing_deduction.links.extend(links);
             } else {
-                actions_to_reasons.insert(actions, reasons);
+                actions_to_deduction.insert(
+                    actions.clone(),
+                    Deduction {
+                        actions,
+                        reasons,
+                        groups,
+                        links,
+                        ..Default::default()
+                    },
+                );
             }
         }
 
-        Ok(actions_to_reasons
-            .into_iter()
-            .map(|(actions, reasons)| Deduction { actions, reasons })
-            .collect())
+        Ok(actions_to_deduction.into_values().collect())
     }
 
     pub fn validate(&self, grid: &Grid<Base>) -> Result<()> {
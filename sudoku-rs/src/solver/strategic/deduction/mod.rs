@@ -1,9 +1,11 @@
 pub use action::Action;
+pub use chain::{Chain, ChainNode, Link, LinkType};
 pub use deduction::Deduction;
 pub use deductions::Deductions;
 pub use reason::Reason;
 
 mod action;
+mod chain;
 #[allow(clippy::module_inception)]
 mod deduction;
 mod deductions;
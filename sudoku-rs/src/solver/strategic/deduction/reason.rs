@@ -15,25 +15,52 @@ use crate::solver::strategic::deduction::transport::TransportReason;
 /// Used to highlight/explain a deduction in the UI.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Reason<Base: SudokuBase> {
+    /// The cell as a whole was a reason for an action, independent of its candidates or value.
+    /// Used to highlight cells, which are not covered by a candidate reason, e.g. the solved cells of a group.
+    Cell,
     /// This set of candidates was a reason for an action.
     /// Highlighted with a green background in the UI.
     Candidates(Candidates<Base>),
-    // TODO: add Reason::Cell for group highlighting
-    //  alternative: reference Group by Coordinate and Group type (row, column, block)
+    /// This set of fin candidates was a reason for an action, optionally combined with regular reason candidates.
+    /// Used by finned fish strategies, where the fins restrict the eliminations to the cells which see all fins.
+    /// Fins are highlighted with an orange background in the UI.
+    Fin {
+        candidates: Candidates<Base>,
+        fins: Candidates<Base>,
+    },
+    /// Two classes of candidates, which are highlighted in different colors in the UI.
+    /// Used by coloring strategies, where all candidates of one color class are either all true or all false.
+    /// Also used by almost locked set strategies, to distinguish the restricted common candidates (color 1)
+    /// from the other candidates of the sets (color 2).
+    Colors {
+        color_1: Candidates<Base>,
+        color_2: Candidates<Base>,
+    },
 }
 
 impl<Base: SudokuBase> TryFrom<TransportReason> for Reason<Base> {
     type Error = Error;
 
     fn try_from(transport_reason: TransportReason) -> Result<Self> {
-        let TransportReason::Candidates(candidates) = transport_reason;
-        Ok(Self::Candidates(candidates.try_into()?))
+        Ok(match transport_reason {
+            TransportReason::Cell {} => Self::Cell,
+            TransportReason::Candidates(candidates) => Self::Candidates(candidates.try_into()?),
+            TransportReason::Fin { candidates, fins } => Self::Fin {
+                candidates: candidates.try_into()?,
+                fins: fins.try_into()?,
+            },
+            TransportReason::Colors { color_1, color_2 } => Self::Colors {
+                color_1: color_1.try_into()?,
+                color_2: color_2.try_into()?,
+            },
+        })
     }
 }
 
 impl<Base: SudokuBase> Display for Reason<Base> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Reason::Cell => write!(f, "cell"),
             Reason::Candidates(candidates) => {
                 if let Some(candidate) = candidates.to_single() {
                     write!(f, "candidate {candidate}")
@@ -41,11 +68,30 @@ impl<Base: SudokuBase> Display for Reason<Base> {
                     write!(f, "candidates {candidates}")
                 }
             }
+            Reason::Fin { candidates, fins } => {
+                if candidates.is_empty() {
+                    write!(f, "fins {fins}")
+                } else {
+                    write!(f, "candidates {candidates} with fins {fins}")
+                }
+            }
+            Reason::Colors { color_1, color_2 } => match (color_1.is_empty(), color_2.is_empty()) {
+                (false, true) => write!(f, "color 1 candidates {color_1}"),
+                (true, false) => write!(f, "color 2 candidates {color_2}"),
+                _ => write!(
+                    f,
+                    "color 1 candidates {color_1} and color 2 candidates {color_2}"
+                ),
+            },
         }
     }
 }
 
 impl<Base: SudokuBase> Reason<Base> {
+    pub fn cell() -> Self {
+        Self::Cell
+    }
+
     pub fn candidate(candidate: Value<Base>) -> Self {
         Self::Candidates(Candidates::with_single(candidate))
     }
@@ -54,22 +100,70 @@ impl<Base: SudokuBase> Reason<Base> {
         Self::Candidates(candidates)
     }
 
+    pub fn fin(fin: Value<Base>) -> Self {
+        Self::fins(Candidates::with_single(fin))
+    }
+
+    pub fn fins(fins: Candidates<Base>) -> Self {
+        Self::Fin {
+            candidates: Candidates::new(),
+            fins,
+        }
+    }
+
+    pub fn color_1(candidate: Value<Base>) -> Self {
+        Self::colors(Candidates::with_single(candidate), Candidates::new())
+    }
+
+    pub fn color_2(candidate: Value<Base>) -> Self {
+        Self::colors(Candidates::new(), Candidates::with_single(candidate))
+    }
+
+    pub fn colors(color_1: Candidates<Base>, color_2: Candidates<Base>) -> Self {
+        Self::Colors { color_1, color_2 }
+    }
+
+    /// All candidates referenced by this reason.
+    pub fn all_candidates(&self) -> Candidates<Base> {
+        match *self {
+            Reason::Cell => Candidates::new(),
+            Reason::Candidates(candidates) => candidates,
+            Reason::Fin { candidates, fins } => candidates.union(fins),
+            Reason::Colors { color_1, color_2 } => color_1.union(color_2),
+        }
+    }
+
     pub fn validate(&self, cell: &Cell<Base>) -> Result<()> {
         (|| {
+            if *self == Reason::Cell {
+                return Ok(());
+            }
             match cell.state() {
                 CellState::Value(value) | CellState::FixedValue(value) => {
                     bail!("unexpected cell with value {value}")
                 }
-                CellState::Candidates(existing_candidates) => match *self {
-                    Reason::Candidates(candidates) => {
-                        ensure!(!candidates.is_empty(), "candidates must not be empty");
-                        let unexpected_candidates = candidates.without(*existing_candidates);
-                        ensure!(
-                            unexpected_candidates.is_empty(),
-                            "unexpected candidates {unexpected_candidates}"
-                        );
+                CellState::Candidates(existing_candidates) => {
+                    match *self {
+                        Reason::Cell => {}
+                        Reason::Candidates(candidates) => {
+                            ensure!(!candidates.is_empty(), "candidates must not be empty");
+                        }
+                        Reason::Fin { fins, .. } => {
+                            ensure!(!fins.is_empty(), "fins must not be empty");
+                        }
+                        Reason::Colors { color_1, color_2 } => {
+                            ensure!(
+                                !color_1.union(color_2).is_empty(),
+                                "colors must not be empty"
+                            );
+                        }
                     }
-                },
+                    let unexpected_candidates = self.all_candidates().without(*existing_candidates);
+                    ensure!(
+                        unexpected_candidates.is_empty(),
+                        "unexpected candidates {unexpected_candidates}"
+                    );
+                }
             }
             Ok(())
         })()
@@ -80,9 +174,52 @@ impl<Base: SudokuBase> Reason<Base> {
 impl<Base: SudokuBase> Merge for Reason<Base> {
     fn merge(self, other: Self) -> Result<Self> {
         Ok(match (self, other) {
+            // A candidate reason already highlights its cell.
+            (Reason::Cell, reason) | (reason, Reason::Cell) => reason,
             (Reason::Candidates(candidates), Reason::Candidates(other_candidates)) => {
                 Reason::Candidates(candidates.union(other_candidates))
             }
+            (
+                Reason::Candidates(candidates),
+                Reason::Fin {
+                    candidates: other_candidates,
+                    fins,
+                },
+            )
+            | (
+                Reason::Fin {
+                    candidates: other_candidates,
+                    fins,
+                },
+                Reason::Candidates(candidates),
+            ) => Reason::Fin {
+                candidates: candidates.union(other_candidates),
+                fins,
+            },
+            (
+                Reason::Fin { candidates, fins },
+                Reason::Fin {
+                    candidates: other_candidates,
+                    fins: other_fins,
+                },
+            ) => Reason::Fin {
+                candidates: candidates.union(other_candidates),
+                fins: fins.union(other_fins),
+            },
+            (
+                Reason::Colors { color_1, color_2 },
+                Reason::Colors {
+                    color_1: other_color_1,
+                    color_2: other_color_2,
+                },
+            ) => Reason::Colors {
+                color_1: color_1.union(other_color_1),
+                color_2: color_2.union(other_color_2),
+            },
+            (reason @ Reason::Colors { .. }, other_reason)
+            | (other_reason, reason @ Reason::Colors { .. }) => {
+                bail!("Unable to merge color reason {reason} with {other_reason}")
+            }
         })
     }
 }
@@ -125,4 +262,117 @@ mod tests {
             assert_eq!(merged_reason_2_1, expected_reason);
         }
     }
+
+    #[test]
+    fn test_merge_fin() {
+        type Base = Base2;
+        let value_1: Value<Base> = 1.try_into().unwrap();
+        let value_2: Value<Base> = 2.try_into().unwrap();
+        let value_3: Value<Base> = 3.try_into().unwrap();
+
+        let test_cases = vec![
+            (
+                Reason::candidate(value_1),
+                Reason::fin(value_2),
+                Reason::Fin {
+                    candidates: Candidates::with_single(value_1),
+                    fins: Candidates::with_single(value_2),
+                },
+            ),
+            (
+                Reason::fin(value_1),
+                Reason::fin(value_2),
+                Reason::fins([value_1, value_2].into_iter().collect()),
+            ),
+            (
+                Reason::Fin {
+                    candidates: Candidates::with_single(value_1),
+                    fins: Candidates::with_single(value_2),
+                },
+                Reason::candidate(value_3),
+                Reason::Fin {
+                    candidates: [value_1, value_3].into_iter().collect(),
+                    fins: Candidates::with_single(value_2),
+                },
+            ),
+        ];
+
+        for (reason_1, reason_2, expected_reason) in test_cases {
+            let merged_reason_1_2 = reason_1.merge(reason_2).unwrap();
+            let merged_reason_2_1 = reason_2.merge(reason_1).unwrap();
+            assert_eq!(merged_reason_1_2, expected_reason);
+            assert_eq!(merged_reason_2_1, expected_reason);
+        }
+    }
+
+    #[test]
+    fn test_merge_colors() {
+        type Base = Base2;
+        let value_1: Value<Base> = 1.try_into().unwrap();
+        let value_2: Value<Base> = 2.try_into().unwrap();
+
+        let test_cases = vec![
+            (
+                Reason::color_1(value_1),
+                Reason::color_2(value_2),
+                Reason::colors(
+                    Candidates::with_single(value_1),
+                    Candidates::with_single(value_2),
+                ),
+            ),
+            (
+                Reason::color_1(value_1),
+                Reason::color_1(value_2),
+                Reason::colors([value_1, value_2].into_iter().collect(), Candidates::new()),
+            ),
+        ];
+
+        for (reason_1, reason_2, expected_reason) in test_cases {
+            let merged_reason_1_2 = reason_1.merge(reason_2).unwrap();
+            let merged_reason_2_1 = reason_2.merge(reason_1).unwrap();
+            assert_eq!(merged_reason_1_2, expected_reason);
+            assert_eq!(merged_reason_2_1, expected_reason);
+        }
+
+        Reason::color_1(value_1)
+            .merge(Reason::candidate(value_2))
+            .unwrap_err();
+        Reason::fin(value_1)
+            .merge(Reason::color_2(value_2))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_merge_cell() {
+        type Base = Base2;
+        let value_1: Value<Base> = 1.try_into().unwrap();
+
+        for reason in [
+            Reason::cell(),
+            Reason::candidate(value_1),
+            Reason::fin(value_1),
+            Reason::color_1(value_1),
+        ] {
+            assert_eq!(Reason::cell().merge(reason).unwrap(), reason);
+            assert_eq!(reason.merge(Reason::cell()).unwrap(), reason);
+        }
+    }
+
+    #[test]
+    fn test_validate_cell() {
+        type Base = Base2;
+        let value_1: Value<Base> = 1.try_into().unwrap();
+
+        for cell in [
+            Cell::new(),
+            Cell::with_candidates(Candidates::with_single(value_1)),
+            Cell::with_value(value_1, false),
+            Cell::with_value(value_1, true),
+        ] {
+            Reason::cell().validate(&cell).unwrap();
+        }
+        Reason::candidate(value_1)
+            .validate(&Cell::with_value(value_1, false))
+            .unwrap_err();
+    }
 }
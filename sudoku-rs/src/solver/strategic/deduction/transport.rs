@@ -2,8 +2,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::base::SudokuBase;
 use crate::cell::dynamic::{DynamicCandidates, DynamicValue};
-use crate::position::DynamicPosition;
-use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::error::{Error, Result};
+use crate::position::{DynamicPosition, GroupCoordinate};
+use crate::solver::strategic::deduction::{
+    Action, ChainNode, Deduction, Deductions, Link, LinkType, Reason,
+};
 
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -24,6 +27,15 @@ impl<Base: SudokuBase> From<Deductions<Base>> for TransportDeductions {
 pub struct TransportDeduction {
     pub reasons: Vec<PositionedTransportReason>,
     pub actions: Vec<PositionedTransportAction>,
+    #[cfg_attr(feature = "wasm", ts(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<Vec<TransportChainNode>>,
+    #[cfg_attr(feature = "wasm", ts(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<TransportGroup>>,
+    #[cfg_attr(feature = "wasm", ts(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<TransportLink>>,
 }
 
 impl<Base: SudokuBase> From<Deduction<Base>> for TransportDeduction {
@@ -45,6 +57,95 @@ impl<Base: SudokuBase> From<Deduction<Base>> for TransportDeduction {
                     action: action.into(),
                 })
                 .collect(),
+            chain: deduction
+                .chain
+                .map(|chain| chain.nodes().iter().copied().map(Into::into).collect()),
+            groups: (!deduction.groups.is_empty())
+                .then(|| deduction.groups.into_iter().map(Into::into).collect()),
+            links: (!deduction.links.is_empty())
+                .then(|| deduction.links.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransportChainNode {
+    pub position: DynamicPosition,
+    pub candidate: DynamicValue,
+}
+
+impl<Base: SudokuBase> From<ChainNode<Base>> for TransportChainNode {
+    fn from(chain_node: ChainNode<Base>) -> Self {
+        Self {
+            position: chain_node.position.into(),
+            candidate: chain_node.candidate.into(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransportGroup {
+    Row(u8),
+    Column(u8),
+    Block(u8),
+}
+
+impl<Base: SudokuBase> From<GroupCoordinate<Base>> for TransportGroup {
+    fn from(group: GroupCoordinate<Base>) -> Self {
+        match group {
+            GroupCoordinate::Row(row) => Self::Row(row.get()),
+            GroupCoordinate::Column(column) => Self::Column(column.get()),
+            GroupCoordinate::Block(block) => Self::Block(block.get()),
+        }
+    }
+}
+
+impl<Base: SudokuBase> TryFrom<TransportGroup> for GroupCoordinate<Base> {
+    type Error = Error;
+
+    fn try_from(transport_group: TransportGroup) -> Result<Self> {
+        Ok(match transport_group {
+            TransportGroup::Row(row) => Self::Row(row.try_into()?),
+            TransportGroup::Column(column) => Self::Column(column.try_into()?),
+            TransportGroup::Block(block) => Self::Block(block.try_into()?),
+        })
+    }
+}
+
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransportLink {
+    pub from: TransportChainNode,
+    pub to: TransportChainNode,
+    pub kind: TransportLinkType,
+}
+
+impl<Base: SudokuBase> From<Link<Base>> for TransportLink {
+    fn from(link: Link<Base>) -> Self {
+        Self {
+            from: link.from.into(),
+            to: link.to.into(),
+            kind: link.kind.into(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransportLinkType {
+    Strong,
+    Weak,
+}
+
+impl From<LinkType> for TransportLinkType {
+    fn from(kind: LinkType) -> Self {
+        match kind {
+            LinkType::Strong => Self::Strong,
+            LinkType::Weak => Self::Weak,
         }
     }
 }
@@ -61,13 +162,34 @@ pub struct PositionedTransportReason {
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TransportReason {
+    /// Serialized as an empty object, which can be flattened next to the position.
+    #[allow(clippy::empty_enum_variants_with_brackets)]
+    Cell {},
     Candidates(DynamicCandidates),
+    Fin {
+        candidates: DynamicCandidates,
+        fins: DynamicCandidates,
+    },
+    Colors {
+        color_1: DynamicCandidates,
+        color_2: DynamicCandidates,
+    },
 }
 
 impl<Base: SudokuBase> From<Reason<Base>> for TransportReason {
     fn from(reason: Reason<Base>) -> Self {
-        let Reason::Candidates(candidates) = reason;
-        Self::Candidates(candidates.into())
+        match reason {
+            Reason::Cell => Self::Cell {},
+            Reason::Candidates(candidates) => Self::Candidates(candidates.into()),
+            Reason::Fin { candidates, fins } => Self::Fin {
+                candidates: candidates.into(),
+                fins: fins.into(),
+            },
+            Reason::Colors { color_1, color_2 } => Self::Colors {
+                color_1: color_1.into(),
+                color_2: color_2.into(),
+            },
+        }
     }
 }
 
@@ -108,6 +230,9 @@ mod tests {
                 TransportDeduction {
                     reasons: vec![],
                     actions: vec![],
+                    chain: None,
+                    groups: None,
+                    links: None,
                 },
                 TransportDeduction {
                     reasons: vec![PositionedTransportReason {
@@ -118,6 +243,9 @@ mod tests {
                         position: (0, 1).into(),
                         action: TransportAction::DeleteCandidates(vec![].into()),
                     }],
+                    chain: None,
+                    groups: None,
+                    links: None,
                 },
                 TransportDeduction {
                     reasons: vec![
@@ -129,6 +257,24 @@ mod tests {
                             position: (1, 1).into(),
                             reason: TransportReason::Candidates(vec![4].into()),
                         },
+                        PositionedTransportReason {
+                            position: (3, 0).into(),
+                            reason: TransportReason::Fin {
+                                candidates: vec![2].into(),
+                                fins: vec![3].into(),
+                            },
+                        },
+                        PositionedTransportReason {
+                            position: (3, 1).into(),
+                            reason: TransportReason::Colors {
+                                color_1: vec![1].into(),
+                                color_2: vec![4].into(),
+                            },
+                        },
+                        PositionedTransportReason {
+                            position: (3, 2).into(),
+                            reason: TransportReason::Cell {},
+                        },
                     ],
                     actions: vec![
                         PositionedTransportAction {
@@ -144,6 +290,45 @@ mod tests {
                             action: TransportAction::DeleteCandidates(vec![1, 2, 3].into()),
                         },
                     ],
+                    chain: Some(vec![
+                        TransportChainNode {
+                            position: (0, 0).into(),
+                            candidate: 1.into(),
+                        },
+                        TransportChainNode {
+                            position: (0, 3).into(),
+                            candidate: 1.into(),
+                        },
+                    ]),
+                    groups: Some(vec![
+                        TransportGroup::Row(0),
+                        TransportGroup::Column(3),
+                        TransportGroup::Block(1),
+                    ]),
+                    links: Some(vec![
+                        TransportLink {
+                            from: TransportChainNode {
+                                position: (0, 0).into(),
+                                candidate: 1.into(),
+                            },
+                            to: TransportChainNode {
+                                position: (0, 3).into(),
+                                candidate: 1.into(),
+                            },
+                            kind: TransportLinkType::Strong,
+                        },
+                        TransportLink {
+                            from: TransportChainNode {
+                                position: (0, 3).into(),
+                                candidate: 1.into(),
+                            },
+                            to: TransportChainNode {
+                                position: (0, 3).into(),
+                                candidate: 2.into(),
+                            },
+                            kind: TransportLinkType::Weak,
+                        },
+                    ]),
                 },
             ],
         }
@@ -158,4 +343,31 @@ mod tests {
 
         assert_eq!(transport_deductions, sample_transport_deductions);
     }
+
+    #[test]
+    fn test_transport_reason_json() {
+        let reasons = [
+            (
+                TransportReason::Cell {},
+                r#"{"position":{"row":0,"column":1},"cell":{}}"#,
+            ),
+            (
+                TransportReason::Candidates(vec![1].into()),
+                r#"{"position":{"row":0,"column":1},"candidates":[1]}"#,
+            ),
+        ];
+
+        for (reason, expected_json) in reasons {
+            let positioned_reason = PositionedTransportReason {
+                position: (0, 1).into(),
+                reason,
+            };
+            let json = serde_json::to_string(&positioned_reason).unwrap();
+            assert_eq!(json, expected_json);
+            assert_eq!(
+                serde_json::from_str::<PositionedTransportReason>(&json).unwrap(),
+                positioned_reason
+            );
+        }
+    }
 }
@@ -0,0 +1,508 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Write};
+
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::grid::Grid;
+use crate::position::{GroupCoordinate, Position};
+use crate::solver::strategic::SolveStep;
+use crate::solver::strategic::deduction::{Action, Deduction};
+use crate::solver::strategic::strategies::StrategyEnum;
+use crate::solver::strategic::strategies::map::StrategyMap;
+
+/// The name of a single application of each strategy, as used in the explanations.
+const TECHNIQUE_NAMES: StrategyMap<&str> = StrategyMap {
+    naked_singles: "Naked Single",
+    hidden_singles: "Hidden Single",
+    naked_pairs: "Naked Pair",
+    locked_sets: "Locked Set",
+    group_intersection_block_to_axis: "Pointing Candidates",
+    group_intersection_axis_to_block: "Box/Line Reduction",
+    group_intersection_both: "Intersection Removal",
+    skyscraper: "Skyscraper",
+    two_string_kite: "2-String Kite",
+    empty_rectangle: "Empty Rectangle",
+    w_wing: "W-Wing",
+    x_wing: "X-Wing",
+    xy_wing: "XY-Wing",
+    xyz_wing: "XYZ-Wing",
+    fish: "Fish",
+    simple_coloring: "Simple Coloring",
+    finned_fish: "Finned Fish",
+    multi_coloring: "Multi Coloring",
+    x_chain: "X-Chain",
+    xy_chain: "XY-Chain",
+    alternating_inference_chain: "Alternating Inference Chain",
+    sue_de_coq: "Sue de Coq",
+    als_xz: "ALS-XZ",
+    als_xy_wing: "ALS-XY-Wing",
+    death_blossom: "Death Blossom",
+    unique_rectangle: "Unique Rectangle",
+    bug_plus_one: "BUG+1",
+    forcing_chains: "Forcing Chain",
+    brute_force: "Brute Force",
+};
+
+/// Human-readable explanations
+impl<Base: SudokuBase> SolveStep<Base> {
+    /// A natural-language sentence for each deduction of this step, e.g.
+    /// `In row 4, 7 can only go in r4c2 (Hidden Single).`
+    ///
+    /// `grid` must be the grid on which the strategy was executed, i.e. before applying the deductions.
+    /// Positions are formatted in the `RxCy` notation with 1-based rows and columns,
+    /// which is common for sudoku resources.
+    pub fn explanations(&self, grid: &Grid<Base>) -> Vec<String> {
+        self.deductions
+            .iter()
+            .map(|deduction| explain_deduction(self.strategy, deduction, grid))
+            .collect()
+    }
+}
+
+/// A natural-language sentence, which explains why `strategy` made `deduction` on `grid`.
+pub fn explain_deduction<Base: SudokuBase>(
+    strategy: StrategyEnum,
+    deduction: &Deduction<Base>,
+    grid: &Grid<Base>,
+) -> String {
+    let explanation = match strategy {
+        StrategyEnum::NakedSingles(_) => explain_naked_single(deduction),
+        StrategyEnum::HiddenSingles(_) => explain_hidden_single(deduction, grid),
+        StrategyEnum::NakedPairs(_) | StrategyEnum::LockedSets(_) => {
+            explain_locked_set(deduction, grid)
+        }
+        StrategyEnum::GroupIntersectionBlockToAxis(_)
+        | StrategyEnum::GroupIntersectionAxisToBlock(_)
+        | StrategyEnum::GroupIntersectionBoth(_) => explain_group_intersection(deduction),
+        _ => None,
+    };
+
+    let (sentence, technique_name) =
+        explanation.unwrap_or_else(|| (explain_generic(deduction), None));
+    let technique_name =
+        technique_name.unwrap_or_else(|| (*TECHNIQUE_NAMES.get(strategy)).to_owned());
+
+    format!("{sentence} ({technique_name}).")
+}
+
+/// A sentence and optionally a more specific technique name than the one of the strategy.
+type Explanation = Option<(String, Option<String>)>;
+
+fn explain_naked_single<Base: SudokuBase>(deduction: &Deduction<Base>) -> Explanation {
+    let (pos, value) = single_set_value(deduction)?;
+
+    Some((format!("{} can only contain {value}", Rc(pos)), None))
+}
+
+fn explain_hidden_single<Base: SudokuBase>(
+    deduction: &Deduction<Base>,
+    grid: &Grid<Base>,
+) -> Explanation {
+    let (pos, value) = single_set_value(deduction)?;
+
+    let sentence = match GroupCoordinate::all_containing(pos)
+        .into_iter()
+        .find(|group| {
+            group
+                .positions()
+                .filter(|&other_pos| has_candidate(grid, other_pos, value))
+                .eq([pos])
+        }) {
+        Some(group) => format!("In {}, {value} can only go in {}", Group(group), Rc(pos)),
+        None => format!("{value} can only go in {}", Rc(pos)),
+    };
+
+    Some((sentence, None))
+}
+
+fn explain_locked_set<Base: SudokuBase>(
+    deduction: &Deduction<Base>,
+    grid: &Grid<Base>,
+) -> Explanation {
+    let set_positions = deduction.reasons.iter().map(|(pos, _)| pos).collect_vec();
+    let set_values = deduction
+        .reasons
+        .iter()
+        .map(|(_, reason)| reason.all_candidates())
+        .fold(Candidates::new(), Candidates::union);
+    let group = GroupCoordinate::containing_all(
+        set_positions
+            .iter()
+            .copied()
+            .chain(deduction.actions.iter().map(|(pos, _)| pos)),
+    )?;
+
+    let is_naked = set_positions.iter().all(|&pos| {
+        grid[pos]
+            .candidates()
+            .is_some_and(|candidates| candidates.without(set_values).is_empty())
+    });
+    let set_name = set_name(set_positions.len());
+
+    Some(if is_naked {
+        (
+            format!(
+                "In {}, {} can only contain {}, so these values can be removed from {}",
+                Group(group),
+                list(set_positions.iter().map(|&pos| Rc(pos))),
+                list(set_values),
+                list(deduction.actions.iter().map(|(pos, _)| Rc(pos)))
+            ),
+            Some(format!("Naked {set_name}")),
+        )
+    } else {
+        (
+            format!(
+                "In {}, {} can only go in {}, so all other candidates can be removed from these cells",
+                Group(group),
+                list(set_values),
+                list(set_positions.iter().map(|&pos| Rc(pos))),
+            ),
+            Some(format!("Hidden {set_name}")),
+        )
+    })
+}
+
+fn explain_group_intersection<Base: SudokuBase>(deduction: &Deduction<Base>) -> Explanation {
+    let &reason_group = deduction.groups.iter().exactly_one().ok()?;
+    let candidate = deduction
+        .actions
+        .iter()
+        .map(|(_, action)| match action {
+            Action::DeleteCandidates(candidates) => candidates.to_single(),
+            Action::SetValue(_) => None,
+        })
+        .all_equal_value()
+        .ok()??;
+    let reason_positions = deduction.reasons.iter().map(|(pos, _)| pos).collect_vec();
+    let eliminating_group = GroupCoordinate::containing_all(
+        reason_positions
+            .iter()
+            .copied()
+            .chain(deduction.actions.iter().map(|(pos, _)| pos)),
+    )?;
+
+    let technique_name = if let GroupCoordinate::Block(_) = reason_group {
+        format!("Pointing {}", set_name(reason_positions.len()))
+    } else {
+        "Box/Line Reduction".to_owned()
+    };
+
+    Some((
+        format!(
+            "In {}, {candidate} can only go in {}, so {candidate} can be removed from {}",
+            Group(reason_group),
+            Group(eliminating_group),
+            list(deduction.actions.iter().map(|(pos, _)| Rc(pos))),
+        ),
+        Some(technique_name),
+    ))
+}
+
+/// Describes the actions and the cells involved, without knowledge about the strategy.
+fn explain_generic<Base: SudokuBase>(deduction: &Deduction<Base>) -> String {
+    let mut set_values = vec![];
+    let mut candidate_to_positions: BTreeMap<Value<Base>, Vec<Position<Base>>> = BTreeMap::new();
+    for (pos, action) in &deduction.actions {
+        match *action {
+            Action::SetValue(value) => set_values.push(format!("{} must be {value}", Rc(pos))),
+            Action::DeleteCandidates(candidates) => {
+                for candidate in candidates {
+                    candidate_to_positions
+                        .entry(candidate)
+                        .or_default()
+                        .push(pos);
+                }
+            }
+        }
+    }
+    let effects = set_values
+        .into_iter()
+        .chain(
+            candidate_to_positions
+                .into_iter()
+                .map(|(candidate, positions)| {
+                    format!(
+                        "{candidate} can be removed from {}",
+                        list(positions.into_iter().map(Rc))
+                    )
+                }),
+        );
+
+    let mut sentence = list(effects);
+    if !deduction.reasons.is_empty() {
+        write!(
+            sentence,
+            " because of {}",
+            list(deduction.reasons.iter().map(|(pos, _)| Rc(pos)))
+        )
+        .unwrap();
+    }
+    if !deduction.groups.is_empty() {
+        write!(
+            sentence,
+            " in {}",
+            list(deduction.groups.iter().copied().map(Group))
+        )
+        .unwrap();
+    }
+    if let Some(chain) = &deduction.chain {
+        let (start, _) = chain.ends();
+        write!(
+            sentence,
+            ", following the chain {}({})",
+            Rc(start.position),
+            start.candidate
+        )
+        .unwrap();
+        for link in chain.links() {
+            write!(
+                sentence,
+                " {} {}({})",
+                link.kind,
+                Rc(link.to.position),
+                link.to.candidate
+            )
+            .unwrap();
+        }
+    }
+    sentence
+}
+
+fn single_set_value<Base: SudokuBase>(
+    deduction: &Deduction<Base>,
+) -> Option<(Position<Base>, Value<Base>)> {
+    let (pos, action) = deduction.actions.iter().exactly_one().ok()?;
+    match *action {
+        Action::SetValue(value) => Some((pos, value)),
+        Action::DeleteCandidates(_) => None,
+    }
+}
+
+fn has_candidate<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    pos: Position<Base>,
+    value: Value<Base>,
+) -> bool {
+    grid[pos]
+        .candidates()
+        .is_some_and(|candidates| candidates.has(value))
+}
+
+/// The name of a set of cells with the given size, e.g. `Pair`.
+fn set_name(size: usize) -> String {
+    match size {
+        2 => "Pair".to_owned(),
+        3 => "Triple".to_owned(),
+        4 => "Quad".to_owned(),
+        size => format!("Set of {size}"),
+    }
+}
+
+/// Joins the items in a natural-language list, e.g. `1, 2 and 3`.
+fn list<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    let mut items = items.into_iter().map(|item| item.to_string()).collect_vec();
+    match items.len() {
+        0 => String::new(),
+        1 => items.remove(0),
+        _ => {
+            let last = items.pop().unwrap();
+            format!("{} and {last}", items.join(", "))
+        }
+    }
+}
+
+/// Formats a position in the `RxCy` notation with 1-based coordinates.
+struct Rc<Base: SudokuBase>(Position<Base>);
+
+impl<Base: SudokuBase> Display for Rc<Base> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (row, column) = self.0.to_row_and_column();
+        write!(f, "r{}c{}", row.get() + 1, column.get() + 1)
+    }
+}
+
+/// Formats a group with a 1-based coordinate.
+struct Group<Base: SudokuBase>(GroupCoordinate<Base>);
+
+impl<Base: SudokuBase> Display for Group<Base> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (group_name, coordinate) = match self.0 {
+            GroupCoordinate::Row(row) => ("row", row),
+            GroupCoordinate::Column(column) => ("column", column),
+            GroupCoordinate::Block(block) => ("block", block),
+        };
+        write!(f, "{group_name} {}", coordinate.get() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+    use crate::position::Coordinate;
+    use crate::solver::strategic::Solver;
+    use crate::solver::strategic::deduction::{Deductions, Reason};
+    use crate::solver::strategic::strategies::selection::StrategySet;
+    use crate::solver::strategic::strategies::{
+        GroupIntersectionBlockToAxis, HiddenSingles, LockedSets, NakedSingles, Strategy, XChain,
+    };
+    use crate::test_util::{for_base_grid_samples, test_max_base3};
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    fn explanations(
+        strategy: impl Into<StrategyEnum>,
+        deductions: Deductions<Base3>,
+        grid: &Grid<Base3>,
+    ) -> Vec<String> {
+        SolveStep {
+            strategy: strategy.into(),
+            deductions,
+        }
+        .explanations(grid)
+    }
+
+    #[test]
+    fn test_naked_single() {
+        let mut grid = Grid::<Base3>::new();
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[5]));
+
+        let deductions = NakedSingles.execute(&grid).unwrap();
+
+        assert_eq!(
+            explanations(NakedSingles, deductions, &grid),
+            vec!["r1c1 can only contain 5 (Naked Single)."]
+        );
+    }
+
+    #[test]
+    fn test_hidden_single() {
+        let mut grid = Grid::<Base3>::new();
+        grid.set_all_direct_candidates();
+        for column in 0..9 {
+            if column != 1 {
+                grid[pos(3, column)].delete_candidate(7.try_into().unwrap());
+            }
+        }
+
+        let deductions = HiddenSingles.execute(&grid).unwrap();
+
+        assert_eq!(
+            explanations(HiddenSingles, deductions, &grid),
+            vec!["In row 4, 7 can only go in r4c2 (Hidden Single)."]
+        );
+    }
+
+    #[test]
+    fn test_locked_set() {
+        let mut grid = Grid::<Base3>::new();
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 1)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+
+        let deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [(pos(0, 4), Action::DeleteCandidates(candidates(&[1, 2])))],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 1), Reason::candidates(candidates(&[1, 2]))),
+            ],
+        )
+        .unwrap()]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            explanations(LockedSets::default(), deductions, &grid),
+            vec![
+                "In row 1, r1c1 and r1c2 can only contain 1 and 2, \
+                so these values can be removed from r1c5 (Naked Pair)."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pointing_pair() {
+        let grid = Grid::<Base3>::new();
+
+        let deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [
+                (pos(0, 3), Action::delete_candidate(5.try_into().unwrap())),
+                (pos(0, 6), Action::delete_candidate(5.try_into().unwrap())),
+            ],
+            [
+                (pos(0, 0), Reason::candidate(5.try_into().unwrap())),
+                (pos(0, 2), Reason::candidate(5.try_into().unwrap())),
+            ],
+        )
+        .unwrap()
+        .with_group(GroupCoordinate::Block(Coordinate::new(0).unwrap()))]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            explanations(GroupIntersectionBlockToAxis, deductions, &grid),
+            vec![
+                "In block 1, 5 can only go in row 1, \
+                so 5 can be removed from r1c4 and r1c7 (Pointing Pair)."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generic() {
+        let grid = Grid::<Base3>::new();
+
+        let deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [
+                (pos(0, 0), Action::delete_candidate(3.try_into().unwrap())),
+                (pos(8, 8), Action::SetValue(4.try_into().unwrap())),
+            ],
+            [(pos(4, 4), Reason::candidate(3.try_into().unwrap()))],
+        )
+        .unwrap()]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            explanations(XChain::default(), deductions, &grid),
+            vec!["r9c9 must be 4 and 3 can be removed from r1c1 because of r5c5 (X-Chain)."]
+        );
+    }
+
+    mod snapshots {
+        use super::*;
+
+        test_max_base3!({
+            for_base_grid_samples!(|grid, name| {
+                grid.set_all_direct_candidates();
+
+                let mut explanations = vec![];
+                while let Some(solve_step) = Solver::with_strategies(
+                    &mut grid,
+                    StrategySet::default_solver_strategies_no_brute_force(),
+                )
+                .try_strategies()
+                .unwrap()
+                {
+                    explanations.extend(solve_step.explanations(&grid));
+                    solve_step.deductions.apply(&mut grid).unwrap();
+                }
+
+                insta::assert_yaml_snapshot!(name, explanations);
+            });
+        });
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::SudokuBase;
+use crate::position::{GroupCoordinate, Position};
+use crate::solver::strategic::SolveStep;
+use crate::solver::strategic::strategies::StrategyEnum;
+pub use dynamic::DynamicHint;
+
+/// How much of a solve step is revealed to the player.
+///
+/// Each level reveals the information of the previous levels and more.
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HintLevel {
+    /// Level 1: names only the technique.
+    Technique,
+    /// Level 2: highlights the rows, columns and blocks involved.
+    Region,
+    /// Level 3: shows the full deductions.
+    Deduction,
+    /// Level 4: applies the deductions to the grid.
+    Apply,
+}
+
+/// The partial information of a solve step, which is revealed by a [`HintLevel`].
+#[derive(Debug, Clone)]
+pub enum Hint<Base: SudokuBase> {
+    Technique {
+        strategy: StrategyEnum,
+    },
+    Region {
+        strategy: StrategyEnum,
+        groups: BTreeSet<GroupCoordinate<Base>>,
+    },
+    Deduction(SolveStep<Base>),
+    Apply(SolveStep<Base>),
+}
+
+impl<Base: SudokuBase> Hint<Base> {
+    pub fn level(&self) -> HintLevel {
+        match self {
+            Hint::Technique { .. } => HintLevel::Technique,
+            Hint::Region { .. } => HintLevel::Region,
+            Hint::Deduction(_) => HintLevel::Deduction,
+            Hint::Apply(_) => HintLevel::Apply,
+        }
+    }
+
+    pub fn strategy(&self) -> StrategyEnum {
+        match self {
+            Hint::Technique { strategy } | Hint::Region { strategy, .. } => *strategy,
+            Hint::Deduction(solve_step) | Hint::Apply(solve_step) => solve_step.strategy,
+        }
+    }
+}
+
+/// Hints
+impl<Base: SudokuBase> SolveStep<Base> {
+    /// Reduces this step to the information revealed by `hint_level`.
+    ///
+    /// The caller is responsible for applying the deductions for [`HintLevel::Apply`].
+    pub fn hint(self, hint_level: HintLevel) -> Hint<Base> {
+        match hint_level {
+            HintLevel::Technique => Hint::Technique {
+                strategy: self.strategy,
+            },
+            HintLevel::Region => Hint::Region {
+                strategy: self.strategy,
+                groups: self.regions(),
+            },
+            HintLevel::Deduction => Hint::Deduction(self),
+            HintLevel::Apply => Hint::Apply(self),
+        }
+    }
+
+    /// The rows, columns and blocks, in which the deductions of this step take place.
+    ///
+    /// For each deduction, these are the groups which were a reason for the deduction
+    /// and the group containing all of its cells.
+    /// If neither exists, e.g. for chains, the blocks of its cells are used instead.
+    pub fn regions(&self) -> BTreeSet<GroupCoordinate<Base>> {
+        self.deductions
+            .iter()
+            .flat_map(|deduction| {
+                let positions = deduction
+                    .actions
+                    .iter()
+                    .map(|(pos, _)| pos)
+                    .chain(deduction.reasons.iter().map(|(pos, _)| pos))
+                    .collect::<BTreeSet<Position<Base>>>();
+
+                let mut groups = deduction.groups.clone();
+                groups.extend(GroupCoordinate::containing_all(positions.iter().copied()));
+                if groups.is_empty() {
+                    groups.extend(
+                        positions
+                            .iter()
+                            .map(|pos| GroupCoordinate::Block(pos.to_block())),
+                    );
+                }
+                groups
+            })
+            .collect()
+    }
+}
+
+mod dynamic {
+    use crate::solver::strategic::DynamicSolveStep;
+    use crate::solver::strategic::deduction::transport::TransportGroup;
+
+    use super::*;
+
+    #[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase", tag = "kind")]
+    pub enum DynamicHint {
+        Technique {
+            strategy: StrategyEnum,
+        },
+        Region {
+            strategy: StrategyEnum,
+            groups: Vec<TransportGroup>,
+        },
+        Deduction {
+            step: DynamicSolveStep,
+        },
+        Apply {
+            step: DynamicSolveStep,
+        },
+    }
+
+    impl<Base: SudokuBase> From<Hint<Base>> for DynamicHint {
+        fn from(hint: Hint<Base>) -> Self {
+            match hint {
+                Hint::Technique { strategy } => Self::Technique { strategy },
+                Hint::Region { strategy, groups } => Self::Region {
+                    strategy,
+                    groups: groups.into_iter().map(Into::into).collect(),
+                },
+                Hint::Deduction(solve_step) => Self::Deduction {
+                    step: solve_step.into(),
+                },
+                Hint::Apply(solve_step) => Self::Apply {
+                    step: solve_step.into(),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::position::Coordinate;
+    use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+    use crate::solver::strategic::strategies::{GroupIntersectionBlockToAxis, XChain};
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    fn coordinate(coordinate: u8) -> Coordinate<Base3> {
+        Coordinate::new(coordinate).unwrap()
+    }
+
+    fn solve_step(
+        strategy: impl Into<StrategyEnum>,
+        deduction: Deduction<Base3>,
+    ) -> SolveStep<Base3> {
+        SolveStep {
+            strategy: strategy.into(),
+            deductions: [deduction].into_iter().collect::<Deductions<Base3>>(),
+        }
+    }
+
+    fn pointing_step() -> SolveStep<Base3> {
+        solve_step(
+            GroupIntersectionBlockToAxis,
+            Deduction::try_from_iters(
+                [(pos(0, 3), Action::delete_candidate(5.try_into().unwrap()))],
+                [
+                    (pos(0, 0), Reason::candidate(5.try_into().unwrap())),
+                    (pos(0, 2), Reason::candidate(5.try_into().unwrap())),
+                ],
+            )
+            .unwrap()
+            .with_group(GroupCoordinate::Block(coordinate(0))),
+        )
+    }
+
+    #[test]
+    fn test_hint() {
+        let solve_step = pointing_step();
+
+        for hint_level in [
+            HintLevel::Technique,
+            HintLevel::Region,
+            HintLevel::Deduction,
+            HintLevel::Apply,
+        ] {
+            let hint = solve_step.clone().hint(hint_level);
+            assert_eq!(hint.level(), hint_level);
+            assert_eq!(hint.strategy(), solve_step.strategy);
+
+            match hint {
+                Hint::Technique { .. } => {}
+                Hint::Region { groups, .. } => assert_eq!(groups, solve_step.regions()),
+                Hint::Deduction(hint_solve_step) | Hint::Apply(hint_solve_step) => {
+                    assert_eq!(hint_solve_step.deductions, solve_step.deductions);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_regions() {
+        assert_eq!(
+            pointing_step().regions(),
+            [
+                GroupCoordinate::Row(coordinate(0)),
+                GroupCoordinate::Block(coordinate(0)),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        let chain_step = solve_step(
+            XChain::default(),
+            Deduction::try_from_iters(
+                [(pos(0, 0), Action::delete_candidate(3.try_into().unwrap()))],
+                [
+                    (pos(4, 0), Reason::candidate(3.try_into().unwrap())),
+                    (pos(4, 4), Reason::candidate(3.try_into().unwrap())),
+                ],
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            chain_step.regions(),
+            [
+                GroupCoordinate::Block(coordinate(0)),
+                GroupCoordinate::Block(coordinate(3)),
+                GroupCoordinate::Block(coordinate(4)),
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use crate::grid::solution::Solution;
 use crate::solver::backtracking::CandidatesFilter;
+use crate::solver::control::SolverControl;
 use crate::solver::strategic::deduction::Deductions;
 use crate::solver::strategic::strategies::StrategyEnum;
+use crate::solver::{ControlledSolver, SolveOutcome};
 use crate::solver::{FallibleSolver, strategic::strategies::selection::StrategySet};
 use crate::{base::SudokuBase, solver::strategic::strategies::STRATEGY_SCORE_FIXED_POINT_SCALE};
 use crate::{
@@ -16,6 +19,8 @@ pub use step::{DynamicSolveStep, SolveStep};
 use strategies::{Strategy, StrategyScore};
 
 pub mod deduction;
+pub mod explanation;
+pub mod hint;
 pub mod strategies;
 
 mod step {
@@ -83,6 +88,7 @@ mod builder {
     #[derive(Debug)]
     pub struct SolverBuilder<Base: SudokuBase, GridMut: AsRef<Grid<Base>>> {
         grid: GridMut,
+        control: SolverControl,
         _base: PhantomData<Base>,
     }
 
@@ -90,6 +96,7 @@ mod builder {
         pub fn new(grid: GridMut) -> Self {
             Self {
                 grid,
+                control: SolverControl::default(),
                 _base: PhantomData,
             }
         }
@@ -102,9 +109,24 @@ mod builder {
             SolverBuilderWithStrategies {
                 grid: self.grid,
                 strategies,
+                control: self.control,
                 _base: PhantomData,
             }
         }
+
+        /// Abort the solver once a limit of `control` is reached. A step is an applied [`SolveStep`].
+        ///
+        /// The limits are checked before each step, a single strategy execution isn't interrupted.
+        /// `BruteForce` is the exception: its solver takes the steps remaining after the current step,
+        /// see [`SolverControl::remaining`].
+        /// If aborted, solving returns an [`AbortReason`](crate::solver::control::AbortReason) as error.
+        ///
+        /// See [`ControlledSolver`].
+        #[must_use]
+        pub fn control(mut self, control: SolverControl) -> Self {
+            self.control = control;
+            self
+        }
     }
 
     impl<Base: SudokuBase, GridMut: AsMut<Grid<Base>> + AsRef<Grid<Base>>>
@@ -121,8 +143,16 @@ mod builder {
         }
 
         pub fn build(self) -> Solver<Base, GridMut, StrategySet> {
-            let SolverBuilder { grid, _base } = self;
-            Solver::with_strategies(grid, StrategySet::default_solver_strategies())
+            let SolverBuilder {
+                grid,
+                control,
+                _base,
+            } = self;
+            Solver::with_strategies_and_control(
+                grid,
+                StrategySet::default_solver_strategies(),
+                control,
+            )
         }
     }
 
@@ -134,6 +164,7 @@ mod builder {
     > {
         grid: GridMut,
         strategies: Strategies,
+        control: SolverControl,
         _base: PhantomData<Base>,
     }
 
@@ -143,13 +174,21 @@ mod builder {
         Strategies: StrategySelection,
     > SolverBuilderWithStrategies<Base, GridMut, Strategies>
     {
+        /// See [`SolverBuilder::control`].
+        #[must_use]
+        pub fn control(mut self, control: SolverControl) -> Self {
+            self.control = control;
+            self
+        }
+
         pub fn build(self) -> Solver<Base, GridMut, Strategies> {
             let SolverBuilderWithStrategies {
                 grid,
                 strategies,
+                control,
                 _base,
             } = self;
-            Solver::with_strategies(grid, strategies)
+            Solver::with_strategies_and_control(grid, strategies, control)
         }
     }
 }
@@ -158,6 +197,10 @@ mod builder {
 pub struct Solver<Base: SudokuBase, GridRef: AsRef<Grid<Base>>, Strategies: StrategySelection> {
     grid: GridRef,
     strategies: Strategies,
+    /// Limits the solver, a step is an applied [`SolveStep`].
+    control: SolverControl,
+    /// The number of applied [`SolveStep`]s.
+    step_count: u64,
     _base: PhantomData<Base>,
 }
 
@@ -180,17 +223,33 @@ impl<
     Strategies: StrategySelection,
 > Solver<Base, GridMut, Strategies>
 {
-    pub fn with_strategies(mut grid: GridMut, strategies: Strategies) -> Self {
+    pub fn with_strategies(grid: GridMut, strategies: Strategies) -> Self {
+        Self::with_strategies_and_control(grid, strategies, SolverControl::default())
+    }
+
+    fn with_strategies_and_control(
+        mut grid: GridMut,
+        strategies: Strategies,
+        control: SolverControl,
+    ) -> Self {
         grid.as_mut()
             .set_all_direct_candidates_if_all_candidates_are_empty();
 
         Self {
             grid,
             strategies,
+            control,
+            step_count: 0,
             _base: PhantomData,
         }
     }
 
+    fn apply_solve_step(&mut self, solve_step: &SolveStep<Base>) -> Result<()> {
+        solve_step.deductions.apply(self.grid.as_mut())?;
+        self.step_count += 1;
+        Ok(())
+    }
+
     pub fn solve_path(&mut self) -> SolverPathIter<'_, Base, GridMut, Strategies> {
         SolverPathIter {
             solver: self,
@@ -217,11 +276,32 @@ impl<Base: SudokuBase, GridMut: AsRef<Grid<Base>>, Strategies: StrategySelection
         Ok(())
     }
 
+    /// Checks the limits of the control, before the next step is taken.
+    fn check_control(&self) -> Result<()> {
+        Ok(self.control.check(self.step_count + 1)?)
+    }
+
     fn execute_strategies_iter(&self) -> impl Iterator<Item = Result<SolveStep<Base>>> + '_ {
         self.strategies.iter_strategies().filter_map(|strategy| {
             trace!("Executing strategy: {strategy:?}");
-            Strategy::execute(strategy, self.grid.as_ref())
-                .map(|deductions| {
+            let executed = match strategy {
+                // Guessing can take arbitrarily long, therefore it respects the control.
+                // The guessing solver takes the steps remaining after this step.
+                StrategyEnum::BruteForce(brute_force) => brute_force
+                    .execute_controlled(
+                        self.grid.as_ref(),
+                        self.control.remaining(self.step_count + 1),
+                    )
+                    .map(|deductions| (strategy, deductions)),
+                // The step is scored by the depth, at which the forcing chains were found.
+                StrategyEnum::ForcingChains(forcing_chains) => forcing_chains
+                    .execute_with_depth(self.grid.as_ref())
+                    .map(|(forcing_chains, deductions)| (forcing_chains.into(), deductions)),
+                strategy => Strategy::execute(strategy, self.grid.as_ref())
+                    .map(|deductions| (strategy, deductions)),
+            };
+            executed
+                .map(|(strategy, deductions)| {
                     (!deductions.is_empty()).then(|| {
                         trace!(
                             "{strategy:?} made progress:\n{deductions}\n{}",
@@ -307,10 +387,24 @@ impl<
     }
 
     /// The number of times a strategy was applied to the grid.
-    pub fn application_count_single(self, strategy: StrategyEnum) -> Result<Option<StrategyScore>> {
-        Ok(self
-            .application_count()?
-            .map(|strategy_map| *strategy_map.get(strategy)))
+    ///
+    /// Applications of every strategy included in `strategy` are counted,
+    /// see `StrategyEnum::includes`.
+    pub fn application_count_single(
+        mut self,
+        strategy: StrategyEnum,
+    ) -> Result<Option<StrategyScore>> {
+        let mut count = 0;
+
+        self.try_for_each(|res| {
+            let solve_step = res?;
+            if strategy.includes(solve_step.strategy) {
+                count += 1;
+            }
+            Ok::<(), Error>(())
+        })?;
+
+        Ok(self.is_solved.then_some(count))
     }
 
     /// Number of deductions by each strategy used to solve the grid.
@@ -336,10 +430,27 @@ impl<
             .map(|strategy_map| strategy_map.into_values().into_iter().sum()))
     }
     /// Number of deductions by a single strategy used to solve the grid.
-    pub fn deduction_count_single(self, strategy: StrategyEnum) -> Result<Option<StrategyScore>> {
-        Ok(self
-            .deduction_count()?
-            .map(|strategy_map| *strategy_map.get(strategy)))
+    ///
+    /// Deductions of every strategy included in `strategy` are counted,
+    /// see `StrategyEnum::includes`.
+    pub fn deduction_count_single(
+        mut self,
+        strategy: StrategyEnum,
+    ) -> Result<Option<StrategyScore>> {
+        let mut count = 0;
+
+        self.try_for_each(|res| {
+            let SolveStep {
+                strategy: step_strategy,
+                deductions,
+            } = res?;
+            if strategy.includes(step_strategy) {
+                count += StrategyScore::try_from(deductions.count())?;
+            }
+            Ok::<(), Error>(())
+        })?;
+
+        Ok(self.is_solved.then_some(count))
     }
 }
 
@@ -355,13 +466,15 @@ impl<
         if self.solver.grid.as_ref().is_solved() {
             self.is_solved = true;
             None
+        } else if let Err(err) = self.solver.check_control() {
+            Some(Err(err))
         } else {
             Some(
                 self.solver
                     .try_strategies()
                     .transpose()?
                     .and_then(|solve_step| {
-                        solve_step.deductions.apply(self.solver.grid.as_mut())?;
+                        self.solver.apply_solve_step(&solve_step)?;
                         Ok(solve_step)
                     }),
             )
@@ -377,11 +490,25 @@ impl<
 {
     type Error = Error;
 
-    fn try_solve(&mut self) -> Result<Option<Grid<Base>>> {
+    fn try_solve(&mut self) -> Result<Option<Solution<Base>>> {
         let solve_path = &mut self.solve_path();
         solve_path.try_for_each(|res| res.map(|_| ()))?;
 
-        Ok(solve_path.is_solved.then(|| self.grid.as_ref().clone()))
+        solve_path
+            .is_solved
+            .then(|| Solution::try_from(self.grid.as_ref()))
+            .transpose()
+    }
+}
+
+impl<
+    Base: SudokuBase,
+    GridMut: AsMut<Grid<Base>> + AsRef<Grid<Base>>,
+    Strategies: StrategySelection,
+> ControlledSolver<Base> for Solver<Base, GridMut, Strategies>
+{
+    fn solve_controlled(&mut self) -> Result<SolveOutcome<Base>> {
+        SolveOutcome::from_fallible(self.try_solve())
     }
 }
 
@@ -403,6 +530,8 @@ impl<
 > SolverPathAllIter<'_, Base, GridMut, Strategies>
 {
     /// The average number of strategies available to make progress. Scaled by a factor of `STRATEGY_SCORE_FIXED_POINT_SCALE`.
+    ///
+    /// Expanded strategies count separately, e.g. `LockedSets` counts once for each set type and size making progress.
     pub fn average_options(mut self) -> Result<Option<StrategyScore>> {
         let (step_count, total_options) =
             self.try_fold::<_, _, Result<_>>((0u64, 0u64), |(acc_count, acc_options), res| {
@@ -431,12 +560,14 @@ impl<
         if self.solver.grid.as_ref().is_solved() {
             self.is_solved = true;
             None
+        } else if let Err(err) = self.solver.check_control() {
+            Some(Err(err))
         } else {
             self.solver
                 .try_all_strategies()
                 .and_then(|possible_solve_steps| {
                     if let Some(solve_step) = possible_solve_steps.first() {
-                        solve_step.deductions.apply(self.solver.grid.as_mut())?;
+                        self.solver.apply_solve_step(solve_step)?;
                         Ok(Some(possible_solve_steps))
                     } else {
                         Ok(None)
@@ -449,8 +580,6 @@ impl<
 
 #[cfg(test)]
 mod tests {
-    use itertools::Itertools;
-
     use crate::base::consts::Base2;
     use crate::cell::Value;
     use crate::position::Position;
@@ -520,6 +649,82 @@ mod tests {
         assert_fallible_solver_single_solution(&mut solver, &grid);
     }
 
+    #[test]
+    fn test_control() {
+        use crate::solver::control::{AbortReason, SolverControl};
+
+        let grid = crate::samples::base_3().remove(0);
+
+        let mut solver = Solver::builder(grid.clone())
+            .control(SolverControl::new().with_max_steps(0))
+            .build();
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::StepBudgetExhausted)
+        );
+        assert_eq!(solver.step_count, 0);
+
+        let mut solver = Solver::builder(grid.clone())
+            .strategies(StrategySet::default_solver_strategies())
+            .control(SolverControl::new().with_max_steps(u64::MAX - 1))
+            .build();
+        let SolveOutcome::Solution(solution) = solver.solve_controlled().unwrap() else {
+            panic!("Solver should find a solution")
+        };
+        crate::solver::test_util::assert_solution(&solution, &grid);
+
+        // `BruteForce` takes the remaining steps, which are not enough for the backtracks of this grid.
+        let mut solver = Solver::builder(grid.clone())
+            .strategies(StrategySet::with_single(
+                crate::solver::strategic::strategies::BruteForce.into(),
+            ))
+            .control(SolverControl::new().with_max_steps(1))
+            .build();
+        assert_eq!(
+            solver.solve_controlled().unwrap(),
+            SolveOutcome::Aborted(AbortReason::StepBudgetExhausted)
+        );
+
+        // Errors unrelated to the control are preserved.
+        let mut inconsistent_grid = Grid::<Base2>::new();
+        inconsistent_grid[Position::try_from((0, 0)).unwrap()].set_value(Value::default());
+        inconsistent_grid[Position::try_from((0, 1)).unwrap()].set_value(Value::default());
+        let mut solver = Solver::new(inconsistent_grid);
+        solver.solve_controlled().unwrap_err();
+    }
+
+    #[test]
+    fn test_forcing_chains_step_depth() {
+        use crate::solver::strategic::strategies::ForcingChains;
+
+        let mut grid = crate::samples::base_3().remove(1);
+        grid.set_all_direct_candidates();
+
+        let solver = Solver::with_strategies(
+            grid.clone(),
+            StrategySet::with_single(ForcingChains::default().into()),
+        );
+        let SolveStep {
+            strategy,
+            deductions,
+        } = solver.try_strategies().unwrap().unwrap();
+
+        // The step reports the smallest depth with deductions, instead of the maximum depth.
+        let StrategyEnum::ForcingChains(forcing_chains) = strategy else {
+            panic!("Expected forcing chains, got {strategy}")
+        };
+        assert!(forcing_chains.max_depth() <= ForcingChains::default().max_depth());
+        assert_eq!(forcing_chains.execute(&grid).unwrap(), deductions);
+        if let Some(smaller_depth) = forcing_chains.max_depth().checked_sub(1) {
+            assert!(
+                ForcingChains::with_max_depth(smaller_depth)
+                    .execute(&grid)
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
+
     mod snapshots {
         use super::*;
         use crate::test_util::{for_base_grid_samples, test_max_base3, test_max_base4};
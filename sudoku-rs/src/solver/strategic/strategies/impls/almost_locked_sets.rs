@@ -0,0 +1,237 @@
+use std::collections::BTreeSet;
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::grid::Grid;
+use crate::grid::group::CandidatesGroup;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Deduction, Deductions, Reason};
+use itertools::Itertools;
+
+/// The maximal number of cells of an enumerated almost locked set.
+///
+/// Larger sets are rarely useful and would dominate the runtime for larger bases.
+const MAX_ALMOST_LOCKED_SET_SIZE: u8 = 4;
+
+/// An almost locked set (ALS): `n` cells inside a single group, which contain exactly `n + 1` candidates.
+///
+/// If any of its candidates is removed, the remaining cells form a locked set.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct AlmostLockedSet<Base: SudokuBase> {
+    cells: Vec<(Position<Base>, Candidates<Base>)>,
+    candidates: Candidates<Base>,
+}
+
+impl<Base: SudokuBase> AlmostLockedSet<Base> {
+    pub(super) fn candidates(&self) -> Candidates<Base> {
+        self.candidates
+    }
+
+    /// The number of cells of this set.
+    pub(super) fn size(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub(super) fn positions(&self) -> impl Iterator<Item = Position<Base>> + '_ {
+        self.cells.iter().map(|&(pos, _)| pos)
+    }
+
+    /// The positions of the cells, which contain `candidate`.
+    pub(super) fn positions_with_candidate(
+        &self,
+        candidate: Value<Base>,
+    ) -> impl Iterator<Item = Position<Base>> + '_ {
+        self.cells
+            .iter()
+            .filter(move |(_, candidates)| candidates.has(candidate))
+            .map(|&(pos, _)| pos)
+    }
+
+    pub(super) fn contains(&self, pos: Position<Base>) -> bool {
+        self.positions().contains(&pos)
+    }
+
+    pub(super) fn overlaps(&self, other: &Self) -> bool {
+        self.positions().any(|pos| other.contains(pos))
+    }
+
+    /// The restricted common candidates (RCC) of two non-overlapping almost locked sets.
+    ///
+    /// A candidate is restricted common, if every cell containing it in one set sees every cell containing it in the other set.
+    /// Therefore the candidate can be true in at most one of the two sets.
+    pub(super) fn restricted_common_candidates(&self, other: &Self) -> Candidates<Base> {
+        self.candidates
+            .intersection(other.candidates)
+            .iter()
+            .filter(|&candidate| {
+                self.positions_with_candidate(candidate)
+                    .cartesian_product(other.positions_with_candidate(candidate).collect_vec())
+                    .all(|(pos, other_pos)| pos.sees(other_pos))
+            })
+            .collect()
+    }
+
+    /// The reasons for all cells of this set.
+    ///
+    /// The `restricted_common_candidates` are highlighted as color 1, all other candidates as color 2.
+    pub(super) fn reasons(
+        &self,
+        restricted_common_candidates: Candidates<Base>,
+    ) -> impl Iterator<Item = (Position<Base>, Reason<Base>)> + '_ {
+        self.cells.iter().map(move |&(pos, candidates)| {
+            (
+                pos,
+                Reason::colors(
+                    candidates.intersection(restricted_common_candidates),
+                    candidates.without(restricted_common_candidates),
+                ),
+            )
+        })
+    }
+}
+
+/// All almost locked sets of the grid with up to `MAX_ALMOST_LOCKED_SET_SIZE` cells.
+///
+/// A set of cells, which is contained in multiple groups (e.g. row and block), is only reported once.
+pub(super) fn almost_locked_sets<Base: SudokuBase>(
+    grid: &Grid<Base>,
+) -> Vec<AlmostLockedSet<Base>> {
+    let max_size = MAX_ALMOST_LOCKED_SET_SIZE.min(Base::MAX_VALUE - 1);
+
+    let mut almost_locked_sets = BTreeSet::new();
+
+    for group in Grid::<Base>::all_group_positions() {
+        let positions = group.collect_vec();
+
+        let candidates_group: CandidatesGroup<Base> = positions
+            .iter()
+            .map(|&pos| grid[pos].candidates().unwrap_or_default())
+            .collect_vec()
+            .try_into()
+            .unwrap();
+
+        // Members of an ALS with up to `max_size` cells contain between 1 and `max_size + 1` candidates.
+        let potential_indexes: Candidates<Base> = candidates_group
+            .iter_enumerate()
+            .filter(|(_, candidates)| (1..=max_size + 1).contains(&candidates.count()))
+            .map(|(i, _)| i)
+            .collect();
+
+        for size in Value::<Base>::all().take(max_size.into()) {
+            for indexes in potential_indexes.combinations(size) {
+                let candidates = candidates_group
+                    .iter_index_mask(indexes)
+                    .fold(Candidates::new(), Candidates::union);
+
+                if candidates.count() != size.get() + 1 {
+                    continue;
+                }
+
+                let cells = candidates_group
+                    .iter_enumerate()
+                    .filter(|&(i, _)| indexes.has(i))
+                    .map(|(i, candidates)| (positions[i.get_usize()], candidates))
+                    .collect();
+
+                almost_locked_sets.insert(AlmostLockedSet { cells, candidates });
+            }
+        }
+    }
+
+    almost_locked_sets.into_iter().collect()
+}
+
+/// All positions outside of `almost_locked_sets`, which contain `candidate`
+/// and see every cell containing `candidate` in each of the `almost_locked_sets`.
+pub(super) fn positions_seeing_candidate<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    candidate: Value<Base>,
+    almost_locked_sets: &[&AlmostLockedSet<Base>],
+) -> Vec<Position<Base>> {
+    let candidate_positions = almost_locked_sets
+        .iter()
+        .flat_map(|almost_locked_set| almost_locked_set.positions_with_candidate(candidate))
+        .collect_vec();
+
+    Position::<Base>::all()
+        .filter(|&pos| {
+            grid[pos]
+                .candidates()
+                .is_some_and(|candidates| candidates.has(candidate))
+                && !almost_locked_sets
+                    .iter()
+                    .any(|almost_locked_set| almost_locked_set.contains(pos))
+                && candidate_positions
+                    .iter()
+                    .all(|&candidate_pos| candidate_pos.sees(pos))
+        })
+        .collect()
+}
+
+/// Keeps only the deductions, which are based on the smallest total number of almost locked set cells.
+///
+/// Larger patterns mostly repeat the eliminations of smaller ones with additional cells.
+pub(super) fn smallest_deductions<Base: SudokuBase>(
+    sized_deductions: impl IntoIterator<Item = (usize, Deduction<Base>)>,
+) -> Deductions<Base> {
+    let sized_deductions = sized_deductions.into_iter().collect_vec();
+
+    let Some(min_size) = sized_deductions.iter().map(|&(size, _)| size).min() else {
+        return Deductions::default();
+    };
+
+    sized_deductions
+        .into_iter()
+        .filter(|&(size, _)| size == min_size)
+        .map(|(_, deduction)| deduction)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+
+    #[test]
+    fn test_almost_locked_sets() {
+        let mut grid = Grid::<Base2>::new();
+        let candidates = |candidates: &[u8]| -> Candidates<Base2> {
+            candidates
+                .iter()
+                .map(|&candidate| Value::try_from(candidate).unwrap())
+                .collect()
+        };
+        let pos = |row: u8, column: u8| Position::<Base2>::try_from((row, column)).unwrap();
+
+        for pos in Position::<Base2>::all() {
+            grid[pos] = Cell::with_value(1.try_into().unwrap(), false);
+        }
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 1)] = Cell::with_candidates(candidates(&[2, 3]));
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[1, 2, 3, 4]));
+
+        let almost_locked_sets = almost_locked_sets(&grid)
+            .into_iter()
+            .map(|almost_locked_set| {
+                (
+                    almost_locked_set.positions().collect_vec(),
+                    almost_locked_set.candidates(),
+                )
+            })
+            .collect_vec();
+
+        assert_eq!(
+            almost_locked_sets,
+            vec![
+                (vec![pos(0, 0)], candidates(&[1, 2])),
+                (vec![pos(0, 0), pos(0, 1)], candidates(&[1, 2, 3])),
+                (
+                    vec![pos(0, 0), pos(0, 1), pos(0, 3)],
+                    candidates(&[1, 2, 3, 4])
+                ),
+                (vec![pos(0, 1)], candidates(&[2, 3])),
+            ]
+        );
+    }
+}
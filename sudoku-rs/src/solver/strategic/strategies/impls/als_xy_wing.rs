@@ -0,0 +1,236 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use crate::solver::strategic::strategies::impls::almost_locked_sets::{
+    almost_locked_sets, positions_seeing_candidate, smallest_deductions,
+};
+use itertools::Itertools;
+
+/*
+Logic:
+
+Three non-overlapping almost locked sets: a pivot `C` and two wings `A` and `B`.
+- `A` and `C` share the restricted common candidate `x`.
+- `B` and `C` share the restricted common candidate `y`, which is different from `x`.
+
+If `A` doesn't contain `z`, it is locked and contains `x`, therefore `C` doesn't contain `x` and is locked.
+Then `C` contains `y`, therefore `B` doesn't contain `y`, is locked and contains `z`.
+
+Eliminations: for each candidate `z` (different from `x` and `y`) shared by `A` and `B`,
+`z` in all cells which see every `z` of both wings.
+
+Only the deductions of the smallest wings are reported.
+*/
+
+/// An implementation of the [ALS-XY-Wing](https://www.sudokuwiki.org/ALS_XY_Wing) strategy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AlsXyWing;
+
+impl Strategy for AlsXyWing {
+    fn name(self) -> &'static str {
+        "AlsXyWing"
+    }
+
+    fn score(self) -> StrategyScore {
+        600
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let almost_locked_sets = almost_locked_sets(grid);
+
+        // All almost locked sets, which are linked to an almost locked set by restricted common candidates.
+        let linked_almost_locked_sets = almost_locked_sets
+            .iter()
+            .map(|als| {
+                almost_locked_sets
+                    .iter()
+                    .filter(|other_als| !als.overlaps(other_als))
+                    .map(|other_als| (other_als, als.restricted_common_candidates(other_als)))
+                    .filter(|(_, restricted_common_candidates)| {
+                        !restricted_common_candidates.is_empty()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        Ok(smallest_deductions(
+            almost_locked_sets
+                .iter()
+                .zip(&linked_almost_locked_sets)
+                .flat_map(|(pivot, linked)| {
+                    linked
+                        .iter()
+                        .tuple_combinations()
+                        .filter(|((wing_a, _), (wing_b, _))| !wing_a.overlaps(wing_b))
+                        .flat_map(move |(&(wing_a, rccs_a), &(wing_b, rccs_b))| {
+                            rccs_a
+                                .iter()
+                                .cartesian_product(rccs_b.iter().collect_vec())
+                                .filter(|(x, y)| x != y)
+                                .filter_map(move |(x, y)| {
+                                    let xy = Candidates::with_single(x)
+                                        .union(Candidates::with_single(y));
+
+                                    let actions = wing_a
+                                        .candidates()
+                                        .intersection(wing_b.candidates())
+                                        .without(xy)
+                                        .iter()
+                                        .flat_map(|z| {
+                                            positions_seeing_candidate(grid, z, &[wing_a, wing_b])
+                                                .into_iter()
+                                                .filter(|&pos| !pivot.contains(pos))
+                                                .map(move |pos| (pos, Action::delete_candidate(z)))
+                                        })
+                                        .collect_vec();
+
+                                    if actions.is_empty() {
+                                        return None;
+                                    }
+
+                                    Some((
+                                        wing_a.size() + pivot.size() + wing_b.size(),
+                                        Deduction::try_from_iters(
+                                            actions,
+                                            wing_a
+                                                .reasons(Candidates::with_single(x))
+                                                .chain(pivot.reasons(xy))
+                                                .chain(wing_b.reasons(Candidates::with_single(y))),
+                                        )
+                                        .unwrap(),
+                                    ))
+                                })
+                        })
+                }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Cell, Value};
+    use crate::position::Position;
+    use crate::solver::strategic::deduction::Reason;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_als_xy_wing() {
+        let mut grid = Grid::<Base3>::new();
+
+        // Pivot: ALS in row 4 with candidates 1, 2 and 3
+        grid[pos(4, 1)] = Cell::with_candidates(candidates(&[1, 3]));
+        grid[pos(4, 7)] = Cell::with_candidates(candidates(&[2, 3]));
+        // Wing A, linked to the pivot by x = 1 in column 1
+        grid[pos(0, 1)] = Cell::with_candidates(candidates(&[1, 4]));
+        // Wing B, linked to the pivot by y = 2 in column 7
+        grid[pos(8, 7)] = Cell::with_candidates(candidates(&[2, 4]));
+        // Sees z = 4 in both wings
+        grid[pos(0, 7)] = Cell::with_candidates(candidates(&[4, 5, 6]));
+        grid[pos(8, 1)] = Cell::with_candidates(candidates(&[4, 5, 6]));
+
+        let deductions = AlsXyWing.execute(&grid).unwrap();
+
+        let actions = [
+            (pos(0, 7), Action::delete_candidate(4.try_into().unwrap())),
+            (pos(8, 1), Action::delete_candidate(4.try_into().unwrap())),
+        ];
+        let expected_deductions: Deductions<Base3> = [
+            // Pivot r4c1 r4c7
+            Deduction::try_from_iters(
+                actions,
+                [
+                    (
+                        pos(0, 1),
+                        Reason::colors(candidates(&[1]), candidates(&[4])),
+                    ),
+                    (
+                        pos(4, 1),
+                        Reason::colors(candidates(&[1]), candidates(&[3])),
+                    ),
+                    (
+                        pos(4, 7),
+                        Reason::colors(candidates(&[2]), candidates(&[3])),
+                    ),
+                    (
+                        pos(8, 7),
+                        Reason::colors(candidates(&[2]), candidates(&[4])),
+                    ),
+                ],
+            )
+            .unwrap(),
+            // Pivot r4c1, wings r0c1 and r4c7 r8c7
+            Deduction::try_from_iters(
+                actions,
+                [
+                    (
+                        pos(0, 1),
+                        Reason::colors(candidates(&[1]), candidates(&[4])),
+                    ),
+                    (
+                        pos(4, 1),
+                        Reason::colors(candidates(&[1, 3]), candidates(&[])),
+                    ),
+                    (
+                        pos(4, 7),
+                        Reason::colors(candidates(&[3]), candidates(&[2])),
+                    ),
+                    (
+                        pos(8, 7),
+                        Reason::colors(candidates(&[]), candidates(&[2, 4])),
+                    ),
+                ],
+            )
+            .unwrap(),
+            // Pivot r4c7, wings r8c7 and r0c1 r4c1
+            Deduction::try_from_iters(
+                actions,
+                [
+                    (
+                        pos(0, 1),
+                        Reason::colors(candidates(&[]), candidates(&[1, 4])),
+                    ),
+                    (
+                        pos(4, 1),
+                        Reason::colors(candidates(&[3]), candidates(&[1])),
+                    ),
+                    (
+                        pos(4, 7),
+                        Reason::colors(candidates(&[2, 3]), candidates(&[])),
+                    ),
+                    (
+                        pos(8, 7),
+                        Reason::colors(candidates(&[2]), candidates(&[4])),
+                    ),
+                ],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    strategy_snapshot_tests!(AlsXyWing);
+}
@@ -0,0 +1,299 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use crate::solver::strategic::strategies::impls::almost_locked_sets::{
+    AlmostLockedSet, almost_locked_sets, positions_seeing_candidate, smallest_deductions,
+};
+use itertools::Itertools;
+
+/*
+Logic:
+
+Two non-overlapping almost locked sets `A` and `B` share a restricted common candidate `x`:
+every cell of `A` containing `x` sees every cell of `B` containing `x`.
+Therefore `x` is true in at most one of the sets, which makes the other set a locked set.
+
+Singly linked: for every other common candidate `z`, one of the sets must contain `z`.
+Eliminations: `z` in all cells which see every `z` of both sets.
+
+Only the deductions of the smallest pairs of sets are reported.
+
+Doubly linked: with two restricted common candidates `x` and `y`, both sets become locked sets.
+Eliminations:
+- `x` and `y` in all cells which see every `x` or `y` of both sets respectively.
+- every other candidate of a set in all cells which see every occurrence of the candidate in this set.
+*/
+
+/// An implementation of the [ALS-XZ](https://www.sudokuwiki.org/Almost_Locked_Sets) strategy,
+/// including doubly linked almost locked sets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AlsXz;
+
+impl Strategy for AlsXz {
+    fn name(self) -> &'static str {
+        "AlsXz"
+    }
+
+    fn score(self) -> StrategyScore {
+        550
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let almost_locked_sets = almost_locked_sets(grid);
+
+        Ok(smallest_deductions(
+            almost_locked_sets
+                .iter()
+                .tuple_combinations()
+                .filter(|(als_a, als_b)| {
+                    als_a.candidates().intersection(als_b.candidates()).count() >= 2
+                        && !als_a.overlaps(als_b)
+                })
+                .filter_map(|(als_a, als_b)| {
+                    let restricted_common_candidates = als_a.restricted_common_candidates(als_b);
+
+                    let actions = match restricted_common_candidates.count() {
+                        0 => return None,
+                        1 => {
+                            singly_linked_actions(grid, als_a, als_b, restricted_common_candidates)
+                        }
+                        _ => {
+                            doubly_linked_actions(grid, als_a, als_b, restricted_common_candidates)
+                        }
+                    };
+
+                    if actions.is_empty() {
+                        return None;
+                    }
+
+                    Some((
+                        als_a.size() + als_b.size(),
+                        Deduction::try_from_iters(
+                            actions,
+                            als_a
+                                .reasons(restricted_common_candidates)
+                                .chain(als_b.reasons(restricted_common_candidates)),
+                        )
+                        .unwrap(),
+                    ))
+                }),
+        ))
+    }
+}
+
+fn singly_linked_actions<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    als_a: &AlmostLockedSet<Base>,
+    als_b: &AlmostLockedSet<Base>,
+    restricted_common_candidates: Candidates<Base>,
+) -> Vec<(Position<Base>, Action<Base>)> {
+    als_a
+        .candidates()
+        .intersection(als_b.candidates())
+        .without(restricted_common_candidates)
+        .iter()
+        .flat_map(|z| {
+            positions_seeing_candidate(grid, z, &[als_a, als_b])
+                .into_iter()
+                .map(move |pos| (pos, Action::delete_candidate(z)))
+        })
+        .collect()
+}
+
+fn doubly_linked_actions<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    als_a: &AlmostLockedSet<Base>,
+    als_b: &AlmostLockedSet<Base>,
+    restricted_common_candidates: Candidates<Base>,
+) -> Vec<(Position<Base>, Action<Base>)> {
+    let restricted_common_actions = restricted_common_candidates.iter().flat_map(|x| {
+        positions_seeing_candidate(grid, x, &[als_a, als_b])
+            .into_iter()
+            .map(move |pos| (pos, Action::delete_candidate(x)))
+    });
+
+    let locked_set_actions =
+        [(als_a, als_b), (als_b, als_a)]
+            .into_iter()
+            .flat_map(|(als, other_als)| {
+                als.candidates()
+                    .without(restricted_common_candidates)
+                    .iter()
+                    .flat_map(move |candidate| {
+                        positions_seeing_candidate(grid, candidate, &[als])
+                            .into_iter()
+                            .filter(|&pos| !other_als.contains(pos))
+                            .map(move |pos| (pos, Action::delete_candidate(candidate)))
+                    })
+            });
+
+    restricted_common_actions
+        .chain(locked_set_actions)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Cell, Value};
+    use crate::solver::strategic::deduction::Reason;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_singly_linked() {
+        let mut grid = Grid::<Base3>::new();
+
+        // ALS in row 0 with candidates 1, 2 and 3
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[1, 3]));
+        // Bivalue cell ALS, linked by the restricted common candidate 3 in column 4
+        grid[pos(4, 4)] = Cell::with_candidates(candidates(&[2, 3]));
+        // Sees the candidate 2 of both sets
+        grid[pos(4, 0)] = Cell::with_candidates(candidates(&[2, 5, 6]));
+        // Sees only the candidate 2 of the first set
+        grid[pos(8, 0)] = Cell::with_candidates(candidates(&[2, 6, 7]));
+
+        let deductions = AlsXz.execute(&grid).unwrap();
+
+        let action = (pos(4, 0), Action::delete_candidate(2.try_into().unwrap()));
+        let expected_deductions: Deductions<Base3> = [
+            // ALS r0c0 r0c4 and ALS r4c4, restricted common candidate 3
+            Deduction::try_from_iters(
+                [action],
+                [
+                    (
+                        pos(0, 0),
+                        Reason::colors(candidates(&[]), candidates(&[1, 2])),
+                    ),
+                    (
+                        pos(0, 4),
+                        Reason::colors(candidates(&[3]), candidates(&[1])),
+                    ),
+                    (
+                        pos(4, 4),
+                        Reason::colors(candidates(&[3]), candidates(&[2])),
+                    ),
+                ],
+            )
+            .unwrap(),
+            // ALS r0c0 and ALS r0c4 r4c4, restricted common candidate 1
+            Deduction::try_from_iters(
+                [action],
+                [
+                    (
+                        pos(0, 0),
+                        Reason::colors(candidates(&[1]), candidates(&[2])),
+                    ),
+                    (
+                        pos(0, 4),
+                        Reason::colors(candidates(&[1]), candidates(&[3])),
+                    ),
+                    (
+                        pos(4, 4),
+                        Reason::colors(candidates(&[]), candidates(&[2, 3])),
+                    ),
+                ],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    #[test]
+    fn test_synthetic_doubly_linked() {
+        let mut grid = Grid::<Base3>::new();
+
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[2, 3]));
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 4]));
+        grid[pos(1, 4)] = Cell::with_candidates(candidates(&[3, 4]));
+        // Eliminations: 2 in row 0, 4 in row 1
+        grid[pos(0, 8)] = Cell::with_candidates(candidates(&[2, 5, 6]));
+        grid[pos(1, 8)] = Cell::with_candidates(candidates(&[4, 5, 6]));
+
+        let deductions = AlsXz.execute(&grid).unwrap();
+
+        let actions = [
+            (pos(0, 8), Action::delete_candidate(2.try_into().unwrap())),
+            (pos(1, 8), Action::delete_candidate(4.try_into().unwrap())),
+        ];
+        let expected_deductions: Deductions<Base3> = [
+            // ALS in row 0 and row 1, restricted common candidates 1 and 3
+            Deduction::try_from_iters(
+                actions,
+                [
+                    (
+                        pos(0, 0),
+                        Reason::colors(candidates(&[1]), candidates(&[2])),
+                    ),
+                    (
+                        pos(0, 4),
+                        Reason::colors(candidates(&[3]), candidates(&[2])),
+                    ),
+                    (
+                        pos(1, 0),
+                        Reason::colors(candidates(&[1]), candidates(&[4])),
+                    ),
+                    (
+                        pos(1, 4),
+                        Reason::colors(candidates(&[3]), candidates(&[4])),
+                    ),
+                ],
+            )
+            .unwrap(),
+            // ALS in column 0 and column 4, restricted common candidates 2 and 4
+            Deduction::try_from_iters(
+                actions,
+                [
+                    (
+                        pos(0, 0),
+                        Reason::colors(candidates(&[2]), candidates(&[1])),
+                    ),
+                    (
+                        pos(0, 4),
+                        Reason::colors(candidates(&[2]), candidates(&[3])),
+                    ),
+                    (
+                        pos(1, 0),
+                        Reason::colors(candidates(&[4]), candidates(&[1])),
+                    ),
+                    (
+                        pos(1, 4),
+                        Reason::colors(candidates(&[4]), candidates(&[3])),
+                    ),
+                ],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    strategy_snapshot_tests!(AlsXz);
+}
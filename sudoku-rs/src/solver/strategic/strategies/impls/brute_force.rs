@@ -1,13 +1,38 @@
 use crate::base::SudokuBase;
 use crate::error::Result;
 use crate::grid::Grid;
-use crate::solver::introspective;
+use crate::solver::control::SolverControl;
 use crate::solver::strategic::deduction::{Action, Deduction, Deductions};
 use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+use crate::solver::{ControlledSolver, SolveOutcome, introspective};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct BruteForce;
 
+impl BruteForce {
+    /// Like [`Strategy::execute`], but aborts once a limit of `control` is reached.
+    ///
+    /// The step budget applies to the steps of the underlying [`introspective::Solver`].
+    /// If aborted, returns an [`AbortReason`](crate::solver::control::AbortReason) as error.
+    pub fn execute_controlled<Base: SudokuBase>(
+        self,
+        grid: &Grid<Base>,
+        control: SolverControl,
+    ) -> Result<Deductions<Base>> {
+        let mut solver = introspective::Solver::with_filter_and_control(grid, (), control);
+
+        match solver.solve_controlled()? {
+            SolveOutcome::Solution(solution) => Ok(grid
+                .all_candidates_positions()
+                .into_iter()
+                .map(|pos| Deduction::with_action(pos, Action::SetValue(solution[pos])))
+                .collect()),
+            SolveOutcome::NoSolution => Ok(Deductions::default()),
+            SolveOutcome::Aborted(abort_reason) => Err(abort_reason.into()),
+        }
+    }
+}
+
 impl Strategy for BruteForce {
     fn name(self) -> &'static str {
         "BruteForce"
@@ -16,22 +41,7 @@ impl Strategy for BruteForce {
         1_000_000
     }
     fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
-        let mut solver = introspective::Solver::new(grid);
-
-        if let Some(solved_grid) = solver.next() {
-            Ok(grid
-                .all_candidates_positions()
-                .into_iter()
-                .map(|pos| {
-                    Deduction::with_action(
-                        pos,
-                        Action::SetValue(solved_grid.get(pos).value().unwrap()),
-                    )
-                })
-                .collect())
-        } else {
-            Ok(Deductions::default())
-        }
+        self.execute_controlled(grid, SolverControl::default())
     }
 }
 
@@ -75,6 +85,23 @@ mod tests {
         assert!(grid.is_solved());
     }
 
+    #[test]
+    fn test_control() {
+        use crate::solver::control::AbortReason;
+        use std::time::Duration;
+
+        let mut grid = samples::base_5().remove(0);
+        grid.set_all_direct_candidates();
+
+        let err = BruteForce
+            .execute_controlled(&grid, SolverControl::new().with_timeout(Duration::ZERO))
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AbortReason>(),
+            Some(&AbortReason::DeadlineExceeded)
+        );
+    }
+
     strategy_snapshot_tests!(BruteForce, |grid| {
         assert!(grid.is_solved());
     });
@@ -0,0 +1,178 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::deduction::Reason;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use itertools::Itertools;
+
+/*
+Logic:
+
+A bivalue universal grave (BUG) is a grid in which every unsolved cell has exactly two candidates
+and every candidate appears exactly twice in each group it appears in.
+Such a grid has either zero or multiple solutions, since all candidates could be swapped along their links.
+
+BUG+1: all unsolved cells are bivalue except a single cell with three candidates.
+If removing the candidate `x` from this cell would result in a BUG, the cell must be `x`,
+assuming the grid has a unique solution.
+*/
+
+/// An implementation of the [BUG+1](https://www.sudokuwiki.org/BUG) (bivalue universal grave) strategy.
+///
+/// Only valid for grids with a unique solution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BugPlusOne;
+
+impl Strategy for BugPlusOne {
+    fn name(self) -> &'static str {
+        "BugPlusOne"
+    }
+
+    fn score(self) -> StrategyScore {
+        260
+    }
+
+    fn requires_unique_solution(self) -> bool {
+        true
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let Some((trivalue_pos, trivalue_candidates)) = single_trivalue_cell(grid) else {
+            return Ok(Deductions::default());
+        };
+
+        let Some(value) = trivalue_candidates
+            .iter()
+            .filter(|&value| {
+                is_bivalue_universal_grave(
+                    grid,
+                    trivalue_pos,
+                    trivalue_candidates.without(Candidates::with_single(value)),
+                )
+            })
+            .exactly_one()
+            .ok()
+        else {
+            return Ok(Deductions::default());
+        };
+
+        // The candidate in the trivalue cell and in all cells seeing it.
+        let reasons = Position::<Base>::all()
+            .filter(|&pos| {
+                (pos == trivalue_pos || pos.sees(trivalue_pos))
+                    && grid[pos]
+                        .candidates()
+                        .is_some_and(|candidates| candidates.has(value))
+            })
+            .map(|pos| (pos, Reason::candidate(value)));
+
+        Ok(
+            Deduction::try_from_iters([(trivalue_pos, Action::set_value(value))], reasons)
+                .unwrap()
+                .into(),
+        )
+    }
+}
+
+/// The only unsolved cell with three candidates, if all other unsolved cells are bivalue.
+fn single_trivalue_cell<Base: SudokuBase>(
+    grid: &Grid<Base>,
+) -> Option<(Position<Base>, Candidates<Base>)> {
+    let mut trivalue_cell = None;
+
+    for pos in Position::<Base>::all() {
+        let Some(candidates) = grid[pos].candidates() else {
+            continue;
+        };
+        match candidates.count() {
+            2 => {}
+            3 if trivalue_cell.is_none() => trivalue_cell = Some((pos, candidates)),
+            _ => return None,
+        }
+    }
+
+    trivalue_cell
+}
+
+/// Checks if every candidate appears either zero or two times in each group,
+/// with the candidates of `replaced_pos` replaced by `replaced_candidates`.
+fn is_bivalue_universal_grave<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    replaced_pos: Position<Base>,
+    replaced_candidates: Candidates<Base>,
+) -> bool {
+    let candidates_at = |pos: Position<Base>| {
+        if pos == replaced_pos {
+            replaced_candidates
+        } else {
+            grid[pos].candidates().unwrap_or_default()
+        }
+    };
+
+    let rows = Grid::<Base>::all_row_positions().map(Itertools::collect_vec);
+    let columns = Grid::<Base>::all_column_positions().map(Itertools::collect_vec);
+    let blocks = Grid::<Base>::all_block_positions().map(Itertools::collect_vec);
+
+    rows.chain(columns).chain(blocks).all(|group| {
+        Value::<Base>::all().all(|candidate| {
+            let count = group
+                .iter()
+                .filter(|&&pos| candidates_at(pos).has(candidate))
+                .count();
+            count == 0 || count == 2
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    #[test]
+    fn test_synthetic_bug_plus_one() {
+        let candidates = |candidates: &[u8]| -> Candidates<Base2> {
+            candidates
+                .iter()
+                .map(|&candidate| Value::try_from(candidate).unwrap())
+                .collect()
+        };
+        let pos = |row: u8, column: u8| Position::<Base2>::try_from((row, column)).unwrap();
+
+        let values = [[1, 2, 3, 4], [3, 4, 1, 2], [2, 1, 4, 3], [4, 3, 2, 1]];
+        let mut grid = Grid::<Base2>::new();
+        for (pos, value) in Position::<Base2>::all().zip(values.into_iter().flatten()) {
+            grid[pos] = Cell::with_value(value.try_into().unwrap(), false);
+        }
+
+        // A BUG on the candidates 1 and 2 ...
+        for (row, column) in [(0, 1), (2, 0), (2, 1)] {
+            grid[pos(row, column)] = Cell::with_candidates(candidates(&[1, 2]));
+        }
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        assert!(BugPlusOne.execute(&grid).unwrap().is_empty());
+
+        // ... with the extra candidate 3
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+
+        let deductions = BugPlusOne.execute(&grid).unwrap();
+
+        let expected_deductions =
+            Deduction::with_action(pos(0, 0), Action::set_value(3.try_into().unwrap()));
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    strategy_snapshot_tests!(BugPlusOne);
+}
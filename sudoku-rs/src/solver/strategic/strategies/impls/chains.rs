@@ -0,0 +1,573 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{
+    Action, Chain, ChainNode, Deduction, Deductions, Reason,
+};
+use crate::solver::strategic::strategies::impls::links::strong_links;
+use crate::solver::strategic::strategies::impls::xy_wing::bivalue_cells;
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+/// The default maximum number of nodes in a chain.
+pub const DEFAULT_MAX_CHAIN_LENGTH: u8 = 12;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct XChain {
+    max_length: u8,
+}
+impl Default for XChain {
+    fn default() -> Self {
+        Self::with_max_length(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+impl XChain {
+    /// Only search for chains containing at most `max_length` nodes.
+    pub const fn with_max_length(max_length: u8) -> Self {
+        Self { max_length }
+    }
+}
+impl Strategy for XChain {
+    fn name(self) -> &'static str {
+        "XChain"
+    }
+    fn score(self) -> StrategyScore {
+        400
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Chains::new(ChainType::X, self.max_length).execute(grid)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct XyChain {
+    max_length: u8,
+}
+impl Default for XyChain {
+    fn default() -> Self {
+        Self::with_max_length(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+impl XyChain {
+    /// Only search for chains containing at most `max_length` nodes.
+    pub const fn with_max_length(max_length: u8) -> Self {
+        Self { max_length }
+    }
+}
+impl Strategy for XyChain {
+    fn name(self) -> &'static str {
+        "XyChain"
+    }
+    fn score(self) -> StrategyScore {
+        450
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Chains::new(ChainType::Xy, self.max_length).execute(grid)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AlternatingInferenceChain {
+    max_length: u8,
+}
+impl Default for AlternatingInferenceChain {
+    fn default() -> Self {
+        Self::with_max_length(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+impl AlternatingInferenceChain {
+    /// Only search for chains containing at most `max_length` nodes.
+    pub const fn with_max_length(max_length: u8) -> Self {
+        Self { max_length }
+    }
+}
+impl Strategy for AlternatingInferenceChain {
+    fn name(self) -> &'static str {
+        "AlternatingInferenceChain"
+    }
+    fn score(self) -> StrategyScore {
+        500
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Chains::new(ChainType::AlternatingInference, self.max_length).execute(grid)
+    }
+}
+
+/*
+Logic:
+
+A node of a chain is a single candidate at a position.
+
+Two nodes are connected by:
+- a strong link, if at least one of them must be true:
+  - the candidate is restricted to exactly these two positions in a row, column or block (conjugate pair)
+  - the two candidates are the only candidates of a bivalue cell
+- a weak link, if at most one of them can be true:
+  - the same candidate in two positions, which see each other
+  - two candidates in the same cell
+
+An alternating inference chain (AIC) connects nodes by alternating strong and weak links,
+starting and ending with a strong link: `start = a - b = c - ... = end`.
+If `start` is false, `a` must be true, so `b` must be false, so `c` must be true, ..., so `end` must be true.
+Therefore at least one of `start` and `end` is true.
+
+Eliminations:
+- Same candidate at `start` and `end`: the candidate in all positions which see both `start` and `end`.
+- Different candidates in the same cell: all other candidates of this cell.
+- Different candidates in positions which see each other:
+  the candidate of `end` from the position of `start` and vice versa.
+
+Named variants:
+- X-Chain: all nodes share a single candidate. Only conjugate pairs are used as strong links.
+- XY-Chain: all nodes are located in bivalue cells. Strong links are located inside the cells,
+  weak links connect the same candidate in different cells.
+- AIC: all of the above links are used.
+
+The chains are found via a breadth-first search from each start node, so the shortest chain is found for each pair of ends.
+Only the deductions of the shortest chains which make progress are returned.
+*/
+
+/// An implementation of the [X-Chain](https://www.sudokuwiki.org/X_Chains),
+/// [XY-Chain](https://www.sudokuwiki.org/XY_Chains) and
+/// [Alternating Inference Chain](https://www.sudokuwiki.org/Alternating_Inference_Chains) strategies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Chains {
+    chain_type: ChainType,
+    max_length: u8,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ChainType {
+    X,
+    Xy,
+    AlternatingInference,
+}
+
+impl ChainType {
+    fn has_conjugate_pairs(self) -> bool {
+        matches!(self, Self::X | Self::AlternatingInference)
+    }
+
+    fn has_bivalue_cells(self) -> bool {
+        matches!(self, Self::Xy | Self::AlternatingInference)
+    }
+}
+
+impl Chains {
+    /// The minimal number of nodes of a reported chain.
+    /// Shorter chains consist of a single strong link, which is already covered by simpler strategies.
+    const MIN_LENGTH: usize = 4;
+
+    fn new(chain_type: ChainType, max_length: u8) -> Self {
+        Self {
+            chain_type,
+            max_length,
+        }
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let link_graph = LinkGraph::new(grid, self.chain_type);
+
+        let mut shortest_deductions: Vec<Deduction<Base>> = vec![];
+        let mut shortest_length = usize::from(self.max_length);
+
+        for start in 0..link_graph.nodes.len() {
+            for chain_nodes in link_graph.shortest_chains_from(start, shortest_length) {
+                let Some(deduction) = Self::chain_deduction(grid, chain_nodes)? else {
+                    continue;
+                };
+                let length = deduction
+                    .chain
+                    .as_ref()
+                    .map_or(0, |chain| chain.nodes().len());
+
+                if length < shortest_length || shortest_deductions.is_empty() {
+                    shortest_deductions.clear();
+                    shortest_length = length;
+                }
+                if length == shortest_length {
+                    shortest_deductions.push(deduction);
+                }
+            }
+        }
+
+        Ok(shortest_deductions.into_iter().collect())
+    }
+
+    fn chain_deduction<Base: SudokuBase>(
+        grid: &Grid<Base>,
+        chain_nodes: Vec<ChainNode<Base>>,
+    ) -> Result<Option<Deduction<Base>>> {
+        let chain = Chain::try_from(chain_nodes)?;
+        let (start, end) = chain.ends();
+
+        let has_candidate = |pos: Position<Base>, candidate: Value<Base>| {
+            grid[pos]
+                .candidates()
+                .is_some_and(|candidates| candidates.has(candidate))
+        };
+
+        let eliminations: Vec<(Position<Base>, Value<Base>)> = if start.candidate == end.candidate {
+            Position::<Base>::all()
+                .filter(|&pos| {
+                    pos != start.position
+                        && pos != end.position
+                        && start.position.sees(pos)
+                        && end.position.sees(pos)
+                        && has_candidate(pos, start.candidate)
+                })
+                .map(|pos| (pos, start.candidate))
+                .collect()
+        } else if start.position == end.position {
+            grid[start.position]
+                .candidates()
+                .into_iter()
+                .flat_map(|candidates| candidates.iter())
+                .filter(|&candidate| candidate != start.candidate && candidate != end.candidate)
+                .map(|candidate| (start.position, candidate))
+                .collect()
+        } else if start.position.sees(end.position) {
+            [
+                (start.position, end.candidate),
+                (end.position, start.candidate),
+            ]
+            .into_iter()
+            .filter(|&(pos, candidate)| has_candidate(pos, candidate))
+            .collect()
+        } else {
+            vec![]
+        };
+
+        // Candidates inside the chain are kept as reasons.
+        let eliminations = eliminations
+            .into_iter()
+            .filter(|&(position, candidate)| {
+                !chain.nodes().contains(&ChainNode::new(position, candidate))
+            })
+            .collect_vec();
+
+        if eliminations.is_empty() {
+            return Ok(None);
+        }
+
+        let deduction = Deduction::try_from_iters(
+            eliminations
+                .into_iter()
+                .map(|(pos, candidate)| (pos, Action::delete_candidate(candidate))),
+            chain
+                .nodes()
+                .iter()
+                .map(|node| (node.position, Reason::candidate(node.candidate))),
+        )?;
+
+        Ok(Some(deduction.with_chain(chain)))
+    }
+}
+
+/// All candidates of a grid, connected by strong and weak links.
+struct LinkGraph<Base: SudokuBase> {
+    nodes: Vec<ChainNode<Base>>,
+    strong_links: Vec<BTreeSet<usize>>,
+    weak_links: Vec<BTreeSet<usize>>,
+}
+
+impl<Base: SudokuBase> LinkGraph<Base> {
+    fn new(grid: &Grid<Base>, chain_type: ChainType) -> Self {
+        let nodes = match chain_type {
+            ChainType::X | ChainType::AlternatingInference => Position::<Base>::all()
+                .flat_map(|pos| {
+                    grid[pos]
+                        .candidates()
+                        .into_iter()
+                        .flat_map(|candidates| candidates.iter())
+                        .map(move |candidate| ChainNode::new(pos, candidate))
+                })
+                .collect_vec(),
+            ChainType::Xy => bivalue_cells(grid)
+                .into_iter()
+                .flat_map(|(pos, candidates)| {
+                    candidates
+                        .iter()
+                        .map(move |candidate| ChainNode::new(pos, candidate))
+                })
+                .collect_vec(),
+        };
+        let node_to_index: BTreeMap<ChainNode<Base>, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+
+        let mut this = Self {
+            strong_links: vec![BTreeSet::new(); nodes.len()],
+            weak_links: vec![BTreeSet::new(); nodes.len()],
+            nodes,
+        };
+
+        if chain_type.has_conjugate_pairs() {
+            for candidate in Value::<Base>::all() {
+                for strong_link in strong_links(grid, candidate) {
+                    let [first, second] = strong_link
+                        .positions
+                        .map(|pos| node_to_index[&ChainNode::new(pos, candidate)]);
+                    this.insert_strong_link(first, second);
+                }
+            }
+        }
+
+        if chain_type.has_bivalue_cells() {
+            for (pos, candidates) in bivalue_cells(grid) {
+                let (first, second) = candidates
+                    .iter()
+                    .map(|candidate| node_to_index[&ChainNode::new(pos, candidate)])
+                    .collect_tuple()
+                    .expect("bivalue cell to contain exactly two candidates");
+                this.insert_strong_link(first, second);
+            }
+        }
+
+        for ((first, first_node), (second, second_node)) in
+            this.nodes.iter().enumerate().tuple_combinations()
+        {
+            let is_weak_link = if first_node.position == second_node.position {
+                chain_type == ChainType::AlternatingInference
+            } else {
+                first_node.candidate == second_node.candidate
+                    && first_node.position.sees(second_node.position)
+            };
+            if is_weak_link {
+                this.weak_links[first].insert(second);
+                this.weak_links[second].insert(first);
+            }
+        }
+
+        this
+    }
+
+    fn insert_strong_link(&mut self, first: usize, second: usize) {
+        self.strong_links[first].insert(second);
+        self.strong_links[second].insert(first);
+        // Every strong link can also be used as a weak link.
+        self.weak_links[first].insert(second);
+        self.weak_links[second].insert(first);
+    }
+
+    /// The shortest chains starting at the node `start`, which contain at most `max_length` nodes.
+    ///
+    /// Each pair of ends is only reported once, for the smaller start node.
+    fn shortest_chains_from(&self, start: usize, max_length: usize) -> Vec<Vec<ChainNode<Base>>> {
+        if self.strong_links[start].is_empty() {
+            return vec![];
+        }
+
+        // A search state is a node combined with the type of the next link: `false` for strong, `true` for weak.
+        let state_index =
+            |node: usize, is_next_link_weak: bool| node * 2 + usize::from(is_next_link_weak);
+        let mut parents: Vec<Option<usize>> = vec![None; self.nodes.len() * 2];
+        let mut lengths: Vec<usize> = vec![0; self.nodes.len() * 2];
+
+        let start_state = state_index(start, false);
+        lengths[start_state] = 1;
+        let mut queue = VecDeque::from([(start, false)]);
+
+        while let Some((node, is_next_link_weak)) = queue.pop_front() {
+            let length = lengths[state_index(node, is_next_link_weak)];
+            if length >= max_length {
+                continue;
+            }
+
+            let adjacent_nodes = if is_next_link_weak {
+                &self.weak_links[node]
+            } else {
+                &self.strong_links[node]
+            };
+
+            for &adjacent_node in adjacent_nodes {
+                let adjacent_state = state_index(adjacent_node, !is_next_link_weak);
+                if lengths[adjacent_state] != 0 {
+                    continue;
+                }
+                lengths[adjacent_state] = length + 1;
+                parents[adjacent_state] = Some(state_index(node, is_next_link_weak));
+                queue.push_back((adjacent_node, !is_next_link_weak));
+            }
+        }
+
+        (start + 1..self.nodes.len())
+            .filter_map(|end| {
+                // The chain must end with a strong link.
+                let end_state = state_index(end, true);
+                if lengths[end_state] < Chains::MIN_LENGTH {
+                    return None;
+                }
+
+                let mut chain_nodes = vec![];
+                let mut state = Some(end_state);
+                while let Some(current_state) = state {
+                    chain_nodes.push(self.nodes[current_state / 2]);
+                    state = parents[current_state];
+                }
+                chain_nodes.reverse();
+
+                // Chains which visit a node twice are not reported.
+                chain_nodes.iter().all_unique().then_some(chain_nodes)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Candidates, Cell};
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    fn node(row: u8, column: u8, candidate: u8) -> ChainNode<Base3> {
+        ChainNode::new(pos(row, column), candidate.try_into().unwrap())
+    }
+
+    fn chain_deduction(
+        eliminations: &[(u8, u8, u8)],
+        chain_nodes: Vec<ChainNode<Base3>>,
+    ) -> Deduction<Base3> {
+        Deduction::try_from_iters(
+            eliminations.iter().map(|&(row, column, candidate)| {
+                (
+                    pos(row, column),
+                    Action::delete_candidate(candidate.try_into().unwrap()),
+                )
+            }),
+            chain_nodes
+                .iter()
+                .map(|node| (node.position, Reason::candidate(node.candidate))),
+        )
+        .unwrap()
+        .with_chain(chain_nodes.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_synthetic_x_chain() {
+        // Chain on candidate 1: r0c0 = r0c4 - r3c4 = r3c7 - r7c7 = r7c1
+        // Elimination: r1c1 sees both r0c0 and r7c1.
+        // r2c2 and r4c1 prevent strong links in block 0 and column 1.
+        let mut grid = Grid::<Base3>::new();
+        for (row, column) in [
+            (0, 0),
+            (0, 4),
+            (3, 4),
+            (3, 7),
+            (7, 7),
+            (7, 1),
+            (1, 1),
+            (2, 2),
+            (4, 1),
+        ] {
+            grid[pos(row, column)] = Cell::with_candidates(candidates(&[1]));
+        }
+
+        let deductions = XChain::default().execute(&grid).unwrap();
+
+        let expected_deductions = chain_deduction(
+            &[(1, 1, 1)],
+            vec![
+                node(0, 0, 1),
+                node(0, 4, 1),
+                node(3, 4, 1),
+                node(3, 7, 1),
+                node(7, 7, 1),
+                node(7, 1, 1),
+            ],
+        );
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_xy_chain() {
+        // Chain: r0c0(1) = r0c0(2) - r0c4(2) = r0c4(3) - r4c4(3) = r4c4(4) - r4c8(4) = r4c8(1)
+        // Elimination: r4c0 and r0c8 see both ends.
+        let mut grid = Grid::<Base3>::new();
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[2, 3]));
+        grid[pos(4, 4)] = Cell::with_candidates(candidates(&[3, 4]));
+        grid[pos(4, 8)] = Cell::with_candidates(candidates(&[1, 4]));
+        grid[pos(4, 0)] = Cell::with_candidates(candidates(&[1, 5, 6]));
+        grid[pos(0, 8)] = Cell::with_candidates(candidates(&[1, 5, 6]));
+
+        let deductions = XyChain::default().execute(&grid).unwrap();
+
+        let expected_deductions = chain_deduction(
+            &[(0, 8, 1), (4, 0, 1)],
+            vec![
+                node(0, 0, 1),
+                node(0, 0, 2),
+                node(0, 4, 2),
+                node(0, 4, 3),
+                node(4, 4, 3),
+                node(4, 4, 4),
+                node(4, 8, 4),
+                node(4, 8, 1),
+            ],
+        );
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let mut grid = Grid::<Base3>::new();
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[2, 3]));
+        grid[pos(4, 4)] = Cell::with_candidates(candidates(&[3, 4]));
+        grid[pos(4, 8)] = Cell::with_candidates(candidates(&[1, 4]));
+        grid[pos(4, 0)] = Cell::with_candidates(candidates(&[1, 5, 6]));
+
+        assert!(
+            XyChain::with_max_length(6)
+                .execute(&grid)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            XyChain::with_max_length(8).execute(&grid).unwrap().count(),
+            1
+        );
+    }
+
+    mod x_chain {
+        use super::*;
+
+        strategy_snapshot_tests!(XChain::default());
+    }
+
+    mod xy_chain {
+        use super::*;
+
+        strategy_snapshot_tests!(XyChain::default());
+    }
+
+    mod alternating_inference_chain {
+        use super::*;
+
+        strategy_snapshot_tests!(AlternatingInferenceChain::default());
+    }
+}
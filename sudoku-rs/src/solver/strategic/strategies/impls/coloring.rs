@@ -0,0 +1,506 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::impls::links::strong_links;
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SimpleColoring;
+impl Strategy for SimpleColoring {
+    fn name(self) -> &'static str {
+        "SimpleColoring"
+    }
+    fn score(self) -> StrategyScore {
+        320
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(Coloring(ColoringType::Simple).execute(grid))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MultiColoring;
+impl Strategy for MultiColoring {
+    fn name(self) -> &'static str {
+        "MultiColoring"
+    }
+    fn score(self) -> StrategyScore {
+        380
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(Coloring(ColoringType::Multi).execute(grid))
+    }
+}
+
+/*
+Logic:
+
+For a single candidate, the strong links (conjugate pairs) of all rows, columns and blocks form a graph.
+Each connected component of this graph is a chain, which can be colored with two alternating colors.
+Exactly one of the two colors is true, i.e. contains the candidate in all of its positions.
+
+Simple coloring:
+- Color wrap: if two positions of the same color see each other, this color must be false.
+  Eliminations: the candidate in all positions of this color.
+- Color trap: if a position outside the chain sees both colors, it can't contain the candidate.
+  Eliminations: the candidate in these positions.
+
+Multi coloring, for two chains `A` and `B`:
+- Color wrap: if a color of `A` sees both colors of `B`, this color of `A` must be false.
+  Eliminations: the candidate in all positions of this color of `A`.
+- Color trap: if a color `a` of `A` sees a color `b` of `B`, at most one of them is true.
+  Therefore either the opposite color of `a` or the opposite color of `b` must be true.
+  Eliminations: the candidate in all positions outside the chains, which see both opposite colors.
+*/
+
+/// An implementation of the [simple coloring](https://www.sudokuwiki.org/Singles_Chains)
+/// and [multi coloring](https://www.sudokuwiki.org/Multi_Colouring) strategies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Coloring(ColoringType);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ColoringType {
+    Simple,
+    Multi,
+}
+
+impl Coloring {
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Deductions<Base> {
+        let Self(coloring_type) = self;
+
+        Value::<Base>::all()
+            .flat_map(|candidate| {
+                let chains = ColorChain::all(grid, candidate);
+
+                match coloring_type {
+                    ColoringType::Simple => chains
+                        .iter()
+                        .flat_map(|chain| {
+                            Self::simple_color_wrap(candidate, chain)
+                                .into_iter()
+                                .chain(Self::simple_color_trap(grid, candidate, chain))
+                        })
+                        .collect_vec(),
+                    ColoringType::Multi => {
+                        chains
+                            .iter()
+                            .permutations(2)
+                            .flat_map(|chain_pair| {
+                                let [chain_a, chain_b] = chain_pair.try_into().unwrap();
+                                Self::multi_color_wrap(candidate, chain_a, chain_b)
+                            })
+                            .chain(chains.iter().tuple_combinations().flat_map(
+                                |(chain_a, chain_b)| {
+                                    Self::multi_color_trap(grid, candidate, chain_a, chain_b)
+                                },
+                            ))
+                            .collect_vec()
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn simple_color_wrap<Base: SudokuBase>(
+        candidate: Value<Base>,
+        chain: &ColorChain<Base>,
+    ) -> Vec<Deduction<Base>> {
+        [0, 1]
+            .into_iter()
+            .filter(|&color| {
+                chain.colors[color]
+                    .iter()
+                    .tuple_combinations()
+                    .any(|(pos_1, pos_2)| pos_1.sees(*pos_2))
+            })
+            .filter_map(|color| {
+                let mut color_classes = [vec![], vec![]];
+                color_classes[1 - color].clone_from(&chain.colors[1 - color]);
+
+                coloring_deduction(candidate, chain.colors[color].clone(), color_classes)
+            })
+            .collect()
+    }
+
+    fn simple_color_trap<Base: SudokuBase>(
+        grid: &Grid<Base>,
+        candidate: Value<Base>,
+        chain: &ColorChain<Base>,
+    ) -> Option<Deduction<Base>> {
+        let positions_to_delete = positions_with_candidate(grid, candidate)
+            .filter(|&pos| {
+                !chain.contains(pos) && chain.color_sees(0, pos) && chain.color_sees(1, pos)
+            })
+            .collect();
+
+        coloring_deduction(candidate, positions_to_delete, chain.colors.clone())
+    }
+
+    fn multi_color_wrap<Base: SudokuBase>(
+        candidate: Value<Base>,
+        chain_a: &ColorChain<Base>,
+        chain_b: &ColorChain<Base>,
+    ) -> Vec<Deduction<Base>> {
+        [0, 1]
+            .into_iter()
+            .filter(|&color_a| {
+                [0, 1]
+                    .into_iter()
+                    .all(|color_b| chain_a.color_sees_color(color_a, chain_b, color_b))
+            })
+            .filter_map(|color_a| {
+                let [color_b_0, color_b_1] = chain_b.colors.clone();
+                let color_classes = [
+                    chain_a.colors[1 - color_a]
+                        .iter()
+                        .copied()
+                        .chain(color_b_0)
+                        .collect(),
+                    color_b_1,
+                ];
+
+                coloring_deduction(candidate, chain_a.colors[color_a].clone(), color_classes)
+            })
+            .collect()
+    }
+
+    fn multi_color_trap<Base: SudokuBase>(
+        grid: &Grid<Base>,
+        candidate: Value<Base>,
+        chain_a: &ColorChain<Base>,
+        chain_b: &ColorChain<Base>,
+    ) -> Vec<Deduction<Base>> {
+        [0, 1]
+            .into_iter()
+            .cartesian_product([0, 1])
+            .filter(|&(color_a, color_b)| chain_a.color_sees_color(color_a, chain_b, color_b))
+            .filter_map(|(color_a, color_b)| {
+                let (opposite_color_a, opposite_color_b) = (1 - color_a, 1 - color_b);
+
+                let positions_to_delete = positions_with_candidate(grid, candidate)
+                    .filter(|&pos| {
+                        !chain_a.contains(pos)
+                            && !chain_b.contains(pos)
+                            && chain_a.color_sees(opposite_color_a, pos)
+                            && chain_b.color_sees(opposite_color_b, pos)
+                    })
+                    .collect();
+
+                // One of the opposite colors is true, the colors which see each other are highlighted secondary.
+                let color_classes = [
+                    chain_a.colors[opposite_color_a]
+                        .iter()
+                        .chain(&chain_b.colors[opposite_color_b])
+                        .copied()
+                        .collect(),
+                    chain_a.colors[color_a]
+                        .iter()
+                        .chain(&chain_b.colors[color_b])
+                        .copied()
+                        .collect(),
+                ];
+
+                coloring_deduction(candidate, positions_to_delete, color_classes)
+            })
+            .collect()
+    }
+}
+
+/// A connected component of strong links for a single candidate, partitioned into two colors.
+/// Either all positions of the first color or all positions of the second color contain the candidate.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct ColorChain<Base: SudokuBase> {
+    colors: [Vec<Position<Base>>; 2],
+}
+
+impl<Base: SudokuBase> ColorChain<Base> {
+    /// All chains of `candidate` in the grid.
+    ///
+    /// Chains which can't be colored consistently are skipped, which is only possible for invalid grids.
+    fn all(grid: &Grid<Base>, candidate: Value<Base>) -> Vec<Self> {
+        let mut adjacent_positions: BTreeMap<Position<Base>, BTreeSet<Position<Base>>> =
+            BTreeMap::new();
+        for strong_link in strong_links(grid, candidate) {
+            for (start, end) in strong_link.orientations() {
+                adjacent_positions.entry(start).or_default().insert(end);
+            }
+        }
+
+        let mut position_to_color: BTreeMap<Position<Base>, usize> = BTreeMap::new();
+        let mut chains = vec![];
+
+        for &start in adjacent_positions.keys() {
+            if position_to_color.contains_key(&start) {
+                continue;
+            }
+
+            let mut chain = Self {
+                colors: [vec![], vec![]],
+            };
+            let mut is_consistent = true;
+            let mut stack = vec![(start, 0)];
+            position_to_color.insert(start, 0);
+
+            while let Some((pos, color)) = stack.pop() {
+                chain.colors[color].push(pos);
+
+                for &adjacent_pos in &adjacent_positions[&pos] {
+                    if let Some(&adjacent_color) = position_to_color.get(&adjacent_pos) {
+                        if adjacent_color == color {
+                            is_consistent = false;
+                        }
+                    } else {
+                        position_to_color.insert(adjacent_pos, 1 - color);
+                        stack.push((adjacent_pos, 1 - color));
+                    }
+                }
+            }
+
+            if is_consistent {
+                chain
+                    .colors
+                    .iter_mut()
+                    .for_each(|positions| positions.sort());
+                chains.push(chain);
+            }
+        }
+
+        chains
+    }
+
+    fn contains(&self, pos: Position<Base>) -> bool {
+        self.colors.iter().any(|positions| positions.contains(&pos))
+    }
+
+    /// Does any position of `color` see `pos`?
+    fn color_sees(&self, color: usize, pos: Position<Base>) -> bool {
+        self.colors[color]
+            .iter()
+            .any(|&color_pos| color_pos.sees(pos))
+    }
+
+    /// Does any position of `color` see any position of `other_color` in the `other` chain?
+    fn color_sees_color(&self, color: usize, other: &Self, other_color: usize) -> bool {
+        other.colors[other_color]
+            .iter()
+            .any(|&other_pos| self.color_sees(color, other_pos))
+    }
+}
+
+fn positions_with_candidate<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    candidate: Value<Base>,
+) -> impl Iterator<Item = Position<Base>> + '_ {
+    Position::<Base>::all().filter(move |&pos| {
+        grid[pos]
+            .candidates()
+            .is_some_and(|candidates| candidates.has(candidate))
+    })
+}
+
+/// Delete `candidate` from `positions_to_delete`.
+/// The positions of both color classes are used as the reason, highlighted in their respective color.
+fn coloring_deduction<Base: SudokuBase>(
+    candidate: Value<Base>,
+    positions_to_delete: Vec<Position<Base>>,
+    [color_1_positions, color_2_positions]: [Vec<Position<Base>>; 2],
+) -> Option<Deduction<Base>> {
+    if positions_to_delete.is_empty() {
+        return None;
+    }
+
+    Some(
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            color_1_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::color_1(candidate)))
+                .chain(
+                    color_2_positions
+                        .into_iter()
+                        .map(|pos| (pos, Reason::color_2(candidate))),
+                ),
+        )
+        .unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::consts::*,
+        solver::strategic::strategies::test_util::{
+            assert_deductions, strategy_snapshot_tests, values_to_candidates,
+        },
+    };
+    use indoc::indoc;
+
+    fn coloring_deduction_1<Base: SudokuBase>(
+        positions_to_delete: impl IntoIterator<Item = (u8, u8)>,
+        color_1_positions: impl IntoIterator<Item = (u8, u8)>,
+        color_2_positions: impl IntoIterator<Item = (u8, u8)>,
+    ) -> Deduction<Base> {
+        let candidate: Value<Base> = 1.try_into().unwrap();
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            color_1_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::color_1(candidate)))
+                .chain(
+                    color_2_positions
+                        .into_iter()
+                        .map(|pos| (pos, Reason::color_2(candidate))),
+                ),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_simple_color_wrap() {
+        // Chain: r0c0 = r0c6 = r6c6 = r6c2 = r2c2
+        // r0c0 and r2c2 share the same color and see each other in block 0.
+        // r1c1 prevents a strong link in block 0.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            100000100
+            010000000
+            001000000
+            000000000
+            000000000
+            000000000
+            001000100
+            000000000
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = SimpleColoring.execute(&grid).unwrap();
+
+        let expected_deductions =
+            coloring_deduction_1(vec![(0, 0), (2, 2), (6, 6)], vec![], vec![(0, 6), (6, 2)]);
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_simple_color_trap() {
+        // Chain: r0c0 = r0c6 = r6c6 = r6c2
+        // r2c2 sees r0c0 in block 0 and r6c2 in column 2, which have opposite colors.
+        // r1c1 and r4c2 prevent strong links in block 0 and column 2.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            100000100
+            010000000
+            001000000
+            000000000
+            001000000
+            000000000
+            001000100
+            000000000
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = SimpleColoring.execute(&grid).unwrap();
+
+        let expected_deductions =
+            coloring_deduction_1(vec![(2, 2)], vec![(0, 0), (6, 6)], vec![(0, 6), (6, 2)]);
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_multi_color_wrap() {
+        // Chain A: r0c0 = r0c6
+        // Chain B: r1c1 = r1c4 = r5c4 = r5c0
+        // r0c0 sees r1c1 in block 0 and r5c0 in column 0, which have opposite colors.
+        // r2c2 and r8c0 prevent strong links in block 0 and column 0.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            100000100
+            010010000
+            001000000
+            000000000
+            000000000
+            100010000
+            000000000
+            000000000
+            100000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = MultiColoring.execute(&grid).unwrap();
+
+        let expected_deductions = coloring_deduction_1(
+            vec![(0, 0)],
+            vec![(0, 6), (1, 1), (5, 4)],
+            vec![(1, 4), (5, 0)],
+        );
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_multi_color_trap() {
+        // Chain A: r0c0 = r0c7
+        // Chain B: r4c0 = r4c7
+        // r0c0 sees r4c0, therefore r0c7 or r4c7 is true: r6c7 can be eliminated.
+        // r0c7 sees r4c7, therefore r0c0 or r4c0 is true: r8c0 can be eliminated.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            100000010
+            000000000
+            000000000
+            000000000
+            100000010
+            000000000
+            000000010
+            000000000
+            100000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = MultiColoring.execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<Base3> = [
+            coloring_deduction_1(vec![(6, 7)], vec![(0, 7), (4, 7)], vec![(0, 0), (4, 0)]),
+            coloring_deduction_1(vec![(8, 0)], vec![(0, 0), (4, 0)], vec![(0, 7), (4, 7)]),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    mod simple_coloring {
+        use super::*;
+
+        strategy_snapshot_tests!(SimpleColoring);
+    }
+
+    mod multi_coloring {
+        use super::*;
+
+        strategy_snapshot_tests!(MultiColoring);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::impls::almost_locked_sets::{
+    AlmostLockedSet, almost_locked_sets, positions_seeing_candidate, smallest_deductions,
+};
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+use itertools::Itertools;
+
+/// The maximal number of candidates of a stem cell.
+///
+/// Each candidate requires its own almost locked set, larger stems are rarely useful.
+const MAX_STEM_CANDIDATES: u8 = 3;
+
+/*
+Logic:
+
+A stem cell with the candidates `c1..cn` and non-overlapping almost locked sets `A1..An` (petals),
+where `Ai` contains `ci` and every cell of `Ai` containing `ci` sees the stem.
+
+The stem is set to some `ci`, which removes `ci` from `Ai` and turns `Ai` into a locked set.
+
+Eliminations: for each candidate `z`, which is not a candidate of the stem and contained in all petals,
+`z` in all cells which see every `z` of all petals.
+
+Only the deductions of the smallest blossoms are reported.
+*/
+
+/// An implementation of the [Death Blossom](https://www.sudokuwiki.org/Death_Blossom) strategy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DeathBlossom;
+
+impl Strategy for DeathBlossom {
+    fn name(self) -> &'static str {
+        "DeathBlossom"
+    }
+
+    fn score(self) -> StrategyScore {
+        650
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let almost_locked_sets = almost_locked_sets(grid);
+
+        let mut sized_deductions = vec![];
+
+        for stem in Position::<Base>::all() {
+            let Some(stem_candidates) = grid[stem]
+                .candidates()
+                .filter(|candidates| (2..=MAX_STEM_CANDIDATES).contains(&candidates.count()))
+            else {
+                continue;
+            };
+
+            let petals = stem_candidates
+                .iter()
+                .map(|candidate| {
+                    almost_locked_sets
+                        .iter()
+                        .filter(|als| {
+                            !als.contains(stem)
+                                && als.candidates().has(candidate)
+                                && als
+                                    .positions_with_candidate(candidate)
+                                    .all(|pos| pos.sees(stem))
+                        })
+                        .collect_vec()
+                })
+                .collect_vec();
+
+            let blossom = Blossom {
+                grid,
+                stem,
+                stem_candidates,
+                petals: &petals,
+            };
+            blossom.search(
+                &mut vec![],
+                Candidates::all().without(stem_candidates),
+                &mut sized_deductions,
+            );
+        }
+
+        Ok(smallest_deductions(sized_deductions))
+    }
+}
+
+struct Blossom<'a, Base: SudokuBase> {
+    grid: &'a Grid<Base>,
+    stem: Position<Base>,
+    stem_candidates: Candidates<Base>,
+    /// The potential petals for each stem candidate.
+    petals: &'a [Vec<&'a AlmostLockedSet<Base>>],
+}
+
+impl<'a, Base: SudokuBase> Blossom<'a, Base> {
+    /// Chooses a petal for each stem candidate in order.
+    ///
+    /// `common_candidates` are the candidates contained in all chosen petals, without the stem candidates.
+    fn search(
+        &self,
+        chosen_petals: &mut Vec<&'a AlmostLockedSet<Base>>,
+        common_candidates: Candidates<Base>,
+        sized_deductions: &mut Vec<(usize, Deduction<Base>)>,
+    ) {
+        let Some(potential_petals) = self.petals.get(chosen_petals.len()) else {
+            self.push_deduction(chosen_petals, common_candidates, sized_deductions);
+            return;
+        };
+
+        for &petal in potential_petals {
+            let common_candidates = common_candidates.intersection(petal.candidates());
+
+            if common_candidates.is_empty()
+                || chosen_petals
+                    .iter()
+                    .any(|chosen_petal| chosen_petal.overlaps(petal))
+            {
+                continue;
+            }
+
+            chosen_petals.push(petal);
+            self.search(chosen_petals, common_candidates, sized_deductions);
+            chosen_petals.pop();
+        }
+    }
+
+    fn push_deduction(
+        &self,
+        petals: &[&AlmostLockedSet<Base>],
+        common_candidates: Candidates<Base>,
+        sized_deductions: &mut Vec<(usize, Deduction<Base>)>,
+    ) {
+        let actions = common_candidates
+            .iter()
+            .flat_map(|z| {
+                positions_seeing_candidate(self.grid, z, petals)
+                    .into_iter()
+                    .map(move |pos| (pos, Action::delete_candidate(z)))
+            })
+            .collect_vec();
+
+        if actions.is_empty() {
+            return;
+        }
+
+        let stem_reason = (
+            self.stem,
+            Reason::colors(self.stem_candidates, Candidates::new()),
+        );
+        let petal_reasons = self.stem_candidates.iter().zip(petals).flat_map(
+            |(candidate, petal): (Value<Base>, _)| {
+                petal.reasons(Candidates::with_single(candidate))
+            },
+        );
+
+        sized_deductions.push((
+            petals.iter().map(|petal| petal.size()).sum(),
+            Deduction::try_from_iters(actions, petal_reasons.chain([stem_reason])).unwrap(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_death_blossom() {
+        let mut grid = Grid::<Base3>::new();
+
+        // Stem with the candidates 1 and 2
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Petal for 1 in row 0
+        grid[pos(0, 4)] = Cell::with_candidates(candidates(&[1, 3]));
+        // Petal for 2 in column 0
+        grid[pos(4, 0)] = Cell::with_candidates(candidates(&[2, 3]));
+        // Sees the candidate 3 of both petals
+        grid[pos(4, 4)] = Cell::with_candidates(candidates(&[3, 5, 6]));
+
+        let deductions = DeathBlossom.execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [(pos(4, 4), Action::delete_candidate(3.try_into().unwrap()))],
+            [
+                (
+                    pos(0, 0),
+                    Reason::colors(candidates(&[1, 2]), candidates(&[])),
+                ),
+                (
+                    pos(0, 4),
+                    Reason::colors(candidates(&[1]), candidates(&[3])),
+                ),
+                (
+                    pos(4, 0),
+                    Reason::colors(candidates(&[2]), candidates(&[3])),
+                ),
+            ],
+        )
+        .unwrap()]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    strategy_snapshot_tests!(DeathBlossom);
+}
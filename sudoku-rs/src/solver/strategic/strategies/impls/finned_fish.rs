@@ -0,0 +1,352 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Coordinate;
+use crate::position::Position;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::deduction::Reason;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use crate::solver::strategic::strategies::impls::x_wing::{Axis, GroupCandidateIndexes};
+use itertools::{Itertools, izip};
+
+/*
+Logic:
+
+A finned fish is a basic fish (see `Fish`), whose base lines contain additional candidates outside of the cover lines: the fins.
+
+For a single candidate:
+- Base set: `n` rows (or columns).
+- Cover set: `n` columns (or rows), which contain all candidates of the base set, except the fins.
+- Fins: the candidates of the base set outside of the cover set. All fins must be located in a single block.
+
+Either one of the fins is true, or the fish without the fins is valid.
+In both cases, the candidate can be eliminated from all cells in the cover lines, which are located in the block of the fins
+and are not part of the base lines.
+
+A sashimi fish is a finned fish, in which a base line contains only a single candidate in the cover set.
+The same logic applies, so both variants are handled by this strategy.
+
+Only X-Wings (n = 2) and Swordfish (n = 3) are searched for.
+*/
+
+/// An implementation of the [finned](https://www.sudokuwiki.org/Finned_X_Wing)
+/// and [sashimi](https://www.sudokuwiki.org/Finned_Swordfish) X-Wing and Swordfish strategies.
+///
+/// The fin cells are reported as `Reason::Fin`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FinnedFish;
+
+impl Strategy for FinnedFish {
+    fn name(self) -> &'static str {
+        "FinnedFish"
+    }
+
+    fn score(self) -> StrategyScore {
+        350
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let candidate_to_group_candidate_indexes = GroupCandidateIndexes::with_grid(grid);
+
+        for size in Self::sizes::<Base>() {
+            let deductions: Deductions<Base> = izip!(
+                Value::<Base>::all(),
+                candidate_to_group_candidate_indexes.iter()
+            )
+            .flat_map(|(candidate, group_candidate_indexes)| {
+                [Axis::Row, Axis::Column]
+                    .into_iter()
+                    .flat_map(move |base_axis| {
+                        find_finned_fish_patterns(group_candidate_indexes, base_axis, size)
+                    })
+                    .filter_map(move |finned_fish_pattern| {
+                        finned_fish_pattern.into_deduction(candidate, group_candidate_indexes)
+                    })
+            })
+            .collect();
+
+            if !deductions.is_empty() {
+                return Ok(deductions);
+            }
+        }
+
+        Ok(Deductions::default())
+    }
+}
+
+impl FinnedFish {
+    const MAX_SIZE: u8 = 3;
+
+    /// All fish sizes which need to be searched for the given base.
+    fn sizes<Base: SudokuBase>() -> impl Iterator<Item = u8> {
+        2..=Self::MAX_SIZE.min(Base::SIDE_LENGTH / 2)
+    }
+}
+
+/// Find all finned fish patterns of exactly `size` base lines in the axis `base_axis`.
+fn find_finned_fish_patterns<Base: SudokuBase>(
+    group_candidate_indexes: &GroupCandidateIndexes<Base>,
+    base_axis: Axis,
+    size: u8,
+) -> Vec<FinnedFishPattern<Base>> {
+    // The fins of a base line are located in a single block, which limits the number of candidates per base line.
+    let max_base_line_count = size + Base::BASE;
+
+    let potential_base_lines: Vec<(Coordinate<Base>, Candidates<Base>)> = group_candidate_indexes
+        .axis(base_axis)
+        .iter_enumerate()
+        .filter(|(_, cover_coordinates)| {
+            (2..=max_base_line_count).contains(&cover_coordinates.count())
+        })
+        .collect();
+
+    let mut finned_fish_patterns = vec![];
+
+    for base_lines in potential_base_lines
+        .iter()
+        .copied()
+        .combinations(usize::from(size))
+    {
+        let all_cover_coordinates = base_lines
+            .iter()
+            .fold(Candidates::new(), |all_cover_coordinates, &(_, line)| {
+                all_cover_coordinates.union(line)
+            });
+
+        // Without fins, this would be a basic fish, which is handled by the `Fish` strategy.
+        if all_cover_coordinates.count() <= size
+            || all_cover_coordinates.count() > max_base_line_count
+        {
+            continue;
+        }
+
+        let base_coordinates: Candidates<Base> = base_lines
+            .iter()
+            .map(|&(base_coordinate, _)| Value::from(base_coordinate))
+            .collect();
+
+        for cover_coordinates in all_cover_coordinates
+            .into_iter()
+            .combinations(usize::from(size))
+        {
+            let cover_coordinates: Candidates<Base> = cover_coordinates.into_iter().collect();
+
+            let fin_positions: Vec<Position<Base>> = base_lines
+                .iter()
+                .flat_map(|&(base_coordinate, line)| {
+                    line.without(cover_coordinates)
+                        .into_iter()
+                        .map(move |fin_coordinate| {
+                            base_axis.coordinates_to_pos(
+                                base_coordinate,
+                                Coordinate::from(fin_coordinate),
+                            )
+                        })
+                })
+                .collect();
+
+            if fin_positions
+                .iter()
+                .map(|fin_position| fin_position.to_block())
+                .all_equal()
+            {
+                finned_fish_patterns.push(FinnedFishPattern {
+                    base_axis,
+                    base_coordinates,
+                    cover_coordinates,
+                    fin_positions,
+                });
+            }
+        }
+    }
+
+    finned_fish_patterns
+}
+
+/// A detected finned fish pattern for a single candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FinnedFishPattern<Base: SudokuBase> {
+    /// The axis of the base lines.
+    base_axis: Axis,
+    /// The coordinates of the base lines in `base_axis`.
+    base_coordinates: Candidates<Base>,
+    /// The coordinates of the cover lines in the opposite axis.
+    cover_coordinates: Candidates<Base>,
+    /// The positions of the fins, all located in the same block.
+    fin_positions: Vec<Position<Base>>,
+}
+
+impl<Base: SudokuBase> FinnedFishPattern<Base> {
+    fn into_deduction(
+        self,
+        candidate: Value<Base>,
+        group_candidate_indexes: &GroupCandidateIndexes<Base>,
+    ) -> Option<Deduction<Base>> {
+        let Self {
+            base_axis,
+            base_coordinates,
+            cover_coordinates,
+            fin_positions,
+        } = self;
+        let cover_axis = base_axis.other();
+        let fin_block = fin_positions.first()?.to_block();
+
+        let positions_to_delete: Vec<_> = cover_coordinates
+            .into_iter()
+            .map(Coordinate::from)
+            .flat_map(|cover_coordinate| {
+                group_candidate_indexes
+                    .axis(cover_axis)
+                    .get(cover_coordinate)
+                    .without(base_coordinates)
+                    .into_iter()
+                    .map(move |base_coordinate_to_delete| {
+                        base_axis.coordinates_to_pos(
+                            Coordinate::from(base_coordinate_to_delete),
+                            cover_coordinate,
+                        )
+                    })
+            })
+            .filter(|pos| pos.to_block() == fin_block)
+            .collect();
+
+        if positions_to_delete.is_empty() {
+            return None;
+        }
+
+        let body_positions =
+            base_coordinates
+                .into_iter()
+                .map(Coordinate::from)
+                .flat_map(|base_coordinate| {
+                    group_candidate_indexes
+                        .axis(base_axis)
+                        .get(base_coordinate)
+                        .intersection(cover_coordinates)
+                        .into_iter()
+                        .map(move |cover_coordinate| {
+                            base_axis.coordinates_to_pos(
+                                base_coordinate,
+                                Coordinate::from(cover_coordinate),
+                            )
+                        })
+                });
+
+        Some(
+            Deduction::try_from_iters(
+                positions_to_delete
+                    .into_iter()
+                    .map(|pos| (pos, Action::delete_candidate(candidate))),
+                body_positions
+                    .map(|pos| (pos, Reason::candidate(candidate)))
+                    .chain(
+                        fin_positions
+                            .into_iter()
+                            .map(|pos| (pos, Reason::fin(candidate))),
+                    ),
+            )
+            .unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::consts::*,
+        solver::strategic::strategies::test_util::{
+            assert_deductions, strategy_snapshot_tests, values_to_candidates,
+        },
+    };
+    use indoc::indoc;
+
+    fn finned_fish_deduction<Base: SudokuBase>(
+        candidate: u8,
+        positions_to_delete: impl IntoIterator<Item = (u8, u8)>,
+        body_positions: impl IntoIterator<Item = (u8, u8)>,
+        fin_positions: impl IntoIterator<Item = (u8, u8)>,
+    ) -> Deduction<Base> {
+        let candidate: Value<Base> = candidate.try_into().unwrap();
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            body_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::candidate(candidate)))
+                .chain(
+                    fin_positions
+                        .into_iter()
+                        .map(|pos| (pos, Reason::fin(candidate))),
+                ),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_finned_x_wing() {
+        // Base rows 1 and 5, cover columns 1 and 6, fin in row 5 column 2.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            000000000
+            010000100
+            000000000
+            000000000
+            010000000
+            011000100
+            000000000
+            010000000
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = FinnedFish.execute(&grid).unwrap();
+
+        let expected_deductions = finned_fish_deduction(
+            1,
+            vec![(4, 1)],
+            vec![(1, 1), (1, 6), (5, 1), (5, 6)],
+            vec![(5, 2)],
+        );
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_sashimi_x_wing() {
+        // Base rows 1 and 5, cover columns 1 and 6.
+        // Row 5 contains the candidate in column 1 only as a fin in column 2, therefore the X-Wing is sashimi.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            000000000
+            010000100
+            000000000
+            000000000
+            010000000
+            001000100
+            000000000
+            010000000
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = FinnedFish.execute(&grid).unwrap();
+
+        let expected_deductions =
+            finned_fish_deduction(1, vec![(4, 1)], vec![(1, 1), (1, 6), (5, 6)], vec![(5, 2)]);
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    strategy_snapshot_tests!(FinnedFish);
+}
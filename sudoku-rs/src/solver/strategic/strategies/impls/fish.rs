@@ -0,0 +1,346 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Coordinate;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::deduction::Reason;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use crate::solver::strategic::strategies::impls::x_wing::{Axis, GroupCandidateIndexes};
+use itertools::izip;
+
+/*
+Logic:
+
+A fish of size `n` generalizes the X-Wing (n = 2) to Swordfish (n = 3), Jellyfish (n = 4) and beyond.
+
+For a single candidate:
+- Base set: `n` rows (or columns), in which the candidate is restricted to at most `n` columns (or rows).
+- Cover set: the union of these columns (or rows). If the cover set contains exactly `n` lines,
+  each cover line must contain the candidate in one of the base lines.
+- Eliminations: the candidate in cover lines, outside the base lines.
+
+Fish of size `n` with base rows are equivalent to fish of size `SIDE_LENGTH - n` with base columns.
+Therefore it is sufficient to search for fish up to size `SIDE_LENGTH / 2` in both directions.
+
+The base lines are combined via a depth-first search, which prunes combinations as soon as the cover set grows larger than `n`.
+*/
+
+/// An implementation of the [basic fish](https://www.sudokuwiki.org/Sword_Fish_Strategy) family of strategies:
+/// X-Wing, Swordfish, Jellyfish and larger fish for bigger sudokus.
+///
+/// Searches for fish of increasing size and returns all deductions of the smallest fish size which makes progress.
+/// Since size 2 finds the same deductions as [`XWing`](super::XWing), the default strategies select only `Fish`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Fish;
+
+impl Strategy for Fish {
+    fn name(self) -> &'static str {
+        "Fish"
+    }
+
+    fn score(self) -> StrategyScore {
+        300
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let candidate_to_group_candidate_indexes = GroupCandidateIndexes::with_grid(grid);
+
+        for size in Self::sizes::<Base>() {
+            let deductions: Deductions<Base> =
+                Self::execute_with_size(&candidate_to_group_candidate_indexes, size).collect();
+
+            if !deductions.is_empty() {
+                return Ok(deductions);
+            }
+        }
+
+        Ok(Deductions::default())
+    }
+}
+
+impl Fish {
+    /// All fish sizes which need to be searched for the given base.
+    fn sizes<Base: SudokuBase>() -> impl Iterator<Item = u8> {
+        2..=(Base::SIDE_LENGTH / 2)
+    }
+
+    fn execute_with_size<Base: SudokuBase>(
+        candidate_to_group_candidate_indexes: &[GroupCandidateIndexes<Base>],
+        size: u8,
+    ) -> impl Iterator<Item = Deduction<Base>> + '_ {
+        izip!(
+            Value::<Base>::all(),
+            candidate_to_group_candidate_indexes.iter()
+        )
+        .flat_map(move |(candidate, group_candidate_indexes)| {
+            [Axis::Row, Axis::Column]
+                .into_iter()
+                .flat_map(move |base_axis| {
+                    find_fish_patterns(group_candidate_indexes, base_axis, size).into_iter()
+                })
+                .filter_map(move |fish_pattern| {
+                    fish_pattern.to_deduction(candidate, group_candidate_indexes)
+                })
+        })
+    }
+}
+
+/// Find all fish patterns of exactly `size` base lines in the axis `base_axis`.
+fn find_fish_patterns<Base: SudokuBase>(
+    group_candidate_indexes: &GroupCandidateIndexes<Base>,
+    base_axis: Axis,
+    size: u8,
+) -> Vec<FishPattern<Base>> {
+    // Lines with a single candidate are hidden singles, lines with more than `size` candidates can't be part of the base set.
+    let potential_base_lines: Vec<(Coordinate<Base>, Candidates<Base>)> = group_candidate_indexes
+        .axis(base_axis)
+        .iter_enumerate()
+        .filter(|(_, cover_coordinates)| (2..=size).contains(&cover_coordinates.count()))
+        .collect();
+
+    let mut fish_patterns = vec![];
+
+    search_base_lines(
+        &potential_base_lines,
+        size,
+        Candidates::new(),
+        Candidates::new(),
+        &mut |base_coordinates, cover_coordinates| {
+            fish_patterns.push(FishPattern {
+                base_axis,
+                base_coordinates,
+                cover_coordinates,
+            });
+        },
+    );
+
+    fish_patterns
+}
+
+/// Depth-first search over all combinations of `size` base lines, whose cover set has exactly `size` lines.
+fn search_base_lines<Base: SudokuBase>(
+    potential_base_lines: &[(Coordinate<Base>, Candidates<Base>)],
+    size: u8,
+    base_coordinates: Candidates<Base>,
+    cover_coordinates: Candidates<Base>,
+    on_fish: &mut impl FnMut(Candidates<Base>, Candidates<Base>),
+) {
+    if base_coordinates.count() == size {
+        // A cover set smaller than the base set would imply an unsolvable sudoku.
+        if cover_coordinates.count() == size {
+            on_fish(base_coordinates, cover_coordinates);
+        }
+        return;
+    }
+
+    for (i, &(base_coordinate, line_cover_coordinates)) in potential_base_lines.iter().enumerate() {
+        let cover_coordinates = cover_coordinates.union(line_cover_coordinates);
+        if cover_coordinates.count() > size {
+            continue;
+        }
+
+        let mut base_coordinates = base_coordinates;
+        base_coordinates.insert(base_coordinate);
+
+        search_base_lines(
+            &potential_base_lines[i + 1..],
+            size,
+            base_coordinates,
+            cover_coordinates,
+            on_fish,
+        );
+    }
+}
+
+/// A detected fish pattern for a single candidate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct FishPattern<Base: SudokuBase> {
+    /// The axis of the base lines.
+    base_axis: Axis,
+    /// The coordinates of the base lines in `base_axis`.
+    base_coordinates: Candidates<Base>,
+    /// The coordinates of the cover lines in the opposite axis.
+    cover_coordinates: Candidates<Base>,
+}
+
+impl<Base: SudokuBase> FishPattern<Base> {
+    fn to_deduction(
+        self,
+        candidate: Value<Base>,
+        group_candidate_indexes: &GroupCandidateIndexes<Base>,
+    ) -> Option<Deduction<Base>> {
+        let Self {
+            base_axis,
+            base_coordinates,
+            cover_coordinates,
+        } = self;
+        let cover_axis = base_axis.other();
+
+        let positions_to_delete: Vec<_> = cover_coordinates
+            .into_iter()
+            .map(Coordinate::from)
+            .flat_map(|cover_coordinate| {
+                group_candidate_indexes
+                    .axis(cover_axis)
+                    .get(cover_coordinate)
+                    .without(base_coordinates)
+                    .into_iter()
+                    .map(move |base_coordinate_to_delete| {
+                        base_axis.coordinates_to_pos(
+                            Coordinate::from(base_coordinate_to_delete),
+                            cover_coordinate,
+                        )
+                    })
+            })
+            .collect();
+
+        if positions_to_delete.is_empty() {
+            return None;
+        }
+
+        let reason_positions =
+            base_coordinates
+                .into_iter()
+                .map(Coordinate::from)
+                .flat_map(|base_coordinate| {
+                    group_candidate_indexes
+                        .axis(base_axis)
+                        .get(base_coordinate)
+                        .intersection(cover_coordinates)
+                        .into_iter()
+                        .map(move |cover_coordinate| {
+                            base_axis.coordinates_to_pos(
+                                base_coordinate,
+                                Coordinate::from(cover_coordinate),
+                            )
+                        })
+                });
+
+        Some(
+            Deduction::try_from_iters(
+                positions_to_delete
+                    .into_iter()
+                    .map(|pos| (pos, Action::delete_candidate(candidate))),
+                reason_positions.map(|pos| (pos, Reason::candidate(candidate))),
+            )
+            .unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::consts::*,
+        solver::strategic::strategies::{
+            XWing,
+            test_util::{assert_deductions, strategy_snapshot_tests, values_to_candidates},
+        },
+    };
+    use indoc::indoc;
+
+    fn fish_deduction<Base: SudokuBase>(
+        candidate: u8,
+        positions_to_delete: impl IntoIterator<Item = (u8, u8)>,
+        fish_positions: impl IntoIterator<Item = (u8, u8)>,
+    ) -> Deduction<Base> {
+        let candidate: Value<Base> = candidate.try_into().unwrap();
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            fish_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::candidate(candidate))),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_swordfish() {
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            100010000
+            000000000
+            101000000
+            000000000
+            000010001
+            000000000
+            000010100
+            000000000
+            100000001"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = Fish.execute(&grid).unwrap();
+
+        let expected_deductions = fish_deduction(
+            1,
+            vec![(2, 0), (6, 4)],
+            vec![(0, 0), (0, 4), (4, 4), (4, 8), (8, 0), (8, 8)],
+        )
+        .into();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    #[test]
+    fn test_synthetic_jellyfish() {
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            101000000
+            000010010
+            001010000
+            000000000
+            000010100
+            000000000
+            100000100
+            000000000
+            100000001"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = Fish.execute(&grid).unwrap();
+
+        let expected_deductions = fish_deduction(
+            1,
+            vec![(1, 4), (8, 0)],
+            vec![
+                (0, 0),
+                (0, 2),
+                (2, 2),
+                (2, 4),
+                (4, 4),
+                (4, 6),
+                (6, 0),
+                (6, 6),
+            ],
+        )
+        .into();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    #[test]
+    fn test_size_2_matches_x_wing() {
+        // Reference: https://www.sudokuwiki.org/X_Wing_Strategy#:~:text=at%20a%20time.-,X%2DWing%20example%201,-%3A%20Load%20Example
+        let grid: Grid<Base3> = "S9B015y2e685w68050609040i022e0e0f0a2e085y050f0a5u090b042e2u2e0i06042c0810012q0f0dd0015w9i102e020a089e03050f9e0d5y042e05d0609i010f095y0e5y0f0a045y0206020166cy669id205".parse().unwrap();
+
+        assert_deductions(
+            &Fish.execute(&grid).unwrap(),
+            &XWing.execute(&grid).unwrap(),
+        );
+    }
+
+    strategy_snapshot_tests!(Fish);
+}
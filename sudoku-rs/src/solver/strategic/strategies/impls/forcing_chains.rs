@@ -0,0 +1,579 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Cell, Value};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, ChainNode, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+/// The default maximum number of propagation steps after an assumption.
+pub const DEFAULT_MAX_FORCING_DEPTH: u8 = 4;
+
+/*
+Logic:
+
+Assume a single candidate to be true and propagate the consequences: place the value, delete it from all
+cells seeing it and repeatedly place all naked and hidden singles. Each round of singles is a single step.
+The placed values of all steps form the forcing tree of the assumption.
+
+Deductions:
+- Nishio forcing: if the assumption leads to a contradiction (a cell without candidates, a value
+  which can't be placed in a group or a duplicate value), the assumed candidate is false.
+- Cell forcing: one of the candidates of a cell is true. Consequences shared by the assumptions
+  of every candidate of the cell are true.
+- Unit forcing: a value is located in one of the positions of a group, which contain the value as a candidate.
+  Consequences shared by the assumptions of every such position are true.
+
+The depth of a deduction is the number of steps needed to reach the contradiction or consequence.
+Only the deductions with the smallest depth are returned, so the simplest explanation is reported first.
+*/
+
+/// A bounded implementation of [Forcing Chains](https://www.sudokuwiki.org/Cell_Forcing_Chains),
+/// including cell, unit and [Nishio](https://www.sudokuwiki.org/Nishio_Forcing_Chains) forcing.
+///
+/// The score increases with the depth, in contrast to the flat score of `BruteForce`.
+/// `strategic::Solver` reports a step with the depth at which its deductions were found,
+/// see [`ForcingChains::execute_with_depth`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ForcingChains {
+    max_depth: u8,
+}
+impl Default for ForcingChains {
+    fn default() -> Self {
+        Self::with_max_depth(DEFAULT_MAX_FORCING_DEPTH)
+    }
+}
+impl ForcingChains {
+    /// Only propagate assumptions for at most `max_depth` steps.
+    pub const fn with_max_depth(max_depth: u8) -> Self {
+        Self { max_depth }
+    }
+
+    pub const fn max_depth(self) -> u8 {
+        self.max_depth
+    }
+
+    /// Whether the deductions of `other` are a subset of the deductions of `self`.
+    pub fn includes(self, other: Self) -> bool {
+        other.max_depth <= self.max_depth
+    }
+
+    /// Like [`Strategy::execute`], but additionally returns the strategy limited to the depth,
+    /// at which the deductions were found. Its score reflects the difficulty of the deductions.
+    ///
+    /// If no deductions are found, `self` is returned unchanged.
+    pub fn execute_with_depth<Base: SudokuBase>(
+        self,
+        grid: &Grid<Base>,
+    ) -> Result<(Self, Deductions<Base>)> {
+        let groups = Grid::<Base>::all_group_positions()
+            .map(Itertools::collect_vec)
+            .collect_vec();
+
+        let propagations: BTreeMap<ChainNode<Base>, Propagation<Base>> = grid
+            .all_candidates_positions()
+            .into_iter()
+            .flat_map(|pos| {
+                grid[pos]
+                    .candidates()
+                    .into_iter()
+                    .flat_map(|candidates| candidates.iter())
+                    .map(move |candidate| ChainNode::new(pos, candidate))
+            })
+            .map(|assumption| {
+                (
+                    assumption,
+                    Propagation::new(grid, &groups, assumption, self.max_depth),
+                )
+            })
+            .collect();
+
+        let forcings = Self::forcings(grid, &groups);
+
+        for depth in 0..=self.max_depth {
+            // The same actions are often reached from multiple assumptions,
+            // only the deduction with the fewest reasons is reported.
+            let mut simplest_deductions: BTreeMap<_, Deduction<Base>> = BTreeMap::new();
+            for deduction in
+                Self::nishio_deductions(&propagations, depth).chain(forcings.iter().filter_map(
+                    |assumptions| Self::forcing_deduction(grid, &propagations, assumptions, depth),
+                ))
+            {
+                match simplest_deductions.entry(deduction.actions.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(deduction);
+                    }
+                    Entry::Occupied(mut entry) => {
+                        if deduction.reasons.len() < entry.get().reasons.len() {
+                            entry.insert(deduction);
+                        }
+                    }
+                }
+            }
+
+            if !simplest_deductions.is_empty() {
+                return Ok((
+                    Self::with_max_depth(depth),
+                    simplest_deductions.into_values().collect(),
+                ));
+            }
+        }
+
+        Ok((self, Deductions::default()))
+    }
+}
+impl Strategy for ForcingChains {
+    fn name(self) -> &'static str {
+        "ForcingChains"
+    }
+    /// Scores by the maximum depth, which is the depth of the deductions
+    /// for strategies returned by [`ForcingChains::execute_with_depth`].
+    fn score(self) -> StrategyScore {
+        700 + 100 * StrategyScore::from(self.max_depth)
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let (_, deductions) = self.execute_with_depth(grid)?;
+        Ok(deductions)
+    }
+}
+
+impl ForcingChains {
+    /// All sets of assumptions, of which exactly one is true:
+    /// the candidates of a cell and the positions of a value inside a group.
+    fn forcings<Base: SudokuBase>(
+        grid: &Grid<Base>,
+        groups: &[Vec<Position<Base>>],
+    ) -> BTreeSet<Vec<ChainNode<Base>>> {
+        let cell_forcings = grid.all_candidates_positions().into_iter().map(|pos| {
+            grid[pos]
+                .candidates()
+                .into_iter()
+                .flat_map(|candidates| candidates.iter())
+                .map(|candidate| ChainNode::new(pos, candidate))
+                .collect_vec()
+        });
+
+        let unit_forcings = groups.iter().flat_map(|group| {
+            Value::<Base>::all()
+                .filter(|&value| !group.iter().any(|&pos| grid[pos].value() == Some(value)))
+                .map(|value| {
+                    group
+                        .iter()
+                        .filter(|&&pos| {
+                            grid[pos]
+                                .candidates()
+                                .is_some_and(|candidates| candidates.has(value))
+                        })
+                        .map(|&pos| ChainNode::new(pos, value))
+                        .collect_vec()
+                })
+        });
+
+        cell_forcings
+            .chain(unit_forcings)
+            .filter(|assumptions| assumptions.len() >= 2)
+            .collect()
+    }
+
+    fn nishio_deductions<Base: SudokuBase>(
+        propagations: &BTreeMap<ChainNode<Base>, Propagation<Base>>,
+        depth: u8,
+    ) -> impl Iterator<Item = Deduction<Base>> {
+        propagations
+            .iter()
+            .filter(move |(_, propagation)| propagation.is_contradiction(depth))
+            .map(move |(&assumption, propagation)| {
+                Deduction::try_from_iters(
+                    [(
+                        assumption.position,
+                        Action::delete_candidate(assumption.candidate),
+                    )],
+                    propagation.reasons(assumption, depth),
+                )
+                .unwrap()
+            })
+    }
+
+    fn forcing_deduction<Base: SudokuBase>(
+        grid: &Grid<Base>,
+        propagations: &BTreeMap<ChainNode<Base>, Propagation<Base>>,
+        assumptions: &[ChainNode<Base>],
+        depth: u8,
+    ) -> Option<Deduction<Base>> {
+        let branches = assumptions
+            .iter()
+            .map(|assumption| &propagations[assumption])
+            .collect_vec();
+
+        // Contradicting assumptions are reported by Nishio forcing.
+        if branches
+            .iter()
+            .any(|propagation| propagation.is_contradiction(depth))
+        {
+            return None;
+        }
+
+        let common_consequences = |consequences: fn(&Propagation<Base>, u8) -> BTreeSet<_>| {
+            branches
+                .iter()
+                .map(|propagation| consequences(propagation, depth))
+                .reduce(|common, consequences| &common & &consequences)
+                .unwrap_or_default()
+        };
+        let placements = common_consequences(Propagation::placements);
+        let eliminations = common_consequences(Propagation::eliminations);
+
+        let placement_positions: BTreeSet<Position<Base>> =
+            placements.iter().map(|node| node.position).collect();
+
+        let actions = placements
+            .iter()
+            .map(|node| (node.position, Action::set_value(node.candidate)))
+            .chain(
+                eliminations
+                    .iter()
+                    .filter(|node| {
+                        !placement_positions.contains(&node.position)
+                            && grid[node.position]
+                                .candidates()
+                                .is_some_and(|candidates| candidates.has(node.candidate))
+                    })
+                    .map(|node| (node.position, Action::delete_candidate(node.candidate))),
+            )
+            .collect_vec();
+
+        if actions.is_empty() {
+            return None;
+        }
+
+        Some(
+            Deduction::try_from_iters(
+                actions,
+                assumptions
+                    .iter()
+                    .zip(branches)
+                    .flat_map(|(&assumption, propagation)| propagation.reasons(assumption, depth)),
+            )
+            .unwrap(),
+        )
+    }
+}
+
+/// The consequences of assuming a single candidate to be true.
+#[derive(Debug, Clone)]
+struct Propagation<Base: SudokuBase> {
+    /// The placed values with the step in which they were placed, excluding the assumption itself.
+    placements: BTreeMap<ChainNode<Base>, u8>,
+    /// The deleted candidates with the step in which they were deleted.
+    eliminations: BTreeMap<ChainNode<Base>, u8>,
+    /// The step in which a contradiction was found.
+    contradiction_step: Option<u8>,
+}
+
+impl<Base: SudokuBase> Propagation<Base> {
+    fn new(
+        grid: &Grid<Base>,
+        groups: &[Vec<Position<Base>>],
+        assumption: ChainNode<Base>,
+        max_depth: u8,
+    ) -> Self {
+        let mut this = Self {
+            placements: BTreeMap::new(),
+            eliminations: BTreeMap::new(),
+            contradiction_step: None,
+        };
+
+        let mut branch = grid.clone();
+        this.place(&mut branch, assumption, 0);
+
+        for step in 0..=max_depth {
+            if step > 0 {
+                let singles = Self::singles(&branch, groups);
+                if singles.is_empty() {
+                    break;
+                }
+                for single in singles {
+                    // An earlier single of this step could have removed the candidate,
+                    // which results in a contradiction below.
+                    if branch[single.position]
+                        .candidates()
+                        .is_some_and(|candidates| candidates.has(single.candidate))
+                    {
+                        this.place(&mut branch, single, step);
+                        this.placements.insert(single, step);
+                    }
+                }
+            }
+
+            if Self::is_contradiction_in(grid, &branch, groups) {
+                this.contradiction_step = Some(step);
+                break;
+            }
+        }
+
+        this
+    }
+
+    fn place(&mut self, branch: &mut Grid<Base>, node: ChainNode<Base>, step: u8) {
+        let ChainNode {
+            position,
+            candidate,
+        } = node;
+
+        let other_candidates = branch[position]
+            .candidates()
+            .unwrap_or_default()
+            .without(Candidates::with_single(candidate));
+        let eliminated_peers = Position::<Base>::all().filter(|&pos| {
+            pos.sees(position)
+                && branch[pos]
+                    .candidates()
+                    .is_some_and(|candidates| candidates.has(candidate))
+        });
+
+        for eliminated in other_candidates
+            .iter()
+            .map(|other_candidate| ChainNode::new(position, other_candidate))
+            .chain(eliminated_peers.map(|pos| ChainNode::new(pos, candidate)))
+        {
+            self.eliminations.entry(eliminated).or_insert(step);
+        }
+
+        branch[position] = Cell::with_value(candidate, false);
+        branch.update_direct_candidates_for_new_value(position, candidate);
+    }
+
+    /// All naked and hidden singles of `branch`.
+    fn singles(branch: &Grid<Base>, groups: &[Vec<Position<Base>>]) -> BTreeSet<ChainNode<Base>> {
+        let naked_singles = branch
+            .all_candidates_positions()
+            .into_iter()
+            .filter_map(|pos| {
+                let candidate = branch[pos].candidates()?.to_single()?;
+                Some(ChainNode::new(pos, candidate))
+            });
+
+        let hidden_singles = groups.iter().flat_map(|group| {
+            let values = group
+                .iter()
+                .filter_map(|&pos| branch[pos].value())
+                .collect::<Candidates<Base>>();
+
+            Candidates::<Base>::all()
+                .without(values)
+                .iter()
+                .filter_map(|value| {
+                    let pos = group
+                        .iter()
+                        .filter(|&&pos| {
+                            branch[pos]
+                                .candidates()
+                                .is_some_and(|candidates| candidates.has(value))
+                        })
+                        .exactly_one()
+                        .ok()?;
+                    Some(ChainNode::new(*pos, value))
+                })
+        });
+
+        naked_singles.chain(hidden_singles).collect()
+    }
+
+    /// Compared to `grid`, `branch` contains a cell without candidates,
+    /// a group in which a value can't be placed anymore or a duplicate value.
+    ///
+    /// Only changes are considered, so that an incomplete `grid` (e.g. with cells without candidates) is supported.
+    fn is_contradiction_in(
+        grid: &Grid<Base>,
+        branch: &Grid<Base>,
+        groups: &[Vec<Position<Base>>],
+    ) -> bool {
+        let available_values = |grid: &Grid<Base>, group: &[Position<Base>]| {
+            group
+                .iter()
+                .map(|&pos| grid[pos].to_candidates())
+                .fold(Candidates::new(), Candidates::union)
+        };
+
+        grid.all_candidates_positions().into_iter().any(|pos| {
+            !grid[pos].to_candidates().is_empty()
+                && branch[pos]
+                    .candidates()
+                    .is_some_and(|candidates| candidates.is_empty())
+        }) || groups.iter().any(|group| {
+            !available_values(grid, group)
+                .without(available_values(branch, group))
+                .is_empty()
+        }) || (!grid.has_value_conflict() && branch.has_value_conflict())
+    }
+
+    fn is_contradiction(&self, depth: u8) -> bool {
+        self.contradiction_step.is_some_and(|step| step <= depth)
+    }
+
+    fn placements(&self, depth: u8) -> BTreeSet<ChainNode<Base>> {
+        Self::consequences(&self.placements, depth)
+    }
+
+    fn eliminations(&self, depth: u8) -> BTreeSet<ChainNode<Base>> {
+        Self::consequences(&self.eliminations, depth)
+    }
+
+    fn consequences(
+        consequences: &BTreeMap<ChainNode<Base>, u8>,
+        depth: u8,
+    ) -> BTreeSet<ChainNode<Base>> {
+        consequences
+            .iter()
+            .filter(|&(_, &step)| step <= depth)
+            .map(|(&node, _)| node)
+            .collect()
+    }
+
+    /// The forcing tree up to `depth`: the assumption is highlighted as color 1,
+    /// the values placed as a consequence as color 2.
+    fn reasons(
+        &self,
+        assumption: ChainNode<Base>,
+        depth: u8,
+    ) -> impl Iterator<Item = (Position<Base>, Reason<Base>)> {
+        std::iter::once((assumption.position, Reason::color_1(assumption.candidate))).chain(
+            self.placements(depth)
+                .into_iter()
+                .map(|node| (node.position, Reason::color_2(node.candidate))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::samples;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn base_2_grid(candidates: &[((u8, u8), &[u8])]) -> Grid<Base2> {
+        let values = [[1, 2, 3, 4], [3, 4, 1, 2], [2, 1, 4, 3], [4, 3, 2, 1]];
+        let mut grid = Grid::<Base2>::new();
+        for (pos, value) in Position::<Base2>::all().zip(values.into_iter().flatten()) {
+            grid[pos] = Cell::with_value(value.try_into().unwrap(), false);
+        }
+        for &(pos, pos_candidates) in candidates {
+            grid[pos.try_into().unwrap()] = Cell::with_candidates(
+                pos_candidates
+                    .iter()
+                    .map(|&candidate| Value::try_from(candidate).unwrap())
+                    .collect(),
+            );
+        }
+        grid
+    }
+
+    #[test]
+    fn test_nishio() {
+        let pos = |row: u8, column: u8| Position::<Base2>::try_from((row, column)).unwrap();
+
+        // 2 is already placed in column 0 and 1 in column 1.
+        let grid = base_2_grid(&[((0, 0), &[1, 2]), ((0, 1), &[1, 2])]);
+
+        let deductions = ForcingChains::default().execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<Base2> = [
+            Deduction::try_from_iters(
+                [(pos(0, 0), Action::delete_candidate(2.try_into().unwrap()))],
+                [(pos(0, 0), Reason::color_1(2.try_into().unwrap()))],
+            )
+            .unwrap(),
+            Deduction::try_from_iters(
+                [(pos(0, 1), Action::delete_candidate(1.try_into().unwrap()))],
+                [(pos(0, 1), Reason::color_1(1.try_into().unwrap()))],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+
+        // The contradictions are found directly after placing the assumptions.
+        let (found, deductions_with_depth) =
+            ForcingChains::default().execute_with_depth(&grid).unwrap();
+        assert_eq!(found, ForcingChains::with_max_depth(0));
+        assert!(found.score() < ForcingChains::default().score());
+        assert_deductions(&deductions_with_depth, &expected_deductions);
+    }
+
+    #[test]
+    fn test_simplest_reasons() {
+        let pos = |row: u8, column: u8| Position::<Base2>::try_from((row, column)).unwrap();
+
+        // The extra candidate 4 is eliminated by the cell forcing of all other cells,
+        // but only the contradiction of the candidate itself is reported.
+        let grid = base_2_grid(&[
+            ((0, 0), &[1, 2]),
+            ((0, 1), &[1, 2]),
+            ((2, 0), &[1, 2]),
+            ((2, 1), &[1, 2, 4]),
+        ]);
+
+        let deductions = ForcingChains::default().execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [(pos(2, 1), Action::delete_candidate(4.try_into().unwrap()))],
+            [(pos(2, 1), Reason::color_1(4.try_into().unwrap()))],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_propagation_depth() {
+        let mut grid = samples::base_3().into_iter().nth(6).unwrap();
+        grid.set_all_direct_candidates();
+        let groups = Grid::<Base3>::all_group_positions()
+            .map(Itertools::collect_vec)
+            .collect_vec();
+        let assumption = ChainNode::new((7, 1).try_into().unwrap(), 7.try_into().unwrap());
+
+        let propagation = Propagation::new(&grid, &groups, assumption, 1);
+        assert_eq!(propagation.contradiction_step, None);
+
+        let propagation = Propagation::new(&grid, &groups, assumption, 2);
+        assert_eq!(propagation.contradiction_step, Some(2));
+        assert!(!propagation.is_contradiction(1));
+        assert!(propagation.is_contradiction(2));
+    }
+
+    #[test]
+    fn test_score_scales_with_depth() {
+        assert!(
+            ForcingChains::with_max_depth(2).score() < ForcingChains::with_max_depth(3).score()
+        );
+        assert_eq!(
+            ForcingChains::default(),
+            ForcingChains::with_max_depth(DEFAULT_MAX_FORCING_DEPTH)
+        );
+        assert!(ForcingChains::default().includes(ForcingChains::with_max_depth(1)));
+        assert!(!ForcingChains::with_max_depth(1).includes(ForcingChains::default()));
+    }
+
+    #[test]
+    fn test_execute_with_depth_without_deductions() {
+        let grid = base_2_grid(&[]);
+
+        let (found, deductions) = ForcingChains::default().execute_with_depth(&grid).unwrap();
+
+        assert_eq!(found, ForcingChains::default());
+        assert!(deductions.is_empty());
+    }
+
+    strategy_snapshot_tests!(ForcingChains::default());
+}
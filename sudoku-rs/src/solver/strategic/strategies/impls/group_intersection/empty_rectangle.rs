@@ -0,0 +1,249 @@
+use itertools::{Itertools, izip};
+
+use crate::base::SudokuBase;
+use crate::cell::{Candidates, Value};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::{BlockCoordinate, Coordinate, GroupCoordinate, Position};
+use crate::solver::strategic::deduction::{Action, ChainNode, Deduction, Deductions, Link, Reason};
+use crate::solver::strategic::strategies::impls::group_intersection::GroupCandidateIndexes;
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+/*
+Logic:
+
+An empty rectangle (ER) is a block, in which all cells containing a candidate
+are located in a single row `r` and a single column `c` of the block, but not all in one of them.
+Therefore the candidate is set inside the block either in `r` or in `c`.
+
+A strong link of the candidate in a column outside of the block connects `(r, c2)` with `(r2, c2)`,
+where `r2` is a row outside of the block:
+- if `(r2, c2)` is true, `(r2, c)` is false, because they share the row `r2`.
+- if `(r, c2)` is true, the candidate is not set in `r` inside the block, therefore it is set in `c`.
+  `(r2, c)` sees column `c` and is false.
+
+Eliminations: the candidate in `(r2, c)`.
+
+Analog for a strong link in a row, with rows and columns swapped.
+*/
+
+/// An implementation of the [Empty Rectangle](https://www.sudokuwiki.org/Empty_Rectangles) strategy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EmptyRectangle;
+
+impl Strategy for EmptyRectangle {
+    fn name(self) -> &'static str {
+        "EmptyRectangle"
+    }
+
+    fn score(self) -> StrategyScore {
+        145
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let candidate_to_group_candidate_indexes = GroupCandidateIndexes::with_grid(grid);
+
+        Ok(
+            izip!(Value::<Base>::all(), &candidate_to_group_candidate_indexes)
+                .flat_map(|(candidate, group_candidate_indexes)| {
+                    Coordinate::<Base>::all().flat_map(move |block| {
+                        empty_rectangle_deductions(group_candidate_indexes, candidate, block)
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+/// All deductions of the empty rectangles of `candidate` in `block`.
+fn empty_rectangle_deductions<Base: SudokuBase>(
+    group_candidate_indexes: &GroupCandidateIndexes<Base>,
+    candidate: Value<Base>,
+    block: Coordinate<Base>,
+) -> Vec<Deduction<Base>> {
+    let block_indexes = group_candidate_indexes.row_major_blocks.get(block);
+
+    // Candidates confined to a single block segment are handled by the group intersection strategies.
+    if block_indexes.count() < 2
+        || block_indexes.block_segmentation().is_some()
+        || group_candidate_indexes
+            .column_major_blocks
+            .get(block)
+            .block_segmentation()
+            .is_some()
+    {
+        return vec![];
+    }
+
+    let block_positions = izip!(Coordinate::<Base>::all(), Position::block(block))
+        .filter(|&(block_index, _)| block_indexes.has(block_index))
+        .map(|(_, pos)| pos)
+        .collect_vec();
+
+    let (block_row, block_column) = block.to_block_row_and_column();
+    let is_in_block_row = |coordinate| BlockCoordinate::round_down(coordinate) == block_row;
+    let is_in_block_column = |coordinate| BlockCoordinate::round_down(coordinate) == block_column;
+
+    let mut deductions = vec![];
+
+    for (row, column) in Coordinate::<Base>::all()
+        .filter(|&row| is_in_block_row(row))
+        .cartesian_product(
+            Coordinate::<Base>::all()
+                .filter(|&column| is_in_block_column(column))
+                .collect_vec(),
+        )
+    {
+        if !block_positions
+            .iter()
+            .all(|pos| pos.to_row() == row || pos.to_column() == column)
+        {
+            continue;
+        }
+
+        let reasons = block_positions
+            .iter()
+            .map(|&pos| (pos, Reason::candidate(candidate)))
+            .collect_vec();
+
+        // Strong links in a column outside of the block, starting in `row`.
+        for link_column in Coordinate::<Base>::all().filter(|&column| !is_in_block_column(column)) {
+            let Some(other_row) =
+                linked_coordinate(group_candidate_indexes.columns.get(link_column), row)
+                    .filter(|&other_row| !is_in_block_row(other_row))
+            else {
+                continue;
+            };
+            if !group_candidate_indexes.rows.get(other_row).has(column) {
+                continue;
+            }
+
+            let link = [(row, link_column), (other_row, link_column)].map(Position::from);
+            deductions.push(empty_rectangle_deduction(
+                (other_row, column).into(),
+                candidate,
+                block,
+                &reasons,
+                link,
+            ));
+        }
+
+        // Strong links in a row outside of the block, starting in `column`.
+        for link_row in Coordinate::<Base>::all().filter(|&row| !is_in_block_row(row)) {
+            let Some(other_column) =
+                linked_coordinate(group_candidate_indexes.rows.get(link_row), column)
+                    .filter(|&other_column| !is_in_block_column(other_column))
+            else {
+                continue;
+            };
+            if !group_candidate_indexes.columns.get(other_column).has(row) {
+                continue;
+            }
+
+            let link = [(link_row, column), (link_row, other_column)].map(Position::from);
+            deductions.push(empty_rectangle_deduction(
+                (row, other_column).into(),
+                candidate,
+                block,
+                &reasons,
+                link,
+            ));
+        }
+    }
+
+    deductions
+}
+
+/// The elimination of `candidate` in `target`, caused by the empty rectangle in `block` and a strong link.
+fn empty_rectangle_deduction<Base: SudokuBase>(
+    target: Position<Base>,
+    candidate: Value<Base>,
+    block: Coordinate<Base>,
+    block_reasons: &[(Position<Base>, Reason<Base>)],
+    [link_start, link_end]: [Position<Base>; 2],
+) -> Deduction<Base> {
+    Deduction::try_from_iters(
+        [(target, Action::delete_candidate(candidate))],
+        block_reasons
+            .iter()
+            .copied()
+            .chain([link_start, link_end].map(|pos| (pos, Reason::candidate(candidate)))),
+    )
+    .unwrap()
+    .with_group(GroupCoordinate::Block(block))
+    .with_link(Link::strong(
+        ChainNode::new(link_start, candidate),
+        ChainNode::new(link_end, candidate),
+    ))
+}
+
+/// If the candidate forms a strong link in the group, which contains `start`, returns the other end of the link.
+fn linked_coordinate<Base: SudokuBase>(
+    group_indexes: Candidates<Base>,
+    start: Coordinate<Base>,
+) -> Option<Coordinate<Base>> {
+    if group_indexes.count() != 2 || !group_indexes.has(start) {
+        return None;
+    }
+
+    let mut other_indexes = group_indexes;
+    other_indexes.delete(start);
+    other_indexes.to_single().map(Coordinate::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_empty_rectangle() {
+        let mut grid = Grid::<Base3>::new();
+
+        let candidate: Value<Base3> = 1.try_into().unwrap();
+        let candidates = Candidates::<Base3>::try_from(vec![1, 2]).unwrap();
+        let other_candidates = Candidates::<Base3>::try_from(vec![2, 3]).unwrap();
+
+        for pos in Position::<Base3>::all() {
+            grid[pos] = Cell::with_candidates(other_candidates);
+        }
+        // Empty rectangle in block 0, row 1 and column 1
+        for pos in [pos(1, 0), pos(1, 1), pos(2, 1)] {
+            grid[pos] = Cell::with_candidates(candidates);
+        }
+        // Strong link in column 5
+        grid[pos(1, 5)] = Cell::with_candidates(candidates);
+        grid[pos(7, 5)] = Cell::with_candidates(candidates);
+        // Target cell in column 1, and other cells of row 7
+        grid[pos(7, 1)] = Cell::with_candidates(candidates);
+        grid[pos(7, 8)] = Cell::with_candidates(candidates);
+
+        let deductions = EmptyRectangle.execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [(pos(7, 1), Action::delete_candidate(candidate))],
+            [pos(1, 0), pos(1, 1), pos(2, 1), pos(1, 5), pos(7, 5)]
+                .map(|pos| (pos, Reason::candidate(candidate))),
+        )
+        .unwrap()
+        .with_group(GroupCoordinate::Block(0.try_into().unwrap()))
+        .with_link(Link::strong(
+            ChainNode::new(pos(1, 5), candidate),
+            ChainNode::new(pos(7, 5), candidate),
+        ))]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    strategy_snapshot_tests!(EmptyRectangle);
+}
@@ -9,6 +9,12 @@ use crate::position::{BlockSegment, CellOrder, Coordinate, Position};
 use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
 use crate::solver::strategic::strategies::{Strategy, StrategyScore};
 
+pub use empty_rectangle::EmptyRectangle;
+pub use sue_de_coq::SueDeCoq;
+
+mod empty_rectangle;
+mod sue_de_coq;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct GroupIntersectionBlockToAxis;
 impl Strategy for GroupIntersectionBlockToAxis {
@@ -234,7 +240,8 @@ impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
                     .map(Coordinate::from)
                     .map(|block_index| (block_segment.block_position(block_index), reason)),
             )
-            .unwrap(),
+            .unwrap()
+            .with_group(block_segment.block_group()),
             GroupIntersectionType::AxisToBlock => Deduction::try_from_iters(
                 block_candidate_positions
                     .without(block_segment.block_mask())
@@ -247,7 +254,8 @@ impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
                     .map(Coordinate::from)
                     .map(|axis_index| (block_segment.axis_position(axis_index), reason)),
             )
-            .unwrap(),
+            .unwrap()
+            .with_group(block_segment.axis_group()),
         })
     }
 }
@@ -256,12 +264,14 @@ impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
 mod tests {
     use super::*;
     use crate::base::consts::*;
+    use crate::position::GroupCoordinate;
     use indoc::indoc;
 
     fn expected_deduction<Base: SudokuBase>(
         candidate: u8,
         action_positions: Vec<(u8, u8)>,
         reason_positions: Vec<(u8, u8)>,
+        group: GroupCoordinate<Base>,
     ) -> Deduction<Base> {
         let candidate: Value<Base> = candidate.try_into().unwrap();
         Deduction::try_from_iters(
@@ -273,6 +283,11 @@ mod tests {
                 .map(|pos| (pos, Reason::candidate(candidate))),
         )
         .unwrap()
+        .with_group(group)
+    }
+
+    fn coordinate<Base: SudokuBase>(coordinate: u8) -> Coordinate<Base> {
+        coordinate.try_into().unwrap()
     }
 
     mod execute {
@@ -315,7 +330,8 @@ mod tests {
                         ((3, 3), Reason::candidate(1.try_into().unwrap())),
                     ],
                 )
-                .unwrap();
+                .unwrap()
+                .with_group(GroupCoordinate::Block(3.try_into().unwrap()));
                 let expected_deduction_row_to_block = Deduction::try_from_iters(
                     vec![
                         ((3, 0), Action::delete_candidate(1.try_into().unwrap())),
@@ -326,7 +342,8 @@ mod tests {
                         ((2, 1), Reason::candidate(1.try_into().unwrap())),
                     ],
                 )
-                .unwrap();
+                .unwrap()
+                .with_group(GroupCoordinate::Row(2.try_into().unwrap()));
 
                 let deductions = GroupIntersection(GroupIntersectionTypeFilter::Both)
                     .execute(&grid)
@@ -375,11 +392,36 @@ mod tests {
                         .unwrap();
 
                     let expected_deductions = vec![
-                        expected_deduction(3, vec![(1, 0), (1, 1), (1, 2)], vec![(1, 6), (1, 8)]),
-                        expected_deduction(6, vec![(2, 2)], vec![(2, 4), (2, 5)]),
-                        expected_deduction(9, vec![(4, 4), (4, 6), (4, 8)], vec![(4, 1), (4, 2)]),
-                        expected_deduction(2, vec![(6, 1)], vec![(6, 3), (6, 4)]),
-                        expected_deduction(8, vec![(6, 1), (6, 6)], vec![(6, 3), (6, 5)]),
+                        expected_deduction(
+                            3,
+                            vec![(1, 0), (1, 1), (1, 2)],
+                            vec![(1, 6), (1, 8)],
+                            GroupCoordinate::Block(coordinate(2)),
+                        ),
+                        expected_deduction(
+                            6,
+                            vec![(2, 2)],
+                            vec![(2, 4), (2, 5)],
+                            GroupCoordinate::Block(coordinate(1)),
+                        ),
+                        expected_deduction(
+                            9,
+                            vec![(4, 4), (4, 6), (4, 8)],
+                            vec![(4, 1), (4, 2)],
+                            GroupCoordinate::Block(coordinate(3)),
+                        ),
+                        expected_deduction(
+                            2,
+                            vec![(6, 1)],
+                            vec![(6, 3), (6, 4)],
+                            GroupCoordinate::Block(coordinate(7)),
+                        ),
+                        expected_deduction(
+                            8,
+                            vec![(6, 1), (6, 6)],
+                            vec![(6, 3), (6, 5)],
+                            GroupCoordinate::Block(coordinate(7)),
+                        ),
                     ]
                     .into_iter()
                     .collect();
@@ -402,15 +444,60 @@ mod tests {
                         .unwrap();
 
                     let expected_deductions = vec![
-                        expected_deduction(8, vec![(0, 7), (1, 7), (2, 7)], vec![(3, 7), (5, 7)]),
-                        expected_deduction(7, vec![(1, 5), (7, 5)], vec![(3, 5), (5, 5)]),
-                        expected_deduction(2, vec![(1, 6), (1, 7), (1, 8)], vec![(1, 3), (1, 5)]),
-                        expected_deduction(6, vec![(1, 6), (2, 6)], vec![(3, 6), (5, 6)]),
-                        expected_deduction(7, vec![(2, 1)], vec![(3, 1), (5, 1)]),
-                        expected_deduction(8, vec![(4, 0), (4, 2)], vec![(4, 3), (4, 4)]),
-                        expected_deduction(4, vec![(6, 1), (6, 2), (6, 4)], vec![(6, 6), (6, 7)]),
-                        expected_deduction(1, vec![(6, 2)], vec![(3, 2), (5, 2)]),
-                        expected_deduction(7, vec![(6, 4)], vec![(6, 6), (6, 8)]),
+                        expected_deduction(
+                            8,
+                            vec![(0, 7), (1, 7), (2, 7)],
+                            vec![(3, 7), (5, 7)],
+                            GroupCoordinate::Block(coordinate(5)),
+                        ),
+                        expected_deduction(
+                            7,
+                            vec![(1, 5), (7, 5)],
+                            vec![(3, 5), (5, 5)],
+                            GroupCoordinate::Block(coordinate(4)),
+                        ),
+                        expected_deduction(
+                            2,
+                            vec![(1, 6), (1, 7), (1, 8)],
+                            vec![(1, 3), (1, 5)],
+                            GroupCoordinate::Block(coordinate(1)),
+                        ),
+                        expected_deduction(
+                            6,
+                            vec![(1, 6), (2, 6)],
+                            vec![(3, 6), (5, 6)],
+                            GroupCoordinate::Block(coordinate(5)),
+                        ),
+                        expected_deduction(
+                            7,
+                            vec![(2, 1)],
+                            vec![(3, 1), (5, 1)],
+                            GroupCoordinate::Block(coordinate(3)),
+                        ),
+                        expected_deduction(
+                            8,
+                            vec![(4, 0), (4, 2)],
+                            vec![(4, 3), (4, 4)],
+                            GroupCoordinate::Block(coordinate(4)),
+                        ),
+                        expected_deduction(
+                            4,
+                            vec![(6, 1), (6, 2), (6, 4)],
+                            vec![(6, 6), (6, 7)],
+                            GroupCoordinate::Block(coordinate(8)),
+                        ),
+                        expected_deduction(
+                            1,
+                            vec![(6, 2)],
+                            vec![(3, 2), (5, 2)],
+                            GroupCoordinate::Block(coordinate(3)),
+                        ),
+                        expected_deduction(
+                            7,
+                            vec![(6, 4)],
+                            vec![(6, 6), (6, 8)],
+                            GroupCoordinate::Block(coordinate(8)),
+                        ),
                     ]
                     .into_iter()
                     .collect();
@@ -432,11 +519,36 @@ mod tests {
                         .unwrap();
 
                     let expected_deductions = vec![
-                        expected_deduction(7, vec![(0, 6), (1, 6), (2, 6)], vec![(7, 6), (8, 6)]),
-                        expected_deduction(9, vec![(3, 5)], vec![(7, 5), (8, 5)]),
-                        expected_deduction(3, vec![(4, 5)], vec![(6, 5), (7, 5), (8, 5)]),
-                        expected_deduction(4, vec![(5, 0), (5, 3), (5, 4)], vec![(5, 6), (5, 7)]),
-                        expected_deduction(6, vec![(7, 1)], vec![(7, 6), (7, 7)]),
+                        expected_deduction(
+                            7,
+                            vec![(0, 6), (1, 6), (2, 6)],
+                            vec![(7, 6), (8, 6)],
+                            GroupCoordinate::Block(coordinate(8)),
+                        ),
+                        expected_deduction(
+                            9,
+                            vec![(3, 5)],
+                            vec![(7, 5), (8, 5)],
+                            GroupCoordinate::Block(coordinate(7)),
+                        ),
+                        expected_deduction(
+                            3,
+                            vec![(4, 5)],
+                            vec![(6, 5), (7, 5), (8, 5)],
+                            GroupCoordinate::Block(coordinate(7)),
+                        ),
+                        expected_deduction(
+                            4,
+                            vec![(5, 0), (5, 3), (5, 4)],
+                            vec![(5, 6), (5, 7)],
+                            GroupCoordinate::Block(coordinate(5)),
+                        ),
+                        expected_deduction(
+                            6,
+                            vec![(7, 1)],
+                            vec![(7, 6), (7, 7)],
+                            GroupCoordinate::Block(coordinate(8)),
+                        ),
                     ]
                     .into_iter()
                     .collect();
@@ -463,11 +575,17 @@ mod tests {
                         .unwrap();
 
                     let expected_deductions = vec![
-                        expected_deduction(2, vec![(1, 4), (2, 3), (2, 4)], vec![(0, 3), (0, 4)]),
+                        expected_deduction(
+                            2,
+                            vec![(1, 4), (2, 3), (2, 4)],
+                            vec![(0, 3), (0, 4)],
+                            GroupCoordinate::Row(coordinate(0)),
+                        ),
                         expected_deduction(
                             4,
                             vec![(1, 6), (1, 8), (2, 6), (2, 8)],
                             vec![(0, 7), (1, 7)],
+                            GroupCoordinate::Column(coordinate(7)),
                         ),
                     ]
                     .into_iter()
@@ -494,18 +612,31 @@ mod tests {
                             6,
                             vec![(3, 2), (4, 2), (5, 2)],
                             vec![(3, 1), (4, 1), (5, 1)],
+                            GroupCoordinate::Column(coordinate(1)),
                         ),
                         expected_deduction(
                             9,
                             vec![(3, 6), (3, 8), (5, 6), (5, 8)],
                             vec![(3, 7), (5, 7)],
+                            GroupCoordinate::Column(coordinate(7)),
+                        ),
+                        expected_deduction(
+                            1,
+                            vec![(7, 1), (8, 2)],
+                            vec![(7, 0), (8, 0)],
+                            GroupCoordinate::Column(coordinate(0)),
+                        ),
+                        expected_deduction(
+                            3,
+                            vec![(7, 1), (8, 2)],
+                            vec![(6, 0), (7, 0), (8, 0)],
+                            GroupCoordinate::Column(coordinate(0)),
                         ),
-                        expected_deduction(1, vec![(7, 1), (8, 2)], vec![(7, 0), (8, 0)]),
-                        expected_deduction(3, vec![(7, 1), (8, 2)], vec![(6, 0), (7, 0), (8, 0)]),
                         expected_deduction(
                             7,
                             vec![(7, 3), (7, 4), (8, 4)],
                             vec![(6, 3), (6, 4), (6, 5)],
+                            GroupCoordinate::Row(coordinate(6)),
                         ),
                     ]
                     .into_iter()
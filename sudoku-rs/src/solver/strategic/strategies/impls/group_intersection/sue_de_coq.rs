@@ -0,0 +1,248 @@
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::{BlockSegment, Position};
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::impls::almost_locked_sets::smallest_deductions;
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+/// The maximal number of cells in the rest of the axis or block, which are combined with the intersection.
+///
+/// Larger sets are rarely useful and would dominate the runtime for larger bases.
+const MAX_SUE_DE_COQ_SET_SIZE: usize = 3;
+
+/*
+Logic:
+
+The intersection `C` of a block and an axis (block segment) contains at least two unsolved cells with the candidates `V`,
+where `V` contains at least two candidates more than `C` contains cells.
+
+`L` is a set of cells in the rest of the axis and `B` a set of cells in the rest of the block,
+whose candidates `VL` and `VB` don't share any candidate.
+If `C`, `L` and `B` together contain exactly as many candidates as cells,
+every candidate must be placed exactly once in these cells:
+- the candidates of `VL` can only be placed in `C` or `L`, which are all part of the axis.
+- the candidates of `VB` can only be placed in `C` or `B`, which are all part of the block.
+- the other candidates of `V` can only be placed in `C`.
+
+Eliminations:
+- `VL` and the candidates of `V` not contained in `VB` in the rest of the axis.
+- `VB` and the candidates of `V` not contained in `VL` in the rest of the block.
+
+Each cell of `L` and `B` must contain a candidate of `V`,
+and `L` and `B` must contain less candidates outside of `V` than cells, otherwise they form a locked set on their own.
+
+Only the deductions of the smallest sets are reported.
+*/
+
+/// An implementation of the [Sue de Coq](https://www.sudokuwiki.org/Sue_De_Coq) strategy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SueDeCoq;
+
+impl Strategy for SueDeCoq {
+    fn name(self) -> &'static str {
+        "SueDeCoq"
+    }
+
+    fn score(self) -> StrategyScore {
+        520
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(smallest_deductions(BlockSegment::<Base>::all().flat_map(
+            |block_segment| {
+                let segment_positions = block_segment.segment_positions().collect_vec();
+
+                let candidates_cells = |positions: &mut dyn Iterator<Item = Position<Base>>| {
+                    positions
+                        .filter(|pos| !segment_positions.contains(pos))
+                        .filter_map(|pos| {
+                            grid[pos].candidates().map(|candidates| (pos, candidates))
+                        })
+                        .collect_vec()
+                };
+                let axis_cells = candidates_cells(&mut block_segment.axis_positions());
+                let block_cells = candidates_cells(&mut block_segment.block_positions());
+
+                let intersection_cells = segment_positions
+                    .iter()
+                    .filter_map(|&pos| grid[pos].candidates().map(|candidates| (pos, candidates)))
+                    .collect_vec();
+
+                (2..=intersection_cells.len())
+                    .flat_map(|size| intersection_cells.iter().copied().combinations(size))
+                    .flat_map(|intersection| {
+                        sue_de_coq_deductions(&intersection, &axis_cells, &block_cells)
+                    })
+                    .collect_vec()
+            },
+        )))
+    }
+}
+
+type Cells<Base> = Vec<(Position<Base>, Candidates<Base>)>;
+
+/// All deductions for the given cells of a block segment, sized by the total number of cells.
+fn sue_de_coq_deductions<Base: SudokuBase>(
+    intersection: &Cells<Base>,
+    axis_cells: &Cells<Base>,
+    block_cells: &Cells<Base>,
+) -> Vec<(usize, Deduction<Base>)> {
+    let intersection_candidates = union(intersection);
+
+    if intersection_candidates.count() < u8::try_from(intersection.len()).unwrap() + 2 {
+        return vec![];
+    }
+
+    let axis_sets = rest_sets(axis_cells, intersection_candidates);
+    let block_sets = rest_sets(block_cells, intersection_candidates);
+
+    axis_sets
+        .iter()
+        .cartesian_product(&block_sets)
+        .filter_map(
+            |((axis_set, axis_candidates), (block_set, block_candidates))| {
+                if !axis_candidates.intersection(*block_candidates).is_empty() {
+                    return None;
+                }
+
+                let size = intersection.len() + axis_set.len() + block_set.len();
+                let all_candidates = intersection_candidates
+                    .union(*axis_candidates)
+                    .union(*block_candidates);
+                if usize::from(all_candidates.count()) != size {
+                    return None;
+                }
+
+                let axis_eliminations =
+                    axis_candidates.union(intersection_candidates.without(*block_candidates));
+                let block_eliminations =
+                    block_candidates.union(intersection_candidates.without(*axis_candidates));
+
+                let actions = [
+                    (axis_cells, axis_set, axis_eliminations),
+                    (block_cells, block_set, block_eliminations),
+                ]
+                .into_iter()
+                .flat_map(|(cells, set, eliminations)| {
+                    cells.iter().filter(|cell| !set.contains(cell)).filter_map(
+                        move |&(pos, candidates)| {
+                            let deleted_candidates = candidates.intersection(eliminations);
+                            (!deleted_candidates.is_empty())
+                                .then_some((pos, Action::DeleteCandidates(deleted_candidates)))
+                        },
+                    )
+                })
+                .collect_vec();
+
+                if actions.is_empty() {
+                    return None;
+                }
+
+                let reasons = intersection
+                    .iter()
+                    .chain(axis_set)
+                    .chain(block_set)
+                    .map(|&(pos, candidates)| (pos, Reason::candidates(candidates)));
+
+                Some((size, Deduction::try_from_iters(actions, reasons).unwrap()))
+            },
+        )
+        .collect()
+}
+
+/// All sets of cells in the rest of an axis or block, which can be combined with the intersection.
+fn rest_sets<Base: SudokuBase>(
+    cells: &Cells<Base>,
+    intersection_candidates: Candidates<Base>,
+) -> Vec<(Cells<Base>, Candidates<Base>)> {
+    let potential_cells = cells
+        .iter()
+        .copied()
+        .filter(|(_, candidates)| {
+            candidates.count() >= 2 && !candidates.intersection(intersection_candidates).is_empty()
+        })
+        .collect_vec();
+
+    (1..=MAX_SUE_DE_COQ_SET_SIZE.min(potential_cells.len()))
+        .flat_map(|size| potential_cells.iter().copied().combinations(size))
+        .filter_map(|set| {
+            let candidates = union(&set);
+            let extra_candidate_count = candidates.without(intersection_candidates).count();
+
+            (usize::from(extra_candidate_count) < set.len()).then_some((set, candidates))
+        })
+        .collect()
+}
+
+fn union<Base: SudokuBase>(cells: &Cells<Base>) -> Candidates<Base> {
+    cells
+        .iter()
+        .map(|&(_, candidates)| candidates)
+        .fold(Candidates::new(), Candidates::union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Cell, Value};
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_sue_de_coq() {
+        let mut grid = Grid::<Base3>::new();
+
+        for pos in Position::<Base3>::all() {
+            grid[pos] = Cell::with_value(9.try_into().unwrap(), false);
+        }
+        // Intersection of row 0 and block 0 with the candidates 1, 2, 3 and 4
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        grid[pos(0, 1)] = Cell::with_candidates(candidates(&[1, 2, 4]));
+        // Bivalue cell in the rest of row 0
+        grid[pos(0, 5)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Bivalue cell in the rest of block 0
+        grid[pos(2, 2)] = Cell::with_candidates(candidates(&[3, 4]));
+        // Eliminations: 1 and 2 in row 0, 3 and 4 in block 0
+        grid[pos(0, 7)] = Cell::with_candidates(candidates(&[1, 3, 5]));
+        grid[pos(1, 1)] = Cell::with_candidates(candidates(&[2, 4, 6]));
+
+        let deductions = SueDeCoq.execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<Base3> = [Deduction::try_from_iters(
+            [
+                (pos(0, 7), Action::DeleteCandidates(candidates(&[1]))),
+                (pos(1, 1), Action::DeleteCandidates(candidates(&[4]))),
+            ],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2, 3]))),
+                (pos(0, 1), Reason::candidates(candidates(&[1, 2, 4]))),
+                (pos(0, 5), Reason::candidates(candidates(&[1, 2]))),
+                (pos(2, 2), Reason::candidates(candidates(&[3, 4]))),
+            ],
+        )
+        .unwrap()]
+        .into_iter()
+        .collect();
+
+        assert_deductions(&deductions, &expected_deductions);
+    }
+
+    strategy_snapshot_tests!(SueDeCoq);
+}
@@ -0,0 +1,67 @@
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::grid::Grid;
+use crate::position::Position;
+use itertools::Itertools;
+
+/// The kind of group, in which a link between two candidates exists.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum LinkGroup {
+    Row,
+    Column,
+    Block,
+}
+
+/// A strong link (conjugate pair) for a single candidate:
+/// the candidate is set in exactly two positions of a group, therefore one of them must be true.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct StrongLink<Base: SudokuBase> {
+    pub(super) group: LinkGroup,
+    pub(super) positions: [Position<Base>; 2],
+}
+
+impl<Base: SudokuBase> StrongLink<Base> {
+    /// Both orientations of this link: `(start, end)` and `(end, start)`.
+    pub(super) fn orientations(self) -> [(Position<Base>, Position<Base>); 2] {
+        let [first, second] = self.positions;
+        [(first, second), (second, first)]
+    }
+
+    pub(super) fn contains(self, pos: Position<Base>) -> bool {
+        self.positions.contains(&pos)
+    }
+}
+
+/// All strong links of `candidate` in the grid.
+///
+/// A pair of positions which is linked in multiple groups (e.g. row and block) is reported once per group.
+pub(super) fn strong_links<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    candidate: Value<Base>,
+) -> Vec<StrongLink<Base>> {
+    let has_candidate = |pos: &Position<Base>| {
+        grid[*pos]
+            .candidates()
+            .is_some_and(|candidates| candidates.has(candidate))
+    };
+
+    let rows = Grid::<Base>::all_row_positions().map(|row| (LinkGroup::Row, row.collect_vec()));
+    let columns = Grid::<Base>::all_column_positions()
+        .map(|column| (LinkGroup::Column, column.collect_vec()));
+    let blocks =
+        Grid::<Base>::all_block_positions().map(|block| (LinkGroup::Block, block.collect_vec()));
+
+    rows.chain(columns)
+        .chain(blocks)
+        .filter_map(|(group, positions)| {
+            let (first, second) = positions
+                .into_iter()
+                .filter(has_candidate)
+                .collect_tuple()?;
+            Some(StrongLink {
+                group,
+                positions: [first, second],
+            })
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use itertools::izip;
+use itertools::Itertools;
 
 use crate::base::SudokuBase;
 use crate::cell::Candidates;
@@ -6,90 +6,292 @@ use crate::cell::Value;
 use crate::error::Result;
 use crate::grid::Grid;
 use crate::grid::group::CandidatesGroup;
-use crate::solver::strategic::deduction::{Action, Deduction, Deductions};
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
 use crate::solver::strategic::strategies::Strategy;
 use crate::solver::strategic::strategies::StrategyScore;
 
 pub mod v2;
 
+/*
+Logic:
+
+Naked set: `n` cells of a group, which contain exactly `n` candidates in total.
+The candidates must be placed in these cells.
+Eliminations: the candidates of the set in all other cells of the group.
+
+Hidden set: `n` candidates, which are not yet placed in a group and are restricted to exactly `n` cells of the group.
+These cells must contain these candidates.
+Eliminations: all other candidates in the cells of the set.
+
+A set contained in multiple groups (e.g. row and block) is reported as a single deduction.
+*/
+
+/// Whether a [`LockedSets`] strategy searches for naked or hidden sets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum LockedSetType {
+    Naked,
+    Hidden,
+}
+
 // TODO: optimize
 //  - https://en.wikipedia.org/wiki/Strongly_connected_component
 //  - https://opensourc.es/blog/sudoku/
 //  This seems to be the bottleneck for the goal generator
-// TODO: produce decuction reasons
-// TODO: parameterize strategy
-//  set size
-//  naked/hidden
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub struct LockedSets;
+pub struct LockedSets {
+    /// `None` searches for all set types and sizes.
+    set: Option<(LockedSetType, u8)>,
+}
+
+impl Default for LockedSets {
+    /// Search for all set types and sizes, see [`LockedSets::execute`].
+    fn default() -> Self {
+        Self { set: None }
+    }
+}
+
+impl LockedSets {
+    /// The smallest supported set size, a pair.
+    pub const MIN_SIZE: u8 = 2;
+    /// The largest supported set size, a quad.
+    pub const MAX_SIZE: u8 = 4;
+
+    /// Search for locked sets of `set_type` with exactly `size` cells.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is outside of `MIN_SIZE..=MAX_SIZE`.
+    pub const fn new(set_type: LockedSetType, size: u8) -> Self {
+        assert!(Self::MIN_SIZE <= size && size <= Self::MAX_SIZE);
+
+        Self {
+            set: Some((set_type, size)),
+        }
+    }
+
+    /// Search for naked sets with exactly `size` cells.
+    pub const fn naked(size: u8) -> Self {
+        Self::new(LockedSetType::Naked, size)
+    }
+
+    /// Search for hidden sets with exactly `size` cells.
+    pub const fn hidden(size: u8) -> Self {
+        Self::new(LockedSetType::Hidden, size)
+    }
+
+    /// `None`, if all set types are searched.
+    pub const fn set_type(self) -> Option<LockedSetType> {
+        match self.set {
+            Some((set_type, _)) => Some(set_type),
+            None => None,
+        }
+    }
+
+    /// `None`, if all set sizes are searched.
+    pub const fn size(self) -> Option<u8> {
+        match self.set {
+            Some((_, size)) => Some(size),
+            None => None,
+        }
+    }
+
+    /// All set sizes and types in ascending order of their score.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (Self::MIN_SIZE..=Self::MAX_SIZE).flat_map(|size| {
+            [LockedSetType::Naked, LockedSetType::Hidden]
+                .into_iter()
+                .map(move |set_type| Self::new(set_type, size))
+        })
+    }
+
+    /// Whether the deductions of `other` are a subset of the deductions of `self`.
+    pub fn includes(self, other: Self) -> bool {
+        self.set.is_none() || self == other
+    }
+}
 
 impl Strategy for LockedSets {
     fn name(self) -> &'static str {
-        "LockedSets"
+        match self.set {
+            None => "LockedSets",
+            Some((LockedSetType::Naked, 2)) => "LockedNakedPairs",
+            Some((LockedSetType::Hidden, 2)) => "LockedHiddenPairs",
+            Some((LockedSetType::Naked, 3)) => "LockedNakedTriples",
+            Some((LockedSetType::Hidden, 3)) => "LockedHiddenTriples",
+            Some((LockedSetType::Naked, 4)) => "LockedNakedQuads",
+            Some((LockedSetType::Hidden, 4)) => "LockedHiddenQuads",
+            Some(_) => unreachable!("Set size is checked in LockedSets::new"),
+        }
     }
 
+    /// Larger sets score higher, hidden sets score higher than naked sets of the same size.
+    ///
+    /// Searching for all set types and sizes scores like the easiest set, naked pairs.
     fn score(self) -> StrategyScore {
-        50
+        let Some((set_type, size)) = self.set else {
+            return Self::naked(Self::MIN_SIZE).score();
+        };
+
+        let set_type_score = match set_type {
+            LockedSetType::Naked => 0,
+            LockedSetType::Hidden => 10,
+        };
+
+        40 + 20 * StrategyScore::from(size - Self::MIN_SIZE) + set_type_score
     }
+
+    /// Searching for all set types and sizes returns the deductions of the first set type and size
+    /// in [`LockedSets::all`] order, which has any deductions.
     fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let Some((set_type, size)) = self.set else {
+            for strategy in Self::all() {
+                let deductions = strategy.execute(grid)?;
+                if !deductions.is_empty() {
+                    return Ok(deductions);
+                }
+            }
+            return Ok(Deductions::default());
+        };
+
+        let Ok(size) = Value::<Base>::try_from(size) else {
+            return Ok(Deductions::default());
+        };
+
         Grid::<Base>::all_group_positions()
-            .map(|group| {
-                // let group = group
-                //     .map(|pos| grid[pos].to_candidates())
-                //     .next_chunk()
-                //     .unwrap();
-
-                // FIXME: optimize conversion of group iters to Group<T> (TrustedGroupSizeIter)
-                //  Grid could provide Group<Base, T> iteration/indexing directly.
-
-                let candidates_group: CandidatesGroup<Base> = group
-                    .clone()
-                    .map(|pos| grid[pos].to_candidates())
-                    .collect::<Vec<_>>()
+            .flat_map(|group| {
+                let positions = group.collect_vec();
+
+                let candidates_group: CandidatesGroup<Base> = positions
+                    .iter()
+                    .map(|&pos| grid[pos].candidates().unwrap_or_default())
+                    .collect_vec()
                     .try_into()
                     .unwrap();
 
-                // let (positions, candidates_group): (Vec<_>, Vec<_>) = group
-                //     .filter_map(|pos| {
-                //         grid.get(pos)
-                //             .candidates()
-                //             .map(|candidates| (pos, candidates))
-                //     })
-                //     .unzip();
-
-                // TODO: v1 vs v2 has no clear-cut performance winner
-                //  For small candidates groups, v1 is faster, up to 10x (Strategies/LockedSets/execute/sample_grid_hidden_pairs)
-                //  For large candidates groups, v2 is way faster, up to 6ms vs 220ns / 20_000x speed-up (Strategies/LockedSets/v2/find_locked_set/all)
-                // Either optimize v2 to be faster or at least comparable to v1 in all cases.
-                // Or use introspective implementation, which switches between the two implementation based on a heuristic.
-                let reduced_candidates_group = v2::find_locked_set(&candidates_group);
-
-                let mut deduction = Deduction::new();
-
-                for (position, candidates, reduced_candidates) in
-                    izip!(group, candidates_group, reduced_candidates_group)
-                {
-                    if candidates != reduced_candidates {
-                        deduction.actions.insert(
-                            position,
-                            Action::DeleteCandidates(candidates.without(reduced_candidates)),
-                        )?;
+                let sets = match set_type {
+                    LockedSetType::Naked => naked_sets(&candidates_group, size),
+                    LockedSetType::Hidden => {
+                        let placed_values = positions
+                            .iter()
+                            .filter_map(|&pos| grid[pos].value())
+                            .collect();
+                        hidden_sets(&candidates_group, placed_values, size)
                     }
-                }
+                };
 
-                if deduction.is_empty() {
-                    Ok(None)
-                } else {
-                    Ok(Some(deduction))
-                }
+                sets.into_iter()
+                    .map(|locked_set| locked_set.deduction(&positions, &candidates_group))
+                    .filter(|deduction| !deduction.actions.is_empty())
+                    .collect_vec()
             })
-            // TODO: copy `.filter_map(Result::transpose)` trick to other strategies
-            .filter_map(Result::transpose)
-            .collect()
+            .collect::<Deductions<Base>>()
+            .merge_deductions_by_reasons()
     }
 }
 
+/// A locked set inside a group: the `values` must be placed in the cells at `indexes`.
+struct LockedSet<Base: SudokuBase> {
+    indexes: Candidates<Base>,
+    values: Candidates<Base>,
+}
+
+impl<Base: SudokuBase> LockedSet<Base> {
+    /// Deletes the values of the set outside of the set cells,
+    /// and all other candidates inside of the set cells.
+    fn deduction(
+        &self,
+        positions: &[Position<Base>],
+        candidates_group: &CandidatesGroup<Base>,
+    ) -> Deduction<Base> {
+        let mut deduction = Deduction::new();
+
+        for (i, candidates) in candidates_group.iter_enumerate() {
+            let pos = positions[i.get_usize()];
+
+            let (deleted_candidates, reason_candidates) = if self.indexes.has(i) {
+                (
+                    candidates.without(self.values),
+                    candidates.intersection(self.values),
+                )
+            } else {
+                (candidates.intersection(self.values), Candidates::new())
+            };
+
+            if !deleted_candidates.is_empty() {
+                deduction
+                    .actions
+                    .insert(pos, Action::DeleteCandidates(deleted_candidates))
+                    .unwrap();
+            }
+            if !reason_candidates.is_empty() {
+                deduction
+                    .reasons
+                    .insert(pos, Reason::candidates(reason_candidates))
+                    .unwrap();
+            }
+        }
+
+        deduction
+    }
+}
+
+/// All sets of `size` cells, which contain exactly `size` candidates in total.
+fn naked_sets<Base: SudokuBase>(
+    candidates_group: &CandidatesGroup<Base>,
+    size: Value<Base>,
+) -> Vec<LockedSet<Base>> {
+    let potential_indexes: Candidates<Base> = candidates_group
+        .iter_enumerate()
+        .filter(|(_, candidates)| (2..=size.get()).contains(&candidates.count()))
+        .map(|(i, _)| i)
+        .collect();
+
+    potential_indexes
+        .combinations(size)
+        .filter_map(|indexes| {
+            let values = candidates_group
+                .iter_index_mask(indexes)
+                .fold(Candidates::new(), Candidates::union);
+
+            (values.count() == size.get()).then_some(LockedSet { indexes, values })
+        })
+        .collect()
+}
+
+/// All sets of `size` unplaced values, which are restricted to exactly `size` cells in total.
+fn hidden_sets<Base: SudokuBase>(
+    candidates_group: &CandidatesGroup<Base>,
+    placed_values: Candidates<Base>,
+    size: Value<Base>,
+) -> Vec<LockedSet<Base>> {
+    let value_indexes = candidates_group.transpose();
+
+    let potential_values: Candidates<Base> = value_indexes
+        .iter_enumerate()
+        .filter(|(_, indexes)| (2..=size.get()).contains(&indexes.count()))
+        .map(|(value, _)| value)
+        .collect::<Candidates<Base>>()
+        .without(placed_values);
+
+    potential_values
+        .combinations(size)
+        .filter_map(|values| {
+            let indexes = value_indexes
+                .iter_index_mask(values)
+                .fold(Candidates::new(), Candidates::union);
+
+            (indexes.count() == size.get()).then_some(LockedSet { indexes, values })
+        })
+        .collect()
+}
+
+// TODO: v1 vs v2 has no clear-cut performance winner
+//  For small candidates groups, v1 is faster, up to 10x
+//  For large candidates groups, v2 is way faster, up to 6ms vs 220ns / 20_000x speed-up (Strategies/LockedSets/v2/find_locked_set/all)
 impl LockedSets {
+    /// Reduces the candidates of the group to the values, which are part of at least one valid assignment,
+    /// which is equivalent to applying locked sets of all sizes.
     pub fn find_locked_set_v1<Base: SudokuBase>(
         candidates_group: &[Candidates<Base>],
     ) -> Vec<Candidates<Base>> {
@@ -392,6 +594,24 @@ mod tests {
         }
     }
 
+    fn deduction(actions: &[((u8, u8), &[u8])], reasons: &[((u8, u8), &[u8])]) -> Deduction<Base3> {
+        let to_candidates = |candidates: &[u8]| Candidates::try_from(candidates.to_vec()).unwrap();
+        let to_pos = |pos: (u8, u8)| Position::try_from(pos).unwrap();
+
+        Deduction::try_from_iters(
+            actions.iter().map(|&(pos, candidates)| {
+                (
+                    to_pos(pos),
+                    Action::DeleteCandidates(to_candidates(candidates)),
+                )
+            }),
+            reasons.iter().map(|&(pos, candidates)| {
+                (to_pos(pos), Reason::candidates(to_candidates(candidates)))
+            }),
+        )
+        .unwrap()
+    }
+
     /// Reference:
     /// `https://www.sudokuwiki.org/Naked_Candidates`
     /// "Naked Pairs examples : Load Example"
@@ -405,47 +625,36 @@ mod tests {
 
         grid.set_all_direct_candidates();
 
-        let deductions = LockedSets.execute(&grid).unwrap();
-
-        let expected_deductions: Deductions<_> = vec![
-            vec![
-                // Pair 1,6 in row 0
-                ((0, 3), vec![1]),
-                ((0, 4), vec![1, 6]),
-                ((0, 5), vec![6]),
-            ],
-            vec![
-                // Pair 1,6 in block 0,0
-                ((2, 0), vec![1]),
-            ],
-            vec![
-                // Pair 6,7 in row 2
-                ((2, 0), vec![7]),
-                ((2, 4), vec![6, 7]),
-            ],
-            vec![
-                // Pair 4,8 in block 1,1
-                ((3, 4), vec![8]),
-                ((5, 4), vec![8]),
-            ],
-            vec![
-                // Pair 5,8 in block 1,2
-                ((3, 7), vec![5, 8]),
-                ((5, 8), vec![5]),
-            ],
+        let deductions = LockedSets::naked(2).execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<_> = [
+            // Pair 1,6 in row 0 and block 0,0
+            deduction(
+                &[
+                    ((0, 3), &[1]),
+                    ((0, 4), &[1, 6]),
+                    ((0, 5), &[6]),
+                    ((2, 0), &[1]),
+                ],
+                &[((0, 1), &[1, 6]), ((0, 2), &[1, 6])],
+            ),
+            // Pair 6,7 in row 2
+            deduction(
+                &[((2, 0), &[7]), ((2, 4), &[6, 7])],
+                &[((2, 5), &[6, 7]), ((2, 8), &[6, 7])],
+            ),
+            // Pair 4,8 in block 1,1
+            deduction(
+                &[((3, 4), &[8]), ((5, 4), &[8])],
+                &[((4, 3), &[4, 8]), ((4, 4), &[4, 8])],
+            ),
+            // Pair 5,8 in block 1,2
+            deduction(
+                &[((3, 7), &[5, 8]), ((5, 8), &[5])],
+                &[((3, 6), &[5, 8]), ((5, 6), &[5, 8])],
+            ),
         ]
         .into_iter()
-        .map(|positioned_candidates| {
-            Deduction::try_from_actions(positioned_candidates.into_iter().map(
-                |(pos, candidates)| {
-                    (
-                        pos.try_into().unwrap(),
-                        Action::DeleteCandidates(Candidates::try_from(candidates).unwrap()),
-                    )
-                },
-            ))
-            .unwrap()
-        })
         .collect();
 
         assert_deductions_with_grid(&deductions, &expected_deductions, &mut grid);
@@ -466,36 +675,94 @@ mod tests {
 
         grid.set_all_direct_candidates();
 
-        let deductions = LockedSets.execute(&grid).unwrap();
-
-        let expected_deductions: Deductions<_> = vec![
-            vec![
-                // Hidden pair 2,4 in block 1,0 and column 2
-                ((3, 2), vec![5, 6]),
-                ((4, 2), vec![3, 6, 7]),
-            ],
-            vec![
-                // Hidden pair 3,7 in block 1,2 and column 6
-                ((4, 6), vec![6, 9]),
-                ((5, 6), vec![1, 5, 9]),
-            ],
+        let deductions = LockedSets::hidden(2).execute(&grid).unwrap();
+
+        let expected_deductions: Deductions<_> = [
+            // Hidden pair 2,4 in block 1,0 and column 2
+            deduction(
+                &[((3, 2), &[5, 6]), ((4, 2), &[3, 6, 7])],
+                &[((3, 2), &[2, 4]), ((4, 2), &[2, 4])],
+            ),
+            // Hidden pair 3,7 in block 1,2 and column 6
+            deduction(
+                &[((4, 6), &[6, 9]), ((5, 6), &[1, 5, 9])],
+                &[((4, 6), &[3, 7]), ((5, 6), &[3, 7])],
+            ),
         ]
         .into_iter()
-        .map(|positioned_candidates| {
-            Deduction::try_from_actions(positioned_candidates.into_iter().map(
-                |(pos, candidates)| {
-                    (
-                        pos.try_into().unwrap(),
-                        Action::DeleteCandidates(Candidates::try_from(candidates).unwrap()),
-                    )
-                },
-            ))
-            .unwrap()
-        })
         .collect();
 
         assert_deductions_with_grid(&deductions, &expected_deductions, &mut grid);
     }
 
-    strategy_snapshot_tests!(LockedSets);
+    #[test]
+    fn test_set_size() {
+        let mut grid: Grid<Base3> =
+            "400000938032094100095300240370609004529001673604703090957008300003900400240030709"
+                .parse()
+                .unwrap();
+
+        grid.set_all_direct_candidates();
+
+        for strategy in LockedSets::all() {
+            for deduction in &strategy.execute(&grid).unwrap() {
+                let locked_values = deduction
+                    .reasons
+                    .iter()
+                    .fold(Candidates::new(), |values, (_, reason)| {
+                        values.union(reason.all_candidates())
+                    });
+
+                let size = strategy.size().unwrap();
+                assert_eq!(locked_values.count(), size);
+                assert!(deduction.reasons.len() <= usize::from(size));
+            }
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        let scores = LockedSets::all().map(Strategy::score).collect::<Vec<_>>();
+
+        assert!(scores.is_sorted());
+        assert_eq!(scores.iter().unique().count(), 6);
+        assert!(LockedSets::naked(3).score() < LockedSets::hidden(3).score());
+        assert!(LockedSets::hidden(3).score() < LockedSets::naked(4).score());
+    }
+
+    mod naked_pairs {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::naked(2));
+    }
+
+    mod hidden_pairs {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::hidden(2));
+    }
+
+    mod naked_triples {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::naked(3));
+    }
+
+    mod hidden_triples {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::hidden(3));
+    }
+
+    mod naked_quads {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::naked(4));
+    }
+
+    mod hidden_quads {
+        use super::*;
+
+        strategy_snapshot_tests!(LockedSets::hidden(4));
+    }
 }
@@ -1,18 +1,49 @@
+pub use als_xy_wing::AlsXyWing;
+pub use als_xz::AlsXz;
 pub use brute_force::BruteForce;
+pub use bug_plus_one::BugPlusOne;
+pub use chains::{AlternatingInferenceChain, DEFAULT_MAX_CHAIN_LENGTH, XChain, XyChain};
+pub use coloring::{MultiColoring, SimpleColoring};
+pub use death_blossom::DeathBlossom;
+pub use finned_fish::FinnedFish;
+pub use fish::Fish;
+pub use forcing_chains::{DEFAULT_MAX_FORCING_DEPTH, ForcingChains};
 pub use group_intersection::{
-    GroupIntersectionAxisToBlock, GroupIntersectionBlockToAxis, GroupIntersectionBoth,
+    EmptyRectangle, GroupIntersectionAxisToBlock, GroupIntersectionBlockToAxis,
+    GroupIntersectionBoth, SueDeCoq,
 };
 pub use hidden_singles::HiddenSingles;
-pub use locked_sets::LockedSets;
+pub use locked_sets::{LockedSetType, LockedSets};
 pub use naked_pairs::NakedPairs;
 pub use naked_singles::NakedSingles;
+pub use turbot_fish::{Skyscraper, TwoStringKite};
+pub use unique_rectangle::UniqueRectangle;
+pub use w_wing::WWing;
 pub use x_wing::XWing;
+pub use xy_wing::XyWing;
+pub use xyz_wing::XyzWing;
 
 // Strategies
+mod almost_locked_sets;
+mod als_xy_wing;
+mod als_xz;
 mod brute_force;
+mod bug_plus_one;
+mod chains;
+mod coloring;
+mod death_blossom;
+mod finned_fish;
+mod fish;
+mod forcing_chains;
 mod group_intersection;
 mod hidden_singles;
+mod links;
 pub mod locked_sets;
 mod naked_pairs;
 mod naked_singles;
+mod turbot_fish;
+mod unique_rectangle;
+mod w_wing;
 mod x_wing;
+mod xy_wing;
+mod xyz_wing;
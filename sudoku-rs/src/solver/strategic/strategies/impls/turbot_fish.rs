@@ -0,0 +1,285 @@
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::impls::links::{LinkGroup, strong_links};
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Skyscraper;
+impl Strategy for Skyscraper {
+    fn name(self) -> &'static str {
+        "Skyscraper"
+    }
+    fn score(self) -> StrategyScore {
+        130
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(TurbotFish(TurbotFishType::Skyscraper).execute(grid))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TwoStringKite;
+impl Strategy for TwoStringKite {
+    fn name(self) -> &'static str {
+        "TwoStringKite"
+    }
+    fn score(self) -> StrategyScore {
+        140
+    }
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(TurbotFish(TurbotFishType::TwoStringKite).execute(grid))
+    }
+}
+
+/*
+Logic:
+
+A turbot fish consists of two strong links for a single candidate, which are connected by a weak link:
+`start_1 = end_1 - start_2 = end_2`
+
+If `start_1` sees `start_2`, at most one of them can be true. Therefore either `end_1` or `end_2` must be true.
+Eliminations: the candidate in all cells which see both `end_1` and `end_2`.
+
+Named variants:
+- Skyscraper: both strong links are located in rows (or both in columns).
+  The starts of the strong links share a column (or row).
+- 2-String Kite: one strong link is located in a row, the other one in a column.
+  The starts of the strong links share a block.
+*/
+
+/// An implementation of the single digit patterns [Skyscraper](https://www.sudokuwiki.org/Skyscraper)
+/// and [2-String Kite](https://www.sudokuwiki.org/2_String_Kite), which are both variants of the turbot fish.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct TurbotFish(TurbotFishType);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum TurbotFishType {
+    Skyscraper,
+    TwoStringKite,
+}
+
+impl TurbotFishType {
+    fn link_groups(self) -> [(LinkGroup, LinkGroup); 2] {
+        match self {
+            TurbotFishType::Skyscraper => [
+                (LinkGroup::Row, LinkGroup::Row),
+                (LinkGroup::Column, LinkGroup::Column),
+            ],
+            TurbotFishType::TwoStringKite => [
+                (LinkGroup::Row, LinkGroup::Column),
+                (LinkGroup::Column, LinkGroup::Row),
+            ],
+        }
+    }
+
+    /// Are the starts of both strong links connected by the expected weak link?
+    fn is_weak_link<Base: SudokuBase>(
+        self,
+        start_1: Position<Base>,
+        start_2: Position<Base>,
+    ) -> bool {
+        match self {
+            TurbotFishType::Skyscraper => {
+                start_1.to_row() == start_2.to_row() || start_1.to_column() == start_2.to_column()
+            }
+            TurbotFishType::TwoStringKite => start_1.to_block() == start_2.to_block(),
+        }
+    }
+}
+
+impl TurbotFish {
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Deductions<Base> {
+        let Self(turbot_fish_type) = self;
+
+        Value::<Base>::all()
+            .flat_map(|candidate| {
+                let strong_links = strong_links(grid, candidate);
+
+                turbot_fish_type
+                    .link_groups()
+                    .into_iter()
+                    .flat_map(|(group_1, group_2)| {
+                        let links_1 = strong_links
+                            .iter()
+                            .filter(move |link| link.group == group_1);
+                        let links_2 = strong_links
+                            .iter()
+                            .filter(move |link| link.group == group_2);
+                        links_1.cartesian_product(links_2.collect_vec())
+                    })
+                    .filter(|(link_1, link_2)| link_1 != link_2)
+                    .flat_map(|(&link_1, &link_2)| {
+                        link_1
+                            .orientations()
+                            .into_iter()
+                            .cartesian_product(link_2.orientations())
+                            .map(move |(oriented_link_1, oriented_link_2)| {
+                                (link_1, oriented_link_1, link_2, oriented_link_2)
+                            })
+                    })
+                    .filter_map(|(link_1, (start_1, end_1), link_2, (start_2, end_2))| {
+                        // The links must not share any position.
+                        if link_1.positions.into_iter().any(|pos| link_2.contains(pos)) {
+                            return None;
+                        }
+                        if !turbot_fish_type.is_weak_link(start_1, start_2) {
+                            return None;
+                        }
+                        // If the ends are connected the same way as the starts, the pattern is an X-Wing.
+                        if turbot_fish_type.is_weak_link(end_1, end_2) {
+                            return None;
+                        }
+
+                        turbot_fish_deduction(
+                            grid,
+                            candidate,
+                            [start_1, end_1, start_2, end_2],
+                            [end_1, end_2],
+                        )
+                    })
+                    .collect_vec()
+            })
+            .collect()
+    }
+}
+
+/// Delete `candidate` from all cells which see both `ends`.
+/// The positions of the strong links are used as the reason.
+fn turbot_fish_deduction<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    candidate: Value<Base>,
+    link_positions: [Position<Base>; 4],
+    [end_1, end_2]: [Position<Base>; 2],
+) -> Option<Deduction<Base>> {
+    let positions_to_delete = Position::<Base>::all()
+        .filter(|&pos| {
+            !link_positions.contains(&pos)
+                && end_1.sees(pos)
+                && end_2.sees(pos)
+                && grid[pos]
+                    .candidates()
+                    .is_some_and(|candidates| candidates.has(candidate))
+        })
+        .collect_vec();
+
+    if positions_to_delete.is_empty() {
+        return None;
+    }
+
+    Some(
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            link_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::candidate(candidate))),
+        )
+        .unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::consts::*,
+        solver::strategic::strategies::test_util::{
+            assert_deductions, strategy_snapshot_tests, values_to_candidates,
+        },
+    };
+    use indoc::indoc;
+
+    fn single_candidate_deduction<Base: SudokuBase>(
+        candidate: u8,
+        positions_to_delete: impl IntoIterator<Item = (u8, u8)>,
+        reason_positions: impl IntoIterator<Item = (u8, u8)>,
+    ) -> Deduction<Base> {
+        let candidate: Value<Base> = candidate.try_into().unwrap();
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(candidate))),
+            reason_positions
+                .into_iter()
+                .map(|pos| (pos, Reason::candidate(candidate))),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_skyscraper() {
+        // Strong links in rows 1 and 7, connected in column 0.
+        // Elimination: r0c5, which sees both r1c4 and r7c5.
+        // Row 4 prevents additional strong links in columns.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            000001000
+            100010000
+            000000000
+            000000000
+            111111111
+            000000000
+            000000000
+            100001000
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = Skyscraper.execute(&grid).unwrap();
+
+        let expected_deductions =
+            single_candidate_deduction(1, vec![(0, 5)], vec![(1, 0), (1, 4), (7, 0), (7, 5)]);
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_two_string_kite() {
+        // Strong link in row 0 (c1, c6) and column 2 (r1, r7), connected in block 0.
+        // Elimination: r7c6, which sees both r0c6 and r7c2.
+        // The center block prevents additional strong links in its rows and columns.
+        let grid: Grid<Base3> = values_to_candidates(
+            indoc! {"
+            010000100
+            001000000
+            000000000
+            000111000
+            000111000
+            000111000
+            000000000
+            001000100
+            000000000"
+            }
+            .parse()
+            .unwrap(),
+        );
+
+        let deductions = TwoStringKite.execute(&grid).unwrap();
+
+        let expected_deductions =
+            single_candidate_deduction(1, vec![(7, 6)], vec![(0, 1), (0, 6), (1, 2), (7, 2)]);
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    mod skyscraper {
+        use super::*;
+
+        strategy_snapshot_tests!(Skyscraper);
+    }
+
+    mod two_string_kite {
+        use super::*;
+
+        strategy_snapshot_tests!(TwoStringKite);
+    }
+}
@@ -0,0 +1,490 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::{Coordinate, Position};
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::deduction::Reason;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use itertools::Itertools;
+
+/*
+Logic:
+
+A unique rectangle consists of four cells in two rows, two columns and two blocks,
+which all contain the candidates `ab`.
+If all four cells could only be `a` or `b`, the two values could be swapped, resulting in a second solution.
+Assuming the grid has a unique solution, this deadly pattern must be avoided.
+
+The "floor" are the cells which contain exactly `ab`, the "roof" are the cells with extra candidates.
+
+- Type 1: three floor cells. The roof cell can't be `a` or `b`.
+- Type 2: two floor cells in a line, both roof cells contain the same single extra candidate `c`.
+  One of the roof cells must be `c`, which eliminates `c` in all cells seeing both roof cells.
+- Type 3: two floor cells in a line, the extra candidates of the roof cells are treated as a virtual cell,
+  which forms a naked subset with other cells of a group shared by both roof cells.
+  The candidates of the subset are eliminated in the remaining cells of the group.
+- Type 4: two floor cells in a line, one of the candidates `a` is restricted to the roof cells
+  in a group shared by both roof cells. One of the roof cells must be `a`, which makes the other `b`.
+  Therefore the candidate `b` is eliminated in both roof cells.
+*/
+
+/// The maximal number of real cells, which form a naked subset with the virtual roof cell (type 3).
+const MAX_TYPE_3_SUBSET_SIZE: usize = 3;
+
+/// An implementation of the [Unique Rectangle](https://www.sudokuwiki.org/Unique_Rectangles) strategy,
+/// covering the types 1 to 4.
+///
+/// Only valid for grids with a unique solution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UniqueRectangle;
+
+impl Strategy for UniqueRectangle {
+    fn name(self) -> &'static str {
+        "UniqueRectangle"
+    }
+
+    fn score(self) -> StrategyScore {
+        280
+    }
+
+    fn requires_unique_solution(self) -> bool {
+        true
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        Ok(rectangles::<Base>()
+            .flat_map(|corners| rectangle_deductions(grid, corners))
+            .collect())
+    }
+}
+
+/// All rectangles of four positions, which span exactly two blocks.
+fn rectangles<Base: SudokuBase>() -> impl Iterator<Item = [Position<Base>; 4]> {
+    let line_pairs = Coordinate::<Base>::all()
+        .collect_vec()
+        .into_iter()
+        .tuple_combinations::<(_, _)>()
+        .collect_vec();
+
+    line_pairs
+        .clone()
+        .into_iter()
+        .cartesian_product(line_pairs)
+        .filter_map(|((row_1, row_2), (column_1, column_2))| {
+            let corners = [
+                Position::from((row_1, column_1)),
+                Position::from((row_1, column_2)),
+                Position::from((row_2, column_1)),
+                Position::from((row_2, column_2)),
+            ];
+
+            (corners.iter().map(|pos| pos.to_block()).unique().count() == 2).then_some(corners)
+        })
+}
+
+fn rectangle_deductions<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    corners: [Position<Base>; 4],
+) -> Vec<Deduction<Base>> {
+    let Some(corner_candidates) = corners
+        .iter()
+        .map(|&pos| grid[pos].candidates())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return vec![];
+    };
+
+    let common_candidates = corner_candidates
+        .iter()
+        .fold(Candidates::all(), |common, &candidates| {
+            common.intersection(candidates)
+        });
+
+    common_candidates
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(a, b)| {
+            let ab = Candidates::with_single(a).union(Candidates::with_single(b));
+
+            let (floor, roof): (Vec<_>, Vec<_>) = corners
+                .into_iter()
+                .zip(corner_candidates.iter().copied())
+                .partition(|&(_, candidates)| candidates == ab);
+
+            let floor = floor.into_iter().map(|(pos, _)| pos).collect_vec();
+
+            match *roof.as_slice() {
+                [(roof_pos, _)] => vec![type_1(&floor, roof_pos, ab)],
+                [roof_1, roof_2] if roof_1.0.sees(roof_2.0) => {
+                    let roof = [roof_1, roof_2];
+                    type_2(grid, &floor, roof, ab)
+                        .into_iter()
+                        .chain(type_3(grid, &floor, roof, ab))
+                        .chain(type_4(grid, &floor, roof, ab))
+                        .collect_vec()
+                }
+                _ => vec![],
+            }
+        })
+        .collect()
+}
+
+fn type_1<Base: SudokuBase>(
+    floor: &[Position<Base>],
+    roof_pos: Position<Base>,
+    ab: Candidates<Base>,
+) -> Deduction<Base> {
+    Deduction::try_from_iters(
+        [(roof_pos, Action::delete_candidates(ab))],
+        floor_reasons(floor, ab),
+    )
+    .unwrap()
+}
+
+fn type_2<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    floor: &[Position<Base>],
+    roof: [(Position<Base>, Candidates<Base>); 2],
+    ab: Candidates<Base>,
+) -> Option<Deduction<Base>> {
+    let [
+        (roof_pos_1, roof_candidates_1),
+        (roof_pos_2, roof_candidates_2),
+    ] = roof;
+
+    let extra = roof_candidates_1.without(ab);
+    if extra != roof_candidates_2.without(ab) {
+        return None;
+    }
+    let c = extra.to_single()?;
+
+    let positions_to_delete = Position::<Base>::all()
+        .filter(|&pos| {
+            pos != roof_pos_1
+                && pos != roof_pos_2
+                && pos.sees(roof_pos_1)
+                && pos.sees(roof_pos_2)
+                && grid[pos]
+                    .candidates()
+                    .is_some_and(|candidates| candidates.has(c))
+        })
+        .collect_vec();
+
+    if positions_to_delete.is_empty() {
+        return None;
+    }
+
+    Some(
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(c))),
+            floor_reasons(floor, ab).chain(
+                roof.into_iter()
+                    .map(|(pos, candidates)| (pos, Reason::candidates(candidates))),
+            ),
+        )
+        .unwrap(),
+    )
+}
+
+fn type_3<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    floor: &[Position<Base>],
+    roof: [(Position<Base>, Candidates<Base>); 2],
+    ab: Candidates<Base>,
+) -> Vec<Deduction<Base>> {
+    let [
+        (roof_pos_1, roof_candidates_1),
+        (roof_pos_2, roof_candidates_2),
+    ] = roof;
+
+    let virtual_candidates = roof_candidates_1.union(roof_candidates_2).without(ab);
+
+    shared_groups(roof_pos_1, roof_pos_2)
+        .into_iter()
+        .flat_map(|group| {
+            let other_cells = group
+                .into_iter()
+                .filter(|&pos| pos != roof_pos_1 && pos != roof_pos_2)
+                .filter_map(|pos| {
+                    grid[pos]
+                        .candidates()
+                        .filter(|candidates| !candidates.is_empty())
+                        .map(|candidates| (pos, candidates))
+                })
+                .collect_vec();
+
+            (1..=MAX_TYPE_3_SUBSET_SIZE)
+                .flat_map(|subset_size| other_cells.iter().copied().combinations(subset_size))
+                .filter_map(|subset| {
+                    let subset_candidates = subset
+                        .iter()
+                        .fold(virtual_candidates, |subset_candidates, &(_, candidates)| {
+                            subset_candidates.union(candidates)
+                        });
+
+                    if usize::from(subset_candidates.count()) != subset.len() + 1 {
+                        return None;
+                    }
+
+                    let actions = other_cells
+                        .iter()
+                        .filter(|(pos, _)| subset.iter().all(|(subset_pos, _)| subset_pos != pos))
+                        .filter_map(|&(pos, candidates)| {
+                            let candidates_to_delete = candidates.intersection(subset_candidates);
+                            (!candidates_to_delete.is_empty())
+                                .then(|| (pos, Action::delete_candidates(candidates_to_delete)))
+                        })
+                        .collect_vec();
+
+                    if actions.is_empty() {
+                        return None;
+                    }
+
+                    Some(
+                        Deduction::try_from_iters(
+                            actions,
+                            floor_reasons(floor, ab).chain(
+                                roof.into_iter()
+                                    .chain(subset)
+                                    .map(|(pos, candidates)| (pos, Reason::candidates(candidates))),
+                            ),
+                        )
+                        .unwrap(),
+                    )
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn type_4<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    floor: &[Position<Base>],
+    roof: [(Position<Base>, Candidates<Base>); 2],
+    ab: Candidates<Base>,
+) -> Vec<Deduction<Base>> {
+    let [(roof_pos_1, _), (roof_pos_2, _)] = roof;
+
+    shared_groups(roof_pos_1, roof_pos_2)
+        .into_iter()
+        .flat_map(|group| {
+            ab.iter()
+                .filter(|&locked_candidate| {
+                    group.iter().all(|&pos| {
+                        pos == roof_pos_1
+                            || pos == roof_pos_2
+                            || !grid[pos]
+                                .candidates()
+                                .is_some_and(|candidates| candidates.has(locked_candidate))
+                    })
+                })
+                .map(|locked_candidate| {
+                    let other_candidate = ab.without(Candidates::with_single(locked_candidate));
+
+                    Deduction::try_from_iters(
+                        [roof_pos_1, roof_pos_2]
+                            .map(|pos| (pos, Action::delete_candidates(other_candidate))),
+                        floor_reasons(floor, ab).chain(
+                            [roof_pos_1, roof_pos_2]
+                                .map(|pos| (pos, Reason::candidate(locked_candidate))),
+                        ),
+                    )
+                    .unwrap()
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn floor_reasons<Base: SudokuBase>(
+    floor: &[Position<Base>],
+    ab: Candidates<Base>,
+) -> impl Iterator<Item = (Position<Base>, Reason<Base>)> {
+    floor.iter().map(move |&pos| (pos, Reason::candidates(ab)))
+}
+
+/// The positions of all groups (row, column and block), which contain both positions.
+fn shared_groups<Base: SudokuBase>(
+    pos_1: Position<Base>,
+    pos_2: Position<Base>,
+) -> Vec<Vec<Position<Base>>> {
+    let mut groups = vec![];
+    if pos_1.to_row() == pos_2.to_row() {
+        groups.push(Position::row(pos_1.to_row()).collect_vec());
+    }
+    if pos_1.to_column() == pos_2.to_column() {
+        groups.push(Position::column(pos_1.to_column()).collect_vec());
+    }
+    if pos_1.to_block() == pos_2.to_block() {
+        groups.push(Position::block(pos_1.to_block()).collect_vec());
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Cell, Value};
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    fn candidates(candidates: &[u8]) -> Candidates<Base3> {
+        candidates
+            .iter()
+            .map(|&candidate| Value::try_from(candidate).unwrap())
+            .collect()
+    }
+
+    fn pos(row: u8, column: u8) -> Position<Base3> {
+        Position::try_from((row, column)).unwrap()
+    }
+
+    #[test]
+    fn test_synthetic_type_1() {
+        let mut grid = Grid::<Base3>::new();
+
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(1, 3)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+
+        let deductions = UniqueRectangle.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [(pos(1, 3), Action::delete_candidates(candidates(&[1, 2])))],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 3), Reason::candidates(candidates(&[1, 2]))),
+                (pos(1, 0), Reason::candidates(candidates(&[1, 2]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_type_2() {
+        let mut grid = Grid::<Base3>::new();
+
+        // Floor
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Roof
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        grid[pos(1, 3)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        // Sees both roof cells
+        grid[pos(4, 3)] = Cell::with_candidates(candidates(&[3, 4]));
+        grid[pos(2, 5)] = Cell::with_candidates(candidates(&[3, 4]));
+        // Sees only one roof cell
+        grid[pos(0, 8)] = Cell::with_candidates(candidates(&[3, 4]));
+        // Prevent type 4 in column 3 and block 1
+        grid[pos(5, 3)] = Cell::with_candidates(candidates(&[1, 2, 6]));
+        grid[pos(2, 4)] = Cell::with_candidates(candidates(&[1, 2, 6]));
+
+        let deductions = UniqueRectangle.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [
+                (pos(2, 5), Action::delete_candidate(3.try_into().unwrap())),
+                (pos(4, 3), Action::delete_candidate(3.try_into().unwrap())),
+            ],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(1, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 3), Reason::candidates(candidates(&[1, 2, 3]))),
+                (pos(1, 3), Reason::candidates(candidates(&[1, 2, 3]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_type_3() {
+        let mut grid = Grid::<Base3>::new();
+
+        // Floor
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Roof, forming the virtual cell `34` in row 1
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        grid[pos(1, 3)] = Cell::with_candidates(candidates(&[1, 2, 4]));
+        // Naked pair with the virtual cell
+        grid[pos(1, 6)] = Cell::with_candidates(candidates(&[3, 4]));
+        // Eliminations in row 1
+        grid[pos(1, 8)] = Cell::with_candidates(candidates(&[3, 5]));
+        // Prevent type 4 in row 1
+        grid[pos(1, 7)] = Cell::with_candidates(candidates(&[1, 2, 6]));
+
+        let deductions = UniqueRectangle.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [(pos(1, 8), Action::delete_candidate(3.try_into().unwrap()))],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 3), Reason::candidates(candidates(&[1, 2]))),
+                (pos(1, 0), Reason::candidates(candidates(&[1, 2, 3]))),
+                (pos(1, 3), Reason::candidates(candidates(&[1, 2, 4]))),
+                (pos(1, 6), Reason::candidates(candidates(&[3, 4]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_synthetic_type_4() {
+        let mut grid = Grid::<Base3>::new();
+
+        // Floor
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Roof, the candidate 1 is restricted to the roof in row 1
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        grid[pos(1, 3)] = Cell::with_candidates(candidates(&[1, 2, 5]));
+        grid[pos(1, 6)] = Cell::with_candidates(candidates(&[2, 6, 7]));
+
+        let deductions = UniqueRectangle.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [
+                (pos(1, 0), Action::delete_candidate(2.try_into().unwrap())),
+                (pos(1, 3), Action::delete_candidate(2.try_into().unwrap())),
+            ],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 3), Reason::candidates(candidates(&[1, 2]))),
+                (pos(1, 0), Reason::candidate(1.try_into().unwrap())),
+                (pos(1, 3), Reason::candidate(1.try_into().unwrap())),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    #[test]
+    fn test_ignores_rectangle_in_single_block() {
+        let mut grid = Grid::<Base3>::new();
+
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(0, 1)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(1, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(1, 1)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+
+        assert!(UniqueRectangle.execute(&grid).unwrap().is_empty());
+    }
+
+    strategy_snapshot_tests!(UniqueRectangle);
+}
@@ -0,0 +1,172 @@
+use itertools::Itertools;
+
+use crate::base::SudokuBase;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::{Action, Deduction, Deductions, Reason};
+use crate::solver::strategic::strategies::impls::links::strong_links;
+use crate::solver::strategic::strategies::impls::xy_wing::bivalue_cells;
+use crate::solver::strategic::strategies::{Strategy, StrategyScore};
+
+/*
+Logic:
+
+A W-Wing consists of:
+- Two bivalue cells with identical candidates `xy`, which don't see each other.
+- A strong link on `x`, where one end sees the first bivalue cell and the other end sees the second bivalue cell.
+
+One end of the strong link must be `x`, so the bivalue cell it sees can't be `x` and therefore must be `y`.
+Therefore at least one of the bivalue cells must be `y`.
+
+Eliminations: the candidate `y` in all cells which see both bivalue cells.
+*/
+
+/// An implementation of the [W-Wing](https://www.sudokuwiki.org/W_Wing_Strategy) strategy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WWing;
+
+impl Strategy for WWing {
+    fn name(self) -> &'static str {
+        "WWing"
+    }
+
+    fn score(self) -> StrategyScore {
+        150
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let bivalue_cells = bivalue_cells(grid);
+        let candidate_to_strong_links = Value::<Base>::all()
+            .map(|candidate| strong_links(grid, candidate))
+            .collect_vec();
+
+        Ok(bivalue_cells
+            .iter()
+            .tuple_combinations()
+            .filter(|((pos_1, candidates_1), (pos_2, candidates_2))| {
+                candidates_1 == candidates_2 && !pos_1.sees(*pos_2)
+            })
+            .flat_map(|(&(pos_1, candidates), &(pos_2, _))| {
+                let [candidate_1, candidate_2] = candidates
+                    .to_vec_value()
+                    .try_into()
+                    .expect("bivalue cell to contain exactly two candidates");
+                let candidate_to_strong_links = &candidate_to_strong_links;
+
+                [(candidate_1, candidate_2), (candidate_2, candidate_1)]
+                    .into_iter()
+                    .flat_map(move |(x, y)| {
+                        candidate_to_strong_links[usize::from(x.get() - 1)]
+                            .iter()
+                            .flat_map(|strong_link| strong_link.orientations())
+                            .filter(move |&(link_start, link_end)| {
+                                ![pos_1, pos_2].contains(&link_start)
+                                    && ![pos_1, pos_2].contains(&link_end)
+                                    && link_start.sees(pos_1)
+                                    && link_end.sees(pos_2)
+                            })
+                            .filter_map(move |(link_start, link_end)| {
+                                w_wing_deduction(
+                                    grid,
+                                    [pos_1, pos_2],
+                                    (x, y),
+                                    [link_start, link_end],
+                                )
+                            })
+                    })
+            })
+            .collect())
+    }
+}
+
+fn w_wing_deduction<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    [pos_1, pos_2]: [Position<Base>; 2],
+    (x, y): (Value<Base>, Value<Base>),
+    link_positions: [Position<Base>; 2],
+) -> Option<Deduction<Base>> {
+    let positions_to_delete = Position::<Base>::all()
+        .filter(|&pos| {
+            pos_1.sees(pos)
+                && pos_2.sees(pos)
+                && grid[pos]
+                    .candidates()
+                    .is_some_and(|candidates| candidates.has(y))
+        })
+        .collect_vec();
+
+    if positions_to_delete.is_empty() {
+        return None;
+    }
+
+    let bivalue_candidates = [x, y].into_iter().collect();
+
+    Some(
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(y))),
+            [pos_1, pos_2]
+                .into_iter()
+                .map(|pos| (pos, Reason::candidates(bivalue_candidates)))
+                .chain(
+                    link_positions
+                        .into_iter()
+                        .map(|pos| (pos, Reason::candidate(x))),
+                ),
+        )
+        .unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Candidates, Cell};
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    #[test]
+    fn test_synthetic_w_wing() {
+        let mut grid = Grid::<Base3>::new();
+        let candidates = |candidates: &[u8]| -> Candidates<Base3> {
+            candidates
+                .iter()
+                .map(|&candidate| Value::try_from(candidate).unwrap())
+                .collect()
+        };
+        let pos = |row: u8, column: u8| Position::<Base3>::try_from((row, column)).unwrap();
+
+        // Bivalue cells
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        grid[pos(4, 3)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Strong link on 1 in row 8
+        grid[pos(8, 0)] = Cell::with_candidates(candidates(&[1, 5]));
+        grid[pos(8, 3)] = Cell::with_candidates(candidates(&[1, 5]));
+        // Elimination: sees both bivalue cells
+        grid[pos(0, 3)] = Cell::with_candidates(candidates(&[2, 5]));
+        // Sees only one bivalue cell
+        grid[pos(0, 8)] = Cell::with_candidates(candidates(&[2, 5]));
+
+        let deductions = WWing.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [(pos(0, 3), Action::delete_candidate(2.try_into().unwrap()))],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(4, 3), Reason::candidates(candidates(&[1, 2]))),
+                (pos(8, 0), Reason::candidates(candidates(&[1]))),
+                (pos(8, 3), Reason::candidates(candidates(&[1]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    strategy_snapshot_tests!(WWing);
+}
@@ -218,13 +218,13 @@ fn find_x_wing_candidate<Base: SudokuBase>(
 
 /// For a single candidate, where in each group is this candidate set?
 #[derive(Debug, Clone, Default)]
-struct GroupCandidateIndexes<Base: SudokuBase> {
+pub(super) struct GroupCandidateIndexes<Base: SudokuBase> {
     rows: CandidatesGroup<Base>,
     columns: CandidatesGroup<Base>,
 }
 
 impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
-    fn with_grid(grid: &Grid<Base>) -> Vec<Self> {
+    pub(super) fn with_grid(grid: &Grid<Base>) -> Vec<Self> {
         let mut candidate_to_group_candidate_indexes =
             vec![GroupCandidateIndexes::<Base>::default(); usize::from(Base::SIDE_LENGTH)];
 
@@ -250,7 +250,7 @@ impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
         candidate_to_group_candidate_indexes
     }
 
-    fn axis(&self, axis: Axis) -> &CandidatesGroup<Base> {
+    pub(super) fn axis(&self, axis: Axis) -> &CandidatesGroup<Base> {
         match axis {
             Axis::Row => &self.rows,
             Axis::Column => &self.columns,
@@ -259,20 +259,20 @@ impl<Base: SudokuBase> GroupCandidateIndexes<Base> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Axis {
+pub(super) enum Axis {
     Row,
     Column,
 }
 
 impl Axis {
-    fn other(self) -> Self {
+    pub(super) fn other(self) -> Self {
         match self {
             Axis::Row => Axis::Column,
             Axis::Column => Axis::Row,
         }
     }
 
-    fn coordinates_to_pos<Base: SudokuBase>(
+    pub(super) fn coordinates_to_pos<Base: SudokuBase>(
         self,
         axis_coordinate: Coordinate<Base>,
         other_axis_coordinate: Coordinate<Base>,
@@ -0,0 +1,199 @@
+use crate::base::SudokuBase;
+use crate::cell::Candidates;
+use crate::cell::Value;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::solver::strategic::deduction::Action;
+use crate::solver::strategic::deduction::Deduction;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::deduction::Reason;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use itertools::Itertools;
+
+/*
+Logic:
+
+A XY-Wing consists of three bivalue cells:
+- Pivot: candidates `xy`
+- Pincer 1: candidates `xz`, sees the pivot
+- Pincer 2: candidates `yz`, sees the pivot
+
+If the pivot is `x`, pincer 1 is `z`. If the pivot is `y`, pincer 2 is `z`.
+In both cases, one of the pincers is `z`.
+
+Eliminations: the candidate `z` in all cells which see both pincers.
+*/
+
+/// An implementation of the [XY-Wing](https://www.sudokuwiki.org/Y_Wing_Strategy) strategy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct XyWing;
+
+impl Strategy for XyWing {
+    fn name(self) -> &'static str {
+        "XyWing"
+    }
+
+    fn score(self) -> StrategyScore {
+        250
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let bivalue_cells = bivalue_cells(grid);
+
+        Ok(bivalue_cells
+            .iter()
+            .flat_map(|&(pivot_pos, pivot_candidates)| {
+                let pincers = bivalue_cells
+                    .iter()
+                    .copied()
+                    .filter(|&(pincer_pos, pincer_candidates)| {
+                        pivot_pos.sees(pincer_pos)
+                            && pivot_candidates.intersection(pincer_candidates).is_single()
+                    })
+                    .collect_vec();
+
+                pincers
+                    .into_iter()
+                    .tuple_combinations()
+                    .filter_map(move |(pincer_1, pincer_2)| {
+                        let (_, pincer_1_candidates) = pincer_1;
+                        let (_, pincer_2_candidates) = pincer_2;
+
+                        // The pincers must cover different candidates of the pivot.
+                        if pincer_1_candidates.intersection(pivot_candidates)
+                            == pincer_2_candidates.intersection(pivot_candidates)
+                        {
+                            return None;
+                        }
+
+                        // The pincers must share the candidate `z`, which is not part of the pivot.
+                        let z = pincer_1_candidates
+                            .intersection(pincer_2_candidates)
+                            .without(pivot_candidates)
+                            .to_single()?;
+
+                        wing_deduction(
+                            grid,
+                            z,
+                            [(pivot_pos, pivot_candidates), pincer_1, pincer_2],
+                            &[pincer_1.0, pincer_2.0],
+                        )
+                    })
+            })
+            .collect())
+    }
+}
+
+/// All cells with exactly two candidates.
+pub(super) fn bivalue_cells<Base: SudokuBase>(
+    grid: &Grid<Base>,
+) -> Vec<(Position<Base>, Candidates<Base>)> {
+    cells_with_candidates_count(grid, 2)
+}
+
+/// All cells with exactly `count` candidates.
+pub(super) fn cells_with_candidates_count<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    count: u8,
+) -> Vec<(Position<Base>, Candidates<Base>)> {
+    Position::<Base>::all()
+        .filter_map(|pos| {
+            grid[pos]
+                .candidates()
+                .filter(|candidates| candidates.count() == count)
+                .map(|candidates| (pos, candidates))
+        })
+        .collect()
+}
+
+/// Delete the candidate `z` from all cells which see every position in `eliminating_positions`.
+///
+/// The cells of the wing are used as the reason.
+pub(super) fn wing_deduction<Base: SudokuBase>(
+    grid: &Grid<Base>,
+    z: Value<Base>,
+    wing_cells: impl IntoIterator<Item = (Position<Base>, Candidates<Base>)>,
+    eliminating_positions: &[Position<Base>],
+) -> Option<Deduction<Base>> {
+    let positions_to_delete = Position::<Base>::all()
+        .filter(|&pos| {
+            grid[pos]
+                .candidates()
+                .is_some_and(|candidates| candidates.has(z))
+                && eliminating_positions
+                    .iter()
+                    .all(|&eliminating_pos| eliminating_pos.sees(pos))
+        })
+        .collect_vec();
+
+    if positions_to_delete.is_empty() {
+        return None;
+    }
+
+    Some(
+        Deduction::try_from_iters(
+            positions_to_delete
+                .into_iter()
+                .map(|pos| (pos, Action::delete_candidate(z))),
+            wing_cells
+                .into_iter()
+                .map(|(pos, candidates)| (pos, Reason::candidates(candidates))),
+        )
+        .unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::Cell;
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    #[test]
+    fn test_synthetic_xy_wing() {
+        let mut grid = Grid::<Base3>::new();
+        let candidates = |candidates: &[u8]| -> Candidates<Base3> {
+            candidates
+                .iter()
+                .map(|&candidate| Value::try_from(candidate).unwrap())
+                .collect()
+        };
+        let pos = |row: u8, column: u8| Position::<Base3>::try_from((row, column)).unwrap();
+
+        // Pivot
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2]));
+        // Pincer sharing the row with the pivot
+        grid[pos(0, 6)] = Cell::with_candidates(candidates(&[1, 3]));
+        // Pincer sharing the block with the pivot
+        grid[pos(2, 1)] = Cell::with_candidates(candidates(&[2, 3]));
+        // Eliminations: sees both pincers
+        grid[pos(2, 7)] = Cell::with_candidates(candidates(&[3, 4, 5]));
+        grid[pos(0, 2)] = Cell::with_candidates(candidates(&[3, 4, 5]));
+        // Sees only one pincer
+        grid[pos(2, 4)] = Cell::with_candidates(candidates(&[3, 4, 5]));
+
+        let deductions = XyWing.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [
+                (pos(0, 2), Action::delete_candidate(3.try_into().unwrap())),
+                (pos(2, 7), Action::delete_candidate(3.try_into().unwrap())),
+            ],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2]))),
+                (pos(0, 6), Reason::candidates(candidates(&[1, 3]))),
+                (pos(2, 1), Reason::candidates(candidates(&[2, 3]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    strategy_snapshot_tests!(XyWing);
+}
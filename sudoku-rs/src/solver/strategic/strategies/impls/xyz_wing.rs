@@ -0,0 +1,130 @@
+use crate::base::SudokuBase;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solver::strategic::deduction::Deductions;
+use crate::solver::strategic::strategies::Strategy;
+use crate::solver::strategic::strategies::StrategyScore;
+use crate::solver::strategic::strategies::impls::xy_wing::{
+    bivalue_cells, cells_with_candidates_count, wing_deduction,
+};
+use itertools::Itertools;
+
+/*
+Logic:
+
+A XYZ-Wing extends the XY-Wing by adding `z` to the pivot:
+- Pivot: candidates `xyz`
+- Pincer 1: candidates `xz`, sees the pivot
+- Pincer 2: candidates `yz`, sees the pivot
+
+One of the three cells must be `z`.
+
+Eliminations: the candidate `z` in all cells which see the pivot and both pincers.
+*/
+
+/// An implementation of the [XYZ-Wing](https://www.sudokuwiki.org/XYZ_Wing) strategy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct XyzWing;
+
+impl Strategy for XyzWing {
+    fn name(self) -> &'static str {
+        "XyzWing"
+    }
+
+    fn score(self) -> StrategyScore {
+        280
+    }
+
+    fn execute<Base: SudokuBase>(self, grid: &Grid<Base>) -> Result<Deductions<Base>> {
+        let bivalue_cells = bivalue_cells(grid);
+
+        Ok(cells_with_candidates_count(grid, 3)
+            .into_iter()
+            .flat_map(|(pivot_pos, pivot_candidates)| {
+                let pincers = bivalue_cells
+                    .iter()
+                    .copied()
+                    .filter(|&(pincer_pos, pincer_candidates)| {
+                        pivot_pos.sees(pincer_pos)
+                            && pincer_candidates.without(pivot_candidates).is_empty()
+                    })
+                    .collect_vec();
+
+                pincers
+                    .into_iter()
+                    .tuple_combinations()
+                    .filter_map(move |(pincer_1, pincer_2)| {
+                        let (pincer_1_pos, pincer_1_candidates) = pincer_1;
+                        let (pincer_2_pos, pincer_2_candidates) = pincer_2;
+
+                        // The pincers must cover all candidates of the pivot.
+                        if pincer_1_candidates.union(pincer_2_candidates) != pivot_candidates {
+                            return None;
+                        }
+
+                        let z = pincer_1_candidates
+                            .intersection(pincer_2_candidates)
+                            .to_single()?;
+
+                        wing_deduction(
+                            grid,
+                            z,
+                            [(pivot_pos, pivot_candidates), pincer_1, pincer_2],
+                            &[pivot_pos, pincer_1_pos, pincer_2_pos],
+                        )
+                    })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::consts::*;
+    use crate::cell::{Candidates, Cell, Value};
+    use crate::position::Position;
+    use crate::solver::strategic::deduction::{Action, Deduction, Reason};
+    use crate::solver::strategic::strategies::test_util::{
+        assert_deductions, strategy_snapshot_tests,
+    };
+
+    #[test]
+    fn test_synthetic_xyz_wing() {
+        let mut grid = Grid::<Base3>::new();
+        let candidates = |candidates: &[u8]| -> Candidates<Base3> {
+            candidates
+                .iter()
+                .map(|&candidate| Value::try_from(candidate).unwrap())
+                .collect()
+        };
+        let pos = |row: u8, column: u8| Position::<Base3>::try_from((row, column)).unwrap();
+
+        // Pivot
+        grid[pos(0, 0)] = Cell::with_candidates(candidates(&[1, 2, 3]));
+        // Pincer sharing the row with the pivot
+        grid[pos(0, 6)] = Cell::with_candidates(candidates(&[1, 3]));
+        // Pincer sharing the block with the pivot
+        grid[pos(2, 1)] = Cell::with_candidates(candidates(&[2, 3]));
+        // Elimination: sees the pivot and both pincers
+        grid[pos(0, 2)] = Cell::with_candidates(candidates(&[3, 4, 5]));
+        // Sees both pincers, but not the pivot
+        grid[pos(2, 7)] = Cell::with_candidates(candidates(&[3, 4, 5]));
+
+        let deductions = XyzWing.execute(&grid).unwrap();
+
+        let expected_deductions = Deduction::try_from_iters(
+            [(pos(0, 2), Action::delete_candidate(3.try_into().unwrap()))],
+            [
+                (pos(0, 0), Reason::candidates(candidates(&[1, 2, 3]))),
+                (pos(0, 6), Reason::candidates(candidates(&[1, 3]))),
+                (pos(2, 1), Reason::candidates(candidates(&[2, 3]))),
+            ],
+        )
+        .unwrap();
+
+        assert_deductions(&deductions, &expected_deductions.into());
+    }
+
+    strategy_snapshot_tests!(XyzWing);
+}
@@ -25,6 +25,13 @@ pub trait Strategy: Debug + Copy + Clone + Eq + Sized {
     /// Higher scores are more difficult.
     fn score(self) -> StrategyScore;
 
+    /// Whether this strategy assumes that the grid has a unique solution.
+    ///
+    /// Such strategies produce invalid deductions for grids with multiple solutions.
+    fn requires_unique_solution(self) -> bool {
+        false
+    }
+
     // TODO: optimize with param: enable reasons
     //  reasons are only needed for debugging and hinting, not for strategic generation.
 
@@ -46,6 +53,7 @@ pub trait Strategy: Debug + Copy + Clone + Eq + Sized {
 #[cfg(test)]
 mod test_util {
     use crate::base::SudokuBase;
+    use crate::cell::Cell;
     use crate::grid::Grid;
     use crate::solver::strategic::deduction::Deductions;
 
@@ -66,6 +74,16 @@ mod test_util {
         );
     }
 
+    /// Converts each value of `grid` into a cell with the value as its only candidate.
+    ///
+    /// Allows writing candidate fixtures for a single digit as a values grid.
+    pub(crate) fn values_to_candidates<Base: SudokuBase>(mut grid: Grid<Base>) -> Grid<Base> {
+        for pos in grid.all_value_positions() {
+            grid[pos] = Cell::with_candidates(grid[pos].to_candidates());
+        }
+        grid
+    }
+
     pub(crate) fn assert_deductions_with_grid<Base: SudokuBase>(
         deductions: &Deductions<Base>,
         expected_deductions: &Deductions<Base>,
@@ -3,13 +3,15 @@ use crate::error::{Error, Result};
 use crate::solver::strategic::strategies::map::StrategyMap;
 use anyhow::format_err;
 use enum_dispatch::enum_dispatch;
+use itertools::Either;
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Debug;
 use std::fmt::{self, Display};
+use std::iter;
 use std::str::FromStr;
 
-const STRATEGY_COUNT: usize = 9;
+const STRATEGY_COUNT: usize = 29;
 
 pub mod map {
     use super::*;
@@ -26,7 +28,27 @@ pub mod map {
         pub group_intersection_block_to_axis: T,
         pub group_intersection_axis_to_block: T,
         pub group_intersection_both: T,
+        pub skyscraper: T,
+        pub two_string_kite: T,
+        pub empty_rectangle: T,
+        pub w_wing: T,
         pub x_wing: T,
+        pub xy_wing: T,
+        pub xyz_wing: T,
+        pub fish: T,
+        pub simple_coloring: T,
+        pub finned_fish: T,
+        pub multi_coloring: T,
+        pub x_chain: T,
+        pub xy_chain: T,
+        pub alternating_inference_chain: T,
+        pub sue_de_coq: T,
+        pub als_xz: T,
+        pub als_xy_wing: T,
+        pub death_blossom: T,
+        pub unique_rectangle: T,
+        pub bug_plus_one: T,
+        pub forcing_chains: T,
         pub brute_force: T,
     }
 
@@ -43,7 +65,27 @@ pub mod map {
                 group_intersection_block_to_axis: value,
                 group_intersection_axis_to_block: value,
                 group_intersection_both: value,
+                skyscraper: value,
+                two_string_kite: value,
+                empty_rectangle: value,
+                w_wing: value,
                 x_wing: value,
+                xy_wing: value,
+                xyz_wing: value,
+                fish: value,
+                simple_coloring: value,
+                finned_fish: value,
+                multi_coloring: value,
+                x_chain: value,
+                xy_chain: value,
+                alternating_inference_chain: value,
+                sue_de_coq: value,
+                als_xz: value,
+                als_xy_wing: value,
+                death_blossom: value,
+                unique_rectangle: value,
+                bug_plus_one: value,
+                forcing_chains: value,
                 brute_force: value,
             }
         }
@@ -61,7 +103,27 @@ pub mod map {
                     &self.group_intersection_axis_to_block
                 }
                 StrategyEnum::GroupIntersectionBoth(_) => &self.group_intersection_both,
+                StrategyEnum::Skyscraper(_) => &self.skyscraper,
+                StrategyEnum::TwoStringKite(_) => &self.two_string_kite,
+                StrategyEnum::EmptyRectangle(_) => &self.empty_rectangle,
+                StrategyEnum::WWing(_) => &self.w_wing,
                 StrategyEnum::XWing(_) => &self.x_wing,
+                StrategyEnum::XyWing(_) => &self.xy_wing,
+                StrategyEnum::XyzWing(_) => &self.xyz_wing,
+                StrategyEnum::Fish(_) => &self.fish,
+                StrategyEnum::SimpleColoring(_) => &self.simple_coloring,
+                StrategyEnum::FinnedFish(_) => &self.finned_fish,
+                StrategyEnum::MultiColoring(_) => &self.multi_coloring,
+                StrategyEnum::XChain(_) => &self.x_chain,
+                StrategyEnum::XyChain(_) => &self.xy_chain,
+                StrategyEnum::AlternatingInferenceChain(_) => &self.alternating_inference_chain,
+                StrategyEnum::SueDeCoq(_) => &self.sue_de_coq,
+                StrategyEnum::AlsXz(_) => &self.als_xz,
+                StrategyEnum::AlsXyWing(_) => &self.als_xy_wing,
+                StrategyEnum::DeathBlossom(_) => &self.death_blossom,
+                StrategyEnum::UniqueRectangle(_) => &self.unique_rectangle,
+                StrategyEnum::BugPlusOne(_) => &self.bug_plus_one,
+                StrategyEnum::ForcingChains(_) => &self.forcing_chains,
                 StrategyEnum::BruteForce(_) => &self.brute_force,
             }
         }
@@ -78,7 +140,27 @@ pub mod map {
                     &mut self.group_intersection_axis_to_block
                 }
                 StrategyEnum::GroupIntersectionBoth(_) => &mut self.group_intersection_both,
+                StrategyEnum::Skyscraper(_) => &mut self.skyscraper,
+                StrategyEnum::TwoStringKite(_) => &mut self.two_string_kite,
+                StrategyEnum::EmptyRectangle(_) => &mut self.empty_rectangle,
+                StrategyEnum::WWing(_) => &mut self.w_wing,
                 StrategyEnum::XWing(_) => &mut self.x_wing,
+                StrategyEnum::XyWing(_) => &mut self.xy_wing,
+                StrategyEnum::XyzWing(_) => &mut self.xyz_wing,
+                StrategyEnum::Fish(_) => &mut self.fish,
+                StrategyEnum::SimpleColoring(_) => &mut self.simple_coloring,
+                StrategyEnum::FinnedFish(_) => &mut self.finned_fish,
+                StrategyEnum::MultiColoring(_) => &mut self.multi_coloring,
+                StrategyEnum::XChain(_) => &mut self.x_chain,
+                StrategyEnum::XyChain(_) => &mut self.xy_chain,
+                StrategyEnum::AlternatingInferenceChain(_) => &mut self.alternating_inference_chain,
+                StrategyEnum::SueDeCoq(_) => &mut self.sue_de_coq,
+                StrategyEnum::AlsXz(_) => &mut self.als_xz,
+                StrategyEnum::AlsXyWing(_) => &mut self.als_xy_wing,
+                StrategyEnum::DeathBlossom(_) => &mut self.death_blossom,
+                StrategyEnum::UniqueRectangle(_) => &mut self.unique_rectangle,
+                StrategyEnum::BugPlusOne(_) => &mut self.bug_plus_one,
+                StrategyEnum::ForcingChains(_) => &mut self.forcing_chains,
                 StrategyEnum::BruteForce(_) => &mut self.brute_force,
             }
         }
@@ -92,7 +174,27 @@ pub mod map {
                 self.group_intersection_block_to_axis,
                 self.group_intersection_axis_to_block,
                 self.group_intersection_both,
+                self.skyscraper,
+                self.two_string_kite,
+                self.empty_rectangle,
+                self.w_wing,
                 self.x_wing,
+                self.xy_wing,
+                self.xyz_wing,
+                self.fish,
+                self.simple_coloring,
+                self.finned_fish,
+                self.multi_coloring,
+                self.x_chain,
+                self.xy_chain,
+                self.alternating_inference_chain,
+                self.sue_de_coq,
+                self.als_xz,
+                self.als_xy_wing,
+                self.death_blossom,
+                self.unique_rectangle,
+                self.bug_plus_one,
+                self.forcing_chains,
                 self.brute_force,
             ]
         }
@@ -135,9 +237,12 @@ pub mod selection {
 
     impl StrategySelection for StrategySet {
         /// Iterates over the selected strategies in "canonical" (`StrategyEnum::all()`) order.
+        ///
+        /// Parameterized strategies are expanded, see `StrategyEnum::expand`.
         fn iter_strategies(&self) -> impl Iterator<Item = StrategyEnum> {
             self.into_iter()
                 .filter_map(|(strategy, selected)| selected.then_some(strategy))
+                .flat_map(StrategyEnum::expand)
         }
     }
 
@@ -172,14 +277,32 @@ pub mod selection {
             self.count() == 0
         }
 
+        /// `NakedPairs` is not selected, since `LockedSets` includes naked pairs.
+        /// `XWing` is not selected, since `Fish` includes X-Wings.
         pub const fn default_solver_strategies() -> Self {
             StrategySet {
                 naked_singles: true,
                 hidden_singles: true,
-                naked_pairs: true,
                 locked_sets: true,
                 group_intersection_both: true,
-                x_wing: true,
+                skyscraper: true,
+                two_string_kite: true,
+                empty_rectangle: true,
+                w_wing: true,
+                xy_wing: true,
+                xyz_wing: true,
+                fish: true,
+                simple_coloring: true,
+                finned_fish: true,
+                multi_coloring: true,
+                x_chain: true,
+                xy_chain: true,
+                alternating_inference_chain: true,
+                sue_de_coq: true,
+                als_xz: true,
+                als_xy_wing: true,
+                death_blossom: true,
+                forcing_chains: true,
                 brute_force: true,
                 ..StrategySet::with_all(false)
             }
@@ -190,6 +313,19 @@ pub mod selection {
             strategies.brute_force = false;
             strategies
         }
+
+        /// Removes all strategies, which require the grid to have a unique solution.
+        ///
+        /// Used for grids without a uniqueness guarantee, e.g. imported grids.
+        #[must_use]
+        pub fn without_uniqueness_strategies(mut self) -> Self {
+            for strategy in StrategyEnum::all() {
+                if strategy.requires_unique_solution() {
+                    *self.get_mut(strategy) = false;
+                }
+            }
+            self
+        }
     }
 
     impl FromIterator<StrategyEnum> for StrategySet {
@@ -203,7 +339,6 @@ pub mod selection {
     }
 }
 
-#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
 #[enum_dispatch]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u8)]
@@ -215,7 +350,27 @@ pub enum StrategyEnum {
     GroupIntersectionBlockToAxis,
     GroupIntersectionAxisToBlock,
     GroupIntersectionBoth,
+    Skyscraper,
+    TwoStringKite,
+    EmptyRectangle,
+    WWing,
     XWing,
+    XyWing,
+    XyzWing,
+    Fish,
+    SimpleColoring,
+    FinnedFish,
+    MultiColoring,
+    XChain,
+    XyChain,
+    AlternatingInferenceChain,
+    SueDeCoq,
+    AlsXz,
+    AlsXyWing,
+    DeathBlossom,
+    UniqueRectangle,
+    BugPlusOne,
+    ForcingChains,
     BruteForce,
 }
 
@@ -231,15 +386,73 @@ impl StrategyEnum {
             NakedSingles.into(),
             HiddenSingles.into(),
             NakedPairs.into(),
-            LockedSets.into(),
+            LockedSets::default().into(),
             GroupIntersectionBlockToAxis.into(),
             GroupIntersectionAxisToBlock.into(),
             GroupIntersectionBoth.into(),
+            Skyscraper.into(),
+            TwoStringKite.into(),
+            EmptyRectangle.into(),
+            WWing.into(),
             XWing.into(),
+            XyWing.into(),
+            XyzWing.into(),
+            Fish.into(),
+            SimpleColoring.into(),
+            FinnedFish.into(),
+            MultiColoring.into(),
+            XChain::default().into(),
+            XyChain::default().into(),
+            AlternatingInferenceChain::default().into(),
+            SueDeCoq.into(),
+            AlsXz.into(),
+            AlsXyWing.into(),
+            DeathBlossom.into(),
+            UniqueRectangle.into(),
+            BugPlusOne.into(),
+            ForcingChains::default().into(),
             BruteForce.into(),
         ]
     }
 
+    /// The strategies executed, if this strategy is selected in a `StrategySet`.
+    ///
+    /// `LockedSets` expands into all of its set sizes and types in ascending order of score,
+    /// so that e.g. naked pairs and hidden quads are scored separately.
+    pub fn expand(self) -> impl Iterator<Item = Self> {
+        match self {
+            StrategyEnum::LockedSets(locked_sets) if locked_sets.size().is_none() => {
+                Either::Left(LockedSets::all().map(Into::into))
+            }
+            strategy => Either::Right(iter::once(strategy)),
+        }
+    }
+
+    /// Whether the deductions of `other` are a subset of the deductions of `self`,
+    /// e.g. all `LockedSets` include naked triples, but hidden pairs don't.
+    /// `ForcingChains` include the forcing chains of smaller depths.
+    pub fn includes(self, other: Self) -> bool {
+        match (self, other) {
+            (StrategyEnum::LockedSets(locked_sets), StrategyEnum::LockedSets(other)) => {
+                locked_sets.includes(other)
+            }
+            (StrategyEnum::ForcingChains(forcing_chains), StrategyEnum::ForcingChains(other)) => {
+                forcing_chains.includes(other)
+            }
+            _ => self == other,
+        }
+    }
+
+    /// The names of all strategies, including the expanded ones, see [`Self::expand`].
+    ///
+    /// Serialization uses these names.
+    pub fn all_names() -> impl Iterator<Item = &'static str> {
+        Self::all()
+            .into_iter()
+            .chain(LockedSets::all().map(Into::into))
+            .map(Strategy::name)
+    }
+
     fn variant_index(&self) -> u32 {
         // Reference: https://doc.rust-lang.org/std/mem/fn.discriminant.html
 
@@ -284,12 +497,58 @@ impl<'de> Deserialize<'de> for StrategyEnum {
     }
 }
 
+#[cfg(feature = "wasm")]
+mod wasm {
+    use itertools::Itertools;
+
+    use super::*;
+
+    // Serialized by name, which includes the expanded strategies.
+    impl ::ts_rs::TS for StrategyEnum {
+        type WithoutGenerics = Self;
+        type OptionInnerType = Self;
+
+        fn name() -> String {
+            "StrategyEnum".to_owned()
+        }
+        fn decl_concrete() -> String {
+            format!("type {} = {};", Self::name(), Self::inline())
+        }
+        fn decl() -> String {
+            let inline = Self::inline();
+            format!("type {} = {inline};", Self::name())
+        }
+        fn inline() -> String {
+            StrategyEnum::all_names()
+                .map(|name| format!("\"{name}\""))
+                .join(" | ")
+        }
+        fn inline_flattened() -> String {
+            panic!("{} cannot be flattened", Self::name())
+        }
+        fn output_path() -> Option<std::path::PathBuf> {
+            Some(std::path::PathBuf::from(format!("{}.ts", Self::name())))
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn export_bindings_strategyenum() {
+        <StrategyEnum as ::ts_rs::TS>::export_all().expect("could not export type");
+    }
+}
+
 impl FromStr for StrategyEnum {
     type Err = Error;
 
     fn from_str(strategy_name: &str) -> Result<Self> {
         StrategyEnum::all()
             .into_iter()
+            .chain(
+                StrategyEnum::all()
+                    .into_iter()
+                    .flat_map(StrategyEnum::expand),
+            )
             .find(|strategy| strategy.name() == strategy_name)
             .ok_or_else(|| format_err!("Unexpected strategy name: {strategy_name}"))
     }
@@ -297,11 +556,17 @@ impl FromStr for StrategyEnum {
 
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+
     use super::*;
+    use crate::solver::strategic::strategies::selection::{StrategySelection, StrategySet};
 
     #[test]
     fn test_serde_round_trip() {
-        let all_strategies = StrategyEnum::all().to_vec();
+        let all_strategies = StrategyEnum::all()
+            .into_iter()
+            .chain(LockedSets::all().map(Into::into))
+            .collect::<Vec<_>>();
 
         let json_string = serde_json::to_string(&all_strategies).unwrap();
 
@@ -310,4 +575,67 @@ mod tests {
 
         assert_eq!(all_strategies, all_strategies_round_tripped);
     }
+
+    #[test]
+    fn test_names_unique() {
+        assert!(StrategyEnum::all_names().all_unique());
+        assert_eq!(
+            StrategyEnum::all_names().count(),
+            STRATEGY_COUNT + LockedSets::all().count()
+        );
+    }
+
+    #[test]
+    fn test_includes() {
+        let locked_sets: StrategyEnum = LockedSets::default().into();
+        let naked_triples: StrategyEnum = LockedSets::naked(3).into();
+
+        assert!(locked_sets.includes(naked_triples));
+        assert!(naked_triples.includes(naked_triples));
+        assert!(!naked_triples.includes(locked_sets));
+        assert!(!naked_triples.includes(LockedSets::hidden(3).into()));
+        assert!(!locked_sets.includes(NakedPairs.into()));
+
+        let forcing_chains: StrategyEnum = ForcingChains::default().into();
+        assert!(forcing_chains.includes(ForcingChains::with_max_depth(0).into()));
+        assert!(!StrategyEnum::from(ForcingChains::with_max_depth(0)).includes(forcing_chains));
+    }
+
+    #[test]
+    fn test_without_uniqueness_strategies() {
+        let strategies = StrategySet::with_all(true).without_uniqueness_strategies();
+
+        assert_eq!(
+            strategies.iter_strategies().collect::<Vec<_>>(),
+            StrategyEnum::all()
+                .into_iter()
+                .flat_map(StrategyEnum::expand)
+                .filter(|strategy| !matches!(
+                    strategy,
+                    StrategyEnum::UniqueRectangle(_) | StrategyEnum::BugPlusOne(_)
+                ))
+                .collect::<Vec<_>>()
+        );
+        assert!(
+            !StrategySet::default_solver_strategies()
+                .iter_strategies()
+                .any(Strategy::requires_unique_solution)
+        );
+    }
+
+    #[test]
+    fn test_default_solver_strategies_unique() {
+        let strategies = StrategySet::default_solver_strategies()
+            .iter_strategies()
+            .collect::<Vec<_>>();
+
+        assert!(
+            !strategies
+                .iter()
+                .any(|&strategy| strategy == NakedPairs.into())
+        );
+        assert!(strategies.contains(&LockedSets::naked(2).into()));
+        assert!(!strategies.iter().any(|&strategy| strategy == XWing.into()));
+        assert!(strategies.contains(&Fish.into()));
+    }
 }
@@ -12,6 +12,7 @@ use crate::grid::format::GridFormatEnum;
 use crate::position::DynamicPosition;
 use crate::solver::strategic::DynamicSolveStep;
 use crate::solver::strategic::deduction::transport::TransportDeductions;
+use crate::solver::strategic::hint::{DynamicHint, HintLevel};
 use crate::sudoku::Sudoku;
 use crate::sudoku::settings::Settings as SudokuSettings;
 use crate::{
@@ -42,7 +43,13 @@ pub trait DynamicSudokuActions {
         &mut self,
         strategies: impl StrategySelection,
     ) -> Result<Option<DynamicSolveStep>>;
+    fn try_strategies_with_hint_level(
+        &mut self,
+        strategies: impl StrategySelection,
+        hint_level: HintLevel,
+    ) -> Result<Option<DynamicHint>>;
     fn apply_deductions(&mut self, deductions: TransportDeductions) -> Result<()>;
+    fn explain_step(&self, solve_step: DynamicSolveStep) -> Result<Vec<String>>;
 
     // actions that don't depend on base
     fn set_all_direct_candidates(&mut self);
@@ -12,6 +12,7 @@ use crate::grid::solution_state::SolutionState;
 use crate::position::{DynamicPosition, Position};
 use crate::solver::strategic::deduction::Deductions;
 use crate::solver::strategic::deduction::transport::TransportDeductions;
+use crate::solver::strategic::hint::{DynamicHint, Hint, HintLevel};
 use crate::solver::strategic::{DynamicSolveStep, SolveStep, Solver as StrategicSolver};
 use crate::{
     base::SudokuBase,
@@ -19,7 +20,8 @@ use crate::{
 };
 use crate::{
     cell::dynamic::{DynamicCandidates, DynamicValue},
-    solver::strategic::strategies::selection::StrategySelection,
+    solver::strategic::strategies::Strategy,
+    solver::strategic::strategies::selection::{StrategyList, StrategySelection},
 };
 use history::History;
 use log::info;
@@ -175,15 +177,41 @@ impl<Base: SudokuBase> Sudoku<Base> {
             self.push_history();
         }
 
+        let strategies = self.applicable_strategies(strategies);
         let solver = StrategicSolver::with_strategies(&mut self.grid, strategies);
         solver.try_strategies()
     }
 
+    /// Tries executing strategies like [`Sudoku::try_strategies`],
+    /// but only reveals the information of the solve step up to `hint_level`.
+    ///
+    /// For [`HintLevel::Apply`] the deductions are applied to the grid.
+    pub fn try_strategies_with_hint_level(
+        &mut self,
+        strategies: impl StrategySelection,
+        hint_level: HintLevel,
+    ) -> Result<Option<Hint<Base>>> {
+        let Some(solve_step) = self.try_strategies(strategies)? else {
+            return Ok(None);
+        };
+
+        if hint_level == HintLevel::Apply {
+            self.apply_deductions(&solve_step.deductions)?;
+        }
+
+        Ok(Some(solve_step.hint(hint_level)))
+    }
+
     pub fn apply_deductions(&mut self, deductions: &Deductions<Base>) -> Result<()> {
         self.push_history();
 
         deductions.apply(&mut self.grid)
     }
+
+    /// Natural-language explanations of `solve_step`, which must have been found on the current grid.
+    pub fn explain_step(&self, solve_step: &SolveStep<Base>) -> Vec<String> {
+        solve_step.explanations(&self.grid)
+    }
 }
 
 impl<Base: SudokuBase> DynamicSudokuActions for Sudoku<Base> {
@@ -263,10 +291,24 @@ impl<Base: SudokuBase> DynamicSudokuActions for Sudoku<Base> {
         Ok(self.try_strategies(strategies)?.map(Into::into))
     }
 
+    fn try_strategies_with_hint_level(
+        &mut self,
+        strategies: impl StrategySelection,
+        hint_level: HintLevel,
+    ) -> Result<Option<DynamicHint>> {
+        Ok(self
+            .try_strategies_with_hint_level(strategies, hint_level)?
+            .map(Into::into))
+    }
+
     fn apply_deductions(&mut self, deductions: TransportDeductions) -> Result<()> {
         self.apply_deductions(&deductions.try_into()?)
     }
 
+    fn explain_step(&self, solve_step: DynamicSolveStep) -> Result<Vec<String>> {
+        Ok(self.explain_step(&solve_step.try_into()?))
+    }
+
     // actions that don't depend on base
 
     fn set_all_direct_candidates(&mut self) {
@@ -310,7 +352,7 @@ impl<Base: SudokuBase> DynamicSudokuActions for Sudoku<Base> {
         metric: GridMetric,
         strategies: impl StrategySelection,
     ) -> Result<EvaluatedGridMetric> {
-        metric.evaluate(&self.grid, strategies)
+        metric.evaluate(&self.grid, self.applicable_strategies(strategies))
     }
 }
 
@@ -318,6 +360,19 @@ impl<Base: SudokuBase> Sudoku<Base> {
     fn push_history(&mut self) {
         self.history.push(self.grid.clone());
     }
+
+    /// The selected strategies, which are valid for this sudoku.
+    ///
+    /// Strategies requiring a unique solution are only offered,
+    /// if the solution state of the sudoku is known to be unique.
+    #[allow(clippy::needless_pass_by_value)]
+    fn applicable_strategies(&self, strategies: impl StrategySelection) -> StrategyList {
+        let is_unique = self.solution.is_unique();
+        strategies
+            .iter_strategies()
+            .filter(|strategy| is_unique || !strategy.requires_unique_solution())
+            .collect()
+    }
 }
 
 impl<Base: SudokuBase> Display for Sudoku<Base> {
@@ -329,6 +384,49 @@ impl<Base: SudokuBase> Display for Sudoku<Base> {
 
 #[cfg(test)]
 mod tests {
+    use crate::base::consts::*;
+    use crate::samples;
+    use crate::solver::strategic::strategies::selection::StrategySet;
+
+    use super::*;
+
     // TODO: test undo
     // TODO: test settings
+
+    #[test]
+    fn test_try_strategies_with_hint_level() {
+        let grid = samples::base_2().remove(0);
+        let strategies = StrategySet::default_solver_strategies_no_brute_force();
+
+        let mut sudoku = Sudoku::<Base2>::with_grid(grid);
+        let solve_step = sudoku.try_strategies(strategies).unwrap().unwrap();
+        let grid_with_candidates = sudoku.grid().clone();
+
+        for hint_level in [
+            HintLevel::Technique,
+            HintLevel::Region,
+            HintLevel::Deduction,
+        ] {
+            let hint = sudoku
+                .try_strategies_with_hint_level(strategies, hint_level)
+                .unwrap()
+                .unwrap();
+            assert_eq!(hint.level(), hint_level);
+            assert_eq!(hint.strategy(), solve_step.strategy);
+            assert_eq!(sudoku.grid(), &grid_with_candidates);
+        }
+
+        let Hint::Apply(applied_solve_step) = sudoku
+            .try_strategies_with_hint_level(strategies, HintLevel::Apply)
+            .unwrap()
+            .unwrap()
+        else {
+            panic!("Expected applied hint");
+        };
+        assert_eq!(applied_solve_step.deductions, solve_step.deductions);
+
+        let mut expected_grid = grid_with_candidates;
+        solve_step.deductions.apply(&mut expected_grid).unwrap();
+        assert_eq!(sudoku.grid(), &expected_grid);
+    }
 }
@@ -64,9 +64,7 @@ impl<Base: SudokuBase> From<&Sudoku<Base>> for TransportSudoku {
                 .map(|pos| {
                     let cell = grid.get(pos);
                     let incorrect_value = if cell.has_value() {
-                        unique_solution
-                            .as_ref()
-                            .is_some_and(|solved_grid| solved_grid.get(pos) != cell)
+                        unique_solution.is_some_and(|solution| cell.value() != Some(solution[pos]))
                     } else {
                         false
                     };
@@ -191,7 +191,7 @@ impl<Base: SudokuBase> DynamicCellWorldActions for CellWorld<Base> {
 
                 let grid_position = grid_positions[solver_stack.len() - 1];
 
-                self.set_grid_at_validated(&solution, grid_position);
+                self.set_grid_at_validated(&solution.to_grid(), grid_position);
 
                 if solver_stack.len() == grid_positions.len() {
                     // world generated
@@ -19,8 +19,10 @@ use sudoku::{
         DynamicSolveStep,
         deduction::transport::{
             PositionedTransportAction, PositionedTransportReason, TransportAction,
-            TransportDeduction, TransportDeductions, TransportReason,
+            TransportDeduction, TransportDeductions, TransportGroup, TransportLink,
+            TransportLinkType, TransportReason,
         },
+        hint::{DynamicHint, HintLevel},
         strategies::{StrategyEnum, selection::StrategySet},
     },
     transport::{TransportCell, TransportSudoku},
@@ -98,6 +100,7 @@ serde_wasm_bindgen_interop! {
     DynamicCell,
     DynamicGeneratorSettings,
     DynamicGrid,
+    DynamicHint,
     DynamicMultiShotGeneratorSettings,
     DynamicPosition,
     DynamicPruningOrder,
@@ -108,6 +111,7 @@ serde_wasm_bindgen_interop! {
     DynamicWorldGridCellPosition,
     GeneratorProgress,
     GridFormatEnum,
+    HintLevel,
     MultiShotGeneratorProgress,
     PositionedTransportAction,
     PositionedTransportReason,
@@ -120,6 +124,9 @@ serde_wasm_bindgen_interop! {
     TransportCell,
     TransportDeduction,
     TransportDeductions,
+    TransportGroup,
+    TransportLink,
+    TransportLinkType,
     TransportReason,
     TransportSudoku,
     WorldGenerationResult,
@@ -190,12 +190,33 @@ impl WasmSudoku {
             .transpose()
     }
 
+    #[wasm_bindgen(js_name = tryStrategiesWithHintLevel)]
+    pub fn try_strategies_with_hint_level(
+        &mut self,
+        strategies: IStrategySet,
+        hint_level: IHintLevel,
+    ) -> Result<Option<IDynamicHint>> {
+        let opt_dyn_hint = self.sudoku.try_strategies_with_hint_level(
+            import_strategy_set(strategies)?,
+            import_hint_level(hint_level)?,
+        )?;
+
+        opt_dyn_hint.map(export_dynamic_hint).transpose()
+    }
+
     #[wasm_bindgen(js_name = applyDeductions)]
     pub fn apply_deductions(&mut self, deductions: ITransportDeductions) -> Result<()> {
         self.sudoku
             .apply_deductions(import_transport_deductions(deductions)?)?;
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = explainStep)]
+    pub fn explain_step(&self, solve_step: IDynamicSolveStep) -> Result<Vec<String>> {
+        Ok(self
+            .sudoku
+            .explain_step(import_dynamic_solve_step(solve_step)?)?)
+    }
 }
 
 /// (De)serialization
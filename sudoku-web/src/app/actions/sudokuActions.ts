@@ -7,8 +7,10 @@ import type {
     DynamicGeneratorSettings,
     DynamicMultiShotGeneratorSettings,
     DynamicPosition,
+    DynamicSolveStep,
     GeneratorProgress,
     GridFormatEnum,
+    HintLevel,
     MultiShotGeneratorProgress,
     StrategySet,
     TransportDeductions,
@@ -564,6 +566,26 @@ export function useTryStrategies() {
     );
 }
 
+export function useTryStrategiesWithHintLevel() {
+    return useAtomCallback(
+        useCallback(async (get, set, strategies: StrategySet, hintLevel: HintLevel) => {
+            const wasmSudoku = await get(wasmSudokuState);
+            const res = await wasmSudoku.tryStrategiesWithHintLevel(strategies, hintLevel);
+            updateSudoku({ set, wasmSudoku });
+            return res;
+        }, []),
+    );
+}
+
+export function useExplainStep() {
+    return useAtomCallback(
+        useCallback(async (get, _set, solveStep: DynamicSolveStep) => {
+            const wasmSudoku = await get(wasmSudokuState);
+            return wasmSudoku.explainStep(solveStep);
+        }, []),
+    );
+}
+
 export function useApplyDeductions() {
     return useAtomCallback(
         useCallback(async (get, set, deductions: TransportDeductions) => {
@@ -74,11 +74,15 @@ export function HintSettingsDialog({ onClose }: HintSettingsDialogProps) {
                                         id: "apply",
                                         label: "Apply hint directly",
                                     },
+                                    {
+                                        id: "progressive",
+                                        label: "Reveal technique, region and deduction, then apply it",
+                                    },
                                 ] satisfies { id: HintSettings["mode"]; label: string }[]
                             }
                             required
                         />
-                        <Fieldset label="Loop" disabled={mode === "toggleHint"}>
+                        <Fieldset label="Loop" disabled={mode === "toggleHint" || mode === "progressive"}>
                             <MySwitch
                                 control={control}
                                 name="doLoop"
@@ -1,7 +1,7 @@
 import InfoOutlinedIcon from "@mui/icons-material/InfoOutlined";
 import { Stack, Tooltip } from "@mui/material";
 import type { Control, FieldPath, FieldValues } from "react-hook-form";
-import { ALL_STRATEGIES, STRATEGY_OPTIONS } from "../../constants";
+import { ALL_STRATEGIES_EXPANDED, STRATEGY_OPTIONS } from "../../constants";
 import { ExternalLink } from "../ExternalLink";
 import { MySelect } from "./mui-rhf/MySelect";
 
@@ -23,7 +23,7 @@ function SelectStrategy<
             control={control}
             name={name}
             label="Strategy"
-            options={ALL_STRATEGIES.map((strategy) => {
+            options={ALL_STRATEGIES_EXPANDED.map((strategy) => {
                 const option = STRATEGY_OPTIONS[strategy];
                 return {
                     id: strategy,
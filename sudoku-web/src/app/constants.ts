@@ -11,12 +11,45 @@ export const STRATEGY_NAMES = [
     { strategyEnum: "GroupIntersectionBlockToAxis", mapKey: "group_intersection_block_to_axis" },
     { strategyEnum: "GroupIntersectionAxisToBlock", mapKey: "group_intersection_axis_to_block" },
     { strategyEnum: "GroupIntersectionBoth", mapKey: "group_intersection_both" },
+    { strategyEnum: "Skyscraper", mapKey: "skyscraper" },
+    { strategyEnum: "TwoStringKite", mapKey: "two_string_kite" },
+    { strategyEnum: "EmptyRectangle", mapKey: "empty_rectangle" },
+    { strategyEnum: "WWing", mapKey: "w_wing" },
     { strategyEnum: "XWing", mapKey: "x_wing" },
+    { strategyEnum: "XyWing", mapKey: "xy_wing" },
+    { strategyEnum: "XyzWing", mapKey: "xyz_wing" },
+    { strategyEnum: "Fish", mapKey: "fish" },
+    { strategyEnum: "SimpleColoring", mapKey: "simple_coloring" },
+    { strategyEnum: "FinnedFish", mapKey: "finned_fish" },
+    { strategyEnum: "MultiColoring", mapKey: "multi_coloring" },
+    { strategyEnum: "XChain", mapKey: "x_chain" },
+    { strategyEnum: "XyChain", mapKey: "xy_chain" },
+    { strategyEnum: "AlternatingInferenceChain", mapKey: "alternating_inference_chain" },
+    { strategyEnum: "SueDeCoq", mapKey: "sue_de_coq" },
+    { strategyEnum: "AlsXz", mapKey: "als_xz" },
+    { strategyEnum: "AlsXyWing", mapKey: "als_xy_wing" },
+    { strategyEnum: "DeathBlossom", mapKey: "death_blossom" },
+    { strategyEnum: "UniqueRectangle", mapKey: "unique_rectangle" },
+    { strategyEnum: "BugPlusOne", mapKey: "bug_plus_one" },
+    { strategyEnum: "ForcingChains", mapKey: "forcing_chains" },
     { strategyEnum: "BruteForce", mapKey: "brute_force" },
 ] satisfies { strategyEnum: StrategyEnum; mapKey: keyof StrategyMap<boolean> }[];
 
-export const strategyEnumSchema = z.enum(STRATEGY_NAMES.map((s) => s.strategyEnum));
-export const ALL_STRATEGIES = strategyEnumSchema.options;
+export const selectableStrategyEnumSchema = z.enum(STRATEGY_NAMES.map((s) => s.strategyEnum));
+export const ALL_STRATEGIES = selectableStrategyEnumSchema.options;
+
+// A selected "LockedSets" expands into these strategies, which are reported separately, e.g. in hints.
+export const EXPANDED_STRATEGY_NAMES = [
+    "LockedNakedPairs",
+    "LockedHiddenPairs",
+    "LockedNakedTriples",
+    "LockedHiddenTriples",
+    "LockedNakedQuads",
+    "LockedHiddenQuads",
+] as const satisfies readonly StrategyEnum[];
+
+export const strategyEnumSchema = z.enum([...ALL_STRATEGIES, ...EXPANDED_STRATEGY_NAMES]);
+export const ALL_STRATEGIES_EXPANDED = strategyEnumSchema.options;
 
 export const strategyMapKeySchema = z.enum(STRATEGY_NAMES.map((s) => s.mapKey));
 
@@ -49,6 +82,36 @@ export const STRATEGY_OPTIONS: Record<
             "Inside a single row, column or box, are there any naked or hidden candidates of any size? (Naked/Hidden Pairs/Triples/Quads)",
         link: "https://www.sudokuwiki.org/Hidden_Candidates",
     },
+    LockedNakedPairs: {
+        label: "Locked Sets: Naked Pairs",
+        description: "Two cells in a row, column or box contain the same two candidates exclusively.",
+        link: "https://www.sudokuwiki.org/Naked_Candidates",
+    },
+    LockedHiddenPairs: {
+        label: "Locked Sets: Hidden Pairs",
+        description: "Two candidates are confined to the same two cells of a row, column or box.",
+        link: "https://www.sudokuwiki.org/Hidden_Candidates",
+    },
+    LockedNakedTriples: {
+        label: "Locked Sets: Naked Triples",
+        description: "Three cells in a row, column or box contain only candidates of the same three values.",
+        link: "https://www.sudokuwiki.org/Naked_Candidates#NT",
+    },
+    LockedHiddenTriples: {
+        label: "Locked Sets: Hidden Triples",
+        description: "Three candidates are confined to the same three cells of a row, column or box.",
+        link: "https://www.sudokuwiki.org/Hidden_Candidates#HT",
+    },
+    LockedNakedQuads: {
+        label: "Locked Sets: Naked Quads",
+        description: "Four cells in a row, column or box contain only candidates of the same four values.",
+        link: "https://www.sudokuwiki.org/Naked_Candidates#NQ",
+    },
+    LockedHiddenQuads: {
+        label: "Locked Sets: Hidden Quads",
+        description: "Four candidates are confined to the same four cells of a row, column or box.",
+        link: "https://www.sudokuwiki.org/Hidden_Candidates#HQ",
+    },
     GroupIntersectionBlockToAxis: {
         label: "Pointing Pairs/Triples",
         description: "In one box the same candidate is aligned in one row or column.",
@@ -64,11 +127,129 @@ export const STRATEGY_OPTIONS: Record<
         description: "A combination of pointing pairs/triples and box line reduction.",
         link: "https://www.sudokuwiki.org/Intersection_Removal",
     },
+    Skyscraper: {
+        label: "Skyscraper",
+        description: "Two strong links of a candidate in parallel rows or columns, whose starts share a column or row.",
+        link: "https://www.sudokuwiki.org/Skyscraper",
+    },
+    TwoStringKite: {
+        label: "2-String Kite",
+        description: "A strong link of a candidate in a row and one in a column, whose starts share a box.",
+        link: "https://www.sudokuwiki.org/2_String_Kite",
+    },
+    EmptyRectangle: {
+        label: "Empty Rectangle",
+        description:
+            "Is a candidate inside a box confined to a single row and column, which combined with a strong link eliminates the candidate?",
+        link: "https://www.sudokuwiki.org/Empty_Rectangles",
+    },
+    WWing: {
+        label: "W-Wing",
+        description:
+            "Two identical bivalue cells are connected by a strong link on one of their candidates, so one of them contains the other candidate.",
+        link: "https://www.sudokuwiki.org/W_Wing_Strategy",
+    },
     XWing: {
         label: "X-Wing",
         description: "A candidate appears in exactly two cells in two different rows and columns, forming a rectangle.",
         link: "https://www.sudokuwiki.org/X_Wing_Strategy",
     },
+    XyWing: {
+        label: "XY-Wing",
+        description:
+            "A bivalue pivot cell sees two bivalue pincers. Whichever value the pivot takes, one pincer contains the shared candidate.",
+        link: "https://www.sudokuwiki.org/Y_Wing_Strategy",
+    },
+    XyzWing: {
+        label: "XYZ-Wing",
+        description:
+            "A pivot cell with three candidates sees two bivalue pincers. One of the three cells contains the shared candidate.",
+        link: "https://www.sudokuwiki.org/XYZ_Wing",
+    },
+    Fish: {
+        label: "Swordfish/Jellyfish",
+        description:
+            "A candidate is restricted to N cells in N rows, which are aligned in N columns (or vice versa). Generalization of X-Wing to bigger sizes.",
+        link: "https://www.sudokuwiki.org/Sword_Fish_Strategy",
+    },
+    SimpleColoring: {
+        label: "Simple Coloring",
+        description:
+            "The strong links of a candidate form chains with two alternating colors. One color is true, which eliminates the candidate where both colors are seen or where a color sees itself.",
+        link: "https://www.sudokuwiki.org/Singles_Chains",
+    },
+    FinnedFish: {
+        label: "Finned/Sashimi Fish",
+        description:
+            "An X-Wing or Swordfish with additional candidates (fins) in one box. Eliminations are restricted to cells which see all fins.",
+        link: "https://www.sudokuwiki.org/Finned_X_Wing",
+    },
+    MultiColoring: {
+        label: "Multi Coloring",
+        description:
+            "Two colored chains of a candidate interact, where a color of one chain sees a color of the other chain.",
+        link: "https://www.sudokuwiki.org/Multi_Colouring",
+    },
+    XChain: {
+        label: "X-Chain",
+        description:
+            "A chain of strong and weak links on a single candidate. One of the ends of the chain contains the candidate.",
+        link: "https://www.sudokuwiki.org/X_Chains",
+    },
+    XyChain: {
+        label: "XY-Chain",
+        description:
+            "A chain of bivalue cells, connected by a shared candidate. One of the ends of the chain contains the candidate.",
+        link: "https://www.sudokuwiki.org/XY_Chains",
+    },
+    AlternatingInferenceChain: {
+        label: "Alternating Inference Chain",
+        description:
+            "A chain of alternating strong and weak links between candidates. At least one of the ends of the chain is true.",
+        link: "https://www.sudokuwiki.org/Alternating_Inference_Chains",
+    },
+    SueDeCoq: {
+        label: "Sue de Coq",
+        description:
+            "Do the cells of a box/line intersection form a locked set together with cells of the rest of the line and the rest of the box?",
+        link: "https://www.sudokuwiki.org/Sue_De_Coq",
+    },
+    AlsXz: {
+        label: "ALS-XZ",
+        description:
+            "Two almost locked sets share a restricted common candidate, which can only be true in one of them. Any other common candidate is eliminated in cells seeing all its occurrences in both sets.",
+        link: "https://www.sudokuwiki.org/Almost_Locked_Sets",
+    },
+    AlsXyWing: {
+        label: "ALS-XY-Wing",
+        description:
+            "A pivot almost locked set is linked to two wing sets by different restricted common candidates. A candidate shared by both wings is eliminated in cells seeing all its occurrences in the wings.",
+        link: "https://www.sudokuwiki.org/ALS_XY_Wing",
+    },
+    DeathBlossom: {
+        label: "Death Blossom",
+        description:
+            "Is there a cell, whose candidates each belong to an almost locked set, which share a common candidate?",
+        link: "https://www.sudokuwiki.org/Death_Blossom",
+    },
+    UniqueRectangle: {
+        label: "Unique Rectangle",
+        description:
+            "Four cells in two rows, columns and blocks, which share the same two candidates, would allow a second solution. The extra candidates of the rectangle (types 1 to 4) must avoid this deadly pattern. Requires a unique solution.",
+        link: "https://www.sudokuwiki.org/Unique_Rectangles",
+    },
+    BugPlusOne: {
+        label: "BUG+1",
+        description:
+            "All unsolved cells are bivalue except a single cell with three candidates. To avoid a bivalue universal grave, which has multiple solutions, the extra candidate of the cell must be true. Requires a unique solution.",
+        link: "https://www.sudokuwiki.org/BUG",
+    },
+    ForcingChains: {
+        label: "Forcing Chains",
+        description:
+            "Assumes a candidate and propagates singles: a contradiction removes the candidate, consequences shared by all candidates of a cell or all positions of a value in a group are true.",
+        link: "https://www.sudokuwiki.org/Cell_Forcing_Chains",
+    },
     BruteForce: {
         label: "Brute Force",
         description:
@@ -77,7 +258,7 @@ export const STRATEGY_OPTIONS: Record<
     },
 };
 
-export const strategyListSchema = strategyEnumSchema.array().min(1);
+export const strategyListSchema = selectableStrategyEnumSchema.array().min(1);
 
 export const strategySetSchema = z.record(strategyMapKeySchema, z.boolean());
 
@@ -113,7 +294,9 @@ const gridMetricNameWithoutStrategySchema = z.enum([
     "strategyApplicationCountAny",
     "strategyDeductionCountAny",
     "strategyAverageOptions",
+    "backdoorSize",
     "satStepCount",
+    "satTriadsStepCount",
     "backtrackCount",
     "gridGivensCount",
     "gridDirectCandidatesCount",
@@ -128,7 +311,9 @@ export const gridMetricNameSchema = z.enum([
     "strategyDeductionCountAny",
     "strategyDeductionCountSingle",
     "strategyAverageOptions",
+    "backdoorSize",
     "satStepCount",
+    "satTriadsStepCount",
     "backtrackCount",
     "gridGivensCount",
     "gridDirectCandidatesCount",
@@ -179,9 +364,17 @@ export const GRID_METRIC_OPTIONS: Record<
         label: "Strategy: average options",
         description: "The average number of strategies available to make progress.",
     },
+    backdoorSize: {
+        label: "Strategy: backdoor size",
+        description:
+            "The minimal number of cells, whose solution values have to be revealed, so that the strategies solve the grid without guessing.",
+    },
     satStepCount: {
         label: "SAT solver: step count",
     },
+    satTriadsStepCount: {
+        label: "SAT solver (triads): step count",
+    },
     backtrackCount: {
         label: "Backtracking solver: step count",
     },
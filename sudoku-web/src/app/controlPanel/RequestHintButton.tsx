@@ -6,12 +6,25 @@ import * as _ from "es-toolkit";
 import { useNotifications } from "@toolpad/core/useNotifications";
 import { useCallback, useState } from "react";
 import { useAtomValue } from "jotai";
-import { useApplyDeductions, useTryStrategies } from "../actions/sudokuActions";
+import {
+    useApplyDeductions,
+    useExplainStep,
+    useTryStrategies,
+    useTryStrategiesWithHintLevel,
+} from "../actions/sudokuActions";
 import MyIconButton from "../components/MyIconButton";
 import { hintSettingsState, scaleLoopDelayIndex } from "../state/forms/hintSettings";
 import { hintState, type Hint, type OptionalHint } from "../state/hint";
 import { sudokuIsSolvedState } from "../state/sudoku";
 import { RESET, useAtomCallback } from "jotai/utils";
+import type { HintLevel } from "../../types";
+
+const NEXT_HINT_LEVEL = {
+    technique: "region",
+    region: "deduction",
+    deduction: "apply",
+    apply: "technique",
+} as const satisfies Record<HintLevel, HintLevel>;
 
 export function RequestHintButton() {
     const [isRequestingHint, setIsRequestingHint] = useState(false);
@@ -19,6 +32,8 @@ export function RequestHintButton() {
 
     const tryStrategies = useTryStrategies();
     const applyDeductions = useApplyDeductions();
+    const explainStep = useExplainStep();
+    const tryStrategiesWithHintLevel = useTryStrategiesWithHintLevel();
     const sudokuIsSolved = useAtomValue(sudokuIsSolvedState);
 
     const notifications = useNotifications();
@@ -87,12 +102,15 @@ export function RequestHintButton() {
                 const hint = await getHint();
                 if (hint) {
                     set(hintState, hint);
+                    const { strategy, deductions } = hint;
+                    const explanations = await explainStep({ strategy, deductions: { deductions } });
+                    notifications.show(explanations.join("\n"), { key: "hint", severity: "info" });
                     return true;
                 } else {
                     return false;
                 }
             },
-            [getHint],
+            [explainStep, getHint, notifications],
         ),
     );
 
@@ -118,6 +136,62 @@ export function RequestHintButton() {
         [applyDeductions, notifications, hideHint],
     );
 
+    const showNextHintLevel = useAtomCallback(
+        useCallback(
+            async (get, set): Promise<boolean> => {
+                const hintSettings = get(hintSettingsState);
+                const hint = get(hintState);
+                const hintLevel = hint?.level ? NEXT_HINT_LEVEL[hint.level] : "technique";
+
+                let dynamicHint;
+                try {
+                    dynamicHint = await tryStrategiesWithHintLevel(hintSettings.strategies, hintLevel);
+                } catch (err) {
+                    if (!(err instanceof Error)) throw err;
+                    console.error("Failed to execute strategies", hintSettings.strategies, ":", err);
+                    notifications.show(err.message, { severity: "error" });
+                    return false;
+                }
+                if (!dynamicHint) {
+                    notifications.show("No strategy made progress", {
+                        key: "no-progress",
+                        severity: "warning",
+                    });
+                    hideHint();
+                    return false;
+                }
+
+                switch (dynamicHint.kind) {
+                    case "technique":
+                        notifications.show(`Try ${dynamicHint.strategy}`, { key: "hint", severity: "info" });
+                        set(hintState, { strategy: dynamicHint.strategy, level: dynamicHint.kind, deductions: [] });
+                        return false;
+                    case "region":
+                        set(hintState, {
+                            strategy: dynamicHint.strategy,
+                            level: dynamicHint.kind,
+                            regions: dynamicHint.groups,
+                            deductions: [],
+                        });
+                        return false;
+                    case "deduction":
+                        set(hintState, {
+                            strategy: dynamicHint.step.strategy,
+                            level: dynamicHint.kind,
+                            deductions: dynamicHint.step.deductions.deductions,
+                        });
+                        return false;
+                    case "apply":
+                        hideHint();
+                        return true;
+                    default:
+                        assertNever(dynamicHint);
+                }
+            },
+            [hideHint, notifications, tryStrategiesWithHintLevel],
+        ),
+    );
+
     const requestSingleHint = useAtomCallback(
         useCallback(
             async (get): Promise<boolean> => {
@@ -150,9 +224,12 @@ export function RequestHintButton() {
                         return false;
                     }
                 }
+                if (mode === "progressive") {
+                    return await showNextHintLevel();
+                }
                 assertNever(mode);
             },
-            [applyHint, getHint, hideHint, showHint],
+            [applyHint, getHint, hideHint, showHint, showNextHintLevel],
         ),
     );
 
@@ -169,7 +246,7 @@ export function RequestHintButton() {
                 try {
                     const { mode, doLoop, loopDelayIndex } = get(hintSettingsState);
 
-                    if (doLoop && mode !== "toggleHint") {
+                    if (doLoop && mode !== "toggleHint" && mode !== "progressive") {
                         while (await requestSingleHint()) {
                             if (loopDelayIndex) {
                                 const loopDelayMs = scaleLoopDelayIndex(loopDelayIndex);
@@ -2,7 +2,13 @@ import classNames from "classnames";
 import type * as CSS from "csstype";
 import { isEqual } from "es-toolkit";
 import { useAtomValue } from "jotai";
-import type { DynamicCellCandidates, DynamicCellValue, DynamicPosition, TransportCell } from "../../types";
+import type {
+    DynamicCellCandidates,
+    DynamicCellValue,
+    DynamicPosition,
+    TransportCell,
+    TransportGroup,
+} from "../../types";
 import { useHandlePosition } from "../actions/sudokuActions";
 import { hintState } from "../state/hint";
 import { inputState } from "../state/input";
@@ -18,6 +24,16 @@ function cellBackgroundClass(isSelected: boolean, isGuide: boolean) {
     }
 }
 
+function isInGroup(group: TransportGroup, { row, column }: DynamicPosition, base: number) {
+    if ("row" in group) {
+        return group.row === row;
+    }
+    if ("column" in group) {
+        return group.column === column;
+    }
+    return group.block === Math.floor(row / base) * base + Math.floor(column / base);
+}
+
 type CellValueProps = {
     value: DynamicCellValue["value"];
 };
@@ -53,7 +69,41 @@ export function Candidates({ candidates, gridPosition, showGuide = true }: Candi
                 //  prototype different deductions data structures via selector
                 const isDeductionReason = hint?.deductions.some((deduction) =>
                     deduction.reasons.some(
-                        (reason) => isEqual(reason.position, gridPosition) && reason.candidates.includes(candidate),
+                        (reason) =>
+                            isEqual(reason.position, gridPosition) &&
+                            (("candidates" in reason && reason.candidates.includes(candidate)) ||
+                                ("fin" in reason && reason.fin.candidates.includes(candidate))),
+                    ),
+                );
+                const isDeductionFin = hint?.deductions.some((deduction) =>
+                    deduction.reasons.some(
+                        (reason) =>
+                            isEqual(reason.position, gridPosition) &&
+                            "fin" in reason &&
+                            reason.fin.fins.includes(candidate),
+                    ),
+                );
+                const isDeductionColor1 = hint?.deductions.some((deduction) =>
+                    deduction.reasons.some(
+                        (reason) =>
+                            isEqual(reason.position, gridPosition) &&
+                            "colors" in reason &&
+                            reason.colors.color_1.includes(candidate),
+                    ),
+                );
+                const isDeductionColor2 = hint?.deductions.some((deduction) =>
+                    deduction.reasons.some(
+                        (reason) =>
+                            isEqual(reason.position, gridPosition) &&
+                            "colors" in reason &&
+                            reason.colors.color_2.includes(candidate),
+                    ),
+                );
+                const isDeductionLink = hint?.deductions.some((deduction) =>
+                    deduction.links?.some((link) =>
+                        [link.from, link.to].some(
+                            (node) => isEqual(node.position, gridPosition) && node.candidate === candidate,
+                        ),
                     ),
                 );
                 const isDeductionDelete = hint?.deductions.some((deduction) =>
@@ -70,6 +120,10 @@ export function Candidates({ candidates, gridPosition, showGuide = true }: Candi
                         className={classNames("candidate", {
                             "candidate--guide": isGuide,
                             "candidate--deduction-reason": isDeductionReason,
+                            "candidate--deduction-fin": isDeductionFin,
+                            "candidate--deduction-color-1": isDeductionColor1,
+                            "candidate--deduction-color-2": isDeductionColor2,
+                            "candidate--deduction-link": isDeductionLink,
                             "candidate--deduction-delete": isDeductionDelete,
                         })}
                         style={style}
@@ -94,10 +148,24 @@ export function Cell(props: CellProps) {
 
     const { position: gridPosition } = cell;
 
+    const base = useAtomValue(sudokuBaseState);
+    const hint = useAtomValue(hintState);
+
+    const isDeductionCell = hint?.deductions.some((deduction) =>
+        deduction.reasons.some((reason) => isEqual(reason.position, gridPosition) && "cell" in reason),
+    );
+    const isDeductionGroup =
+        hint?.regions?.some((group) => isInGroup(group, gridPosition, base)) ||
+        hint?.deductions.some((deduction) => deduction.groups?.some((group) => isInGroup(group, gridPosition, base)));
+
     const cellClassNames = classNames(
         "cell",
         cellBackgroundClass(isSelected, isGuide),
         cellColorClass(cell.kind === "value" && cell.fixed, cell.incorrectValue),
+        {
+            "cell--deduction-cell": isDeductionCell,
+            "cell--deduction-group": isDeductionGroup,
+        },
     );
 
     const handlePosition = useHandlePosition();
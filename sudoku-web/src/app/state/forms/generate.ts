@@ -63,7 +63,6 @@ export const GENERATE_FORM_DEFAULT_VALUES = {
     strategies: selectedStrategiesSchema.decode([
         "NakedSingles",
         "HiddenSingles",
-        "NakedPairs",
         "LockedSets",
         "GroupIntersectionBoth",
         "XWing",
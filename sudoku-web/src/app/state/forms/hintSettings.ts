@@ -28,7 +28,7 @@ export const MAX_LOOP_DELAY_INDEX = unscaleLoopDelayMs(4000);
 export type HintSettings = z.infer<typeof hintSettingsSchema>;
 export const hintSettingsSchema = z.object({
     strategies: selectedStrategiesSchema,
-    mode: z.enum(["toggleHint", "hintApply", "apply"]),
+    mode: z.enum(["toggleHint", "hintApply", "apply", "progressive"]),
     doLoop: z.boolean(),
     loopDelayIndex: z.number().nonnegative().max(MAX_LOOP_DELAY_INDEX),
     multipleDeductions: z.boolean(),
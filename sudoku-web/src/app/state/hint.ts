@@ -1,8 +1,11 @@
 import { atomWithReset } from "jotai/utils";
-import type { StrategyEnum, TransportDeductions } from "../../types";
+import type { HintLevel, StrategyEnum, TransportDeductions, TransportGroup } from "../../types";
 
 export type Hint = {
     strategy: StrategyEnum;
+    // The level of a progressive hint, which may not reveal its deductions yet.
+    level?: HintLevel;
+    regions?: TransportGroup[];
 } & TransportDeductions;
 
 export type OptionalHint = Hint | undefined;